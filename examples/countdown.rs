@@ -0,0 +1,40 @@
+//! A countdown that reports each whole second remaining.
+//!
+//! This should result in an output of:
+//! ```text
+//! [t=0] Timer started
+//! [t=1] 2...
+//! [t=2] 1...
+//! [t=3] Timer finished
+//! ```
+
+use bevy::{log::LogPlugin, prelude::*};
+use bevy_mod_observable_timer::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            LogPlugin::default(),
+            ObservableTimerPlugin::default(),
+        ))
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands) {
+    commands
+        .spawn(ObservableTimer::once_from_seconds(3.0).with_second_events(true))
+        .observe(|_: Trigger<TimerStarted>| {
+            info!("Timer started");
+        })
+        .observe(|trigger: Trigger<TimerSecondElapsed>| {
+            info!("{}...", trigger.event().remaining_secs());
+        })
+        .observe(
+            |_: Trigger<TimerFinished>, mut app_exit: EventWriter<AppExit>| {
+                info!("Timer finished");
+                app_exit.send_default();
+            },
+        );
+}
@@ -0,0 +1,64 @@
+//! Demonstrates round-tripping a partially elapsed timer through a `DynamicScene`.
+//!
+//! This should result in an output of:
+//! ```text
+//! Saved timer with 1.5s elapsed
+//! Scene timer started with 1.5s elapsed
+//! ```
+
+use std::time::Duration;
+
+use bevy::{
+    ecs::entity::EntityHashMap, prelude::*, scene::DynamicSceneBuilder, time::TimeUpdateStrategy,
+};
+use bevy_mod_observable_timer::*;
+
+fn main() {
+    // Run a timer part of the way through in one app...
+    let mut source = headless_app();
+    let timer_id = source
+        .world_mut()
+        .spawn(ObservableTimer::from_seconds(3, 1.0))
+        .id();
+    for _ in 0..7 {
+        source.update();
+    }
+
+    let elapsed = source
+        .world()
+        .get::<ObservableTimer>(timer_id)
+        .unwrap()
+        .elapsed();
+    println!("Saved timer with {}s elapsed", elapsed.as_secs_f32());
+
+    let scene = DynamicSceneBuilder::from_world(source.world())
+        .extract_entities([timer_id].into_iter())
+        .build();
+
+    // ...and resume it in another
+    let mut target = headless_app();
+    target.world_mut().observe(
+        |trigger: Trigger<TimerStarted>, timers: Query<&ObservableTimer>| {
+            let timer = timers.get(trigger.entity()).unwrap();
+            println!(
+                "Scene timer started with {}s elapsed",
+                timer.elapsed().as_secs_f32()
+            );
+        },
+    );
+
+    scene
+        .write_to_world(target.world_mut(), &mut EntityHashMap::default())
+        .unwrap();
+    target.update();
+}
+
+/// An app whose clock advances by exactly 250ms each update (after the first).
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, ObservableTimerPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+    app
+}
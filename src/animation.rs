@@ -0,0 +1,186 @@
+use bevy::{
+    animation::{
+        graph::{AnimationGraph, AnimationNodeIndex},
+        AnimationClip, AnimationPlayer,
+    },
+    prelude::*,
+};
+
+use crate::timeline::KeyframeFn;
+
+/// A marker in an [`AnimationTimeline`].
+struct AnimationMarker {
+    time: f32,
+    trigger: KeyframeFn,
+}
+
+/// A component that triggers events on its entity at set points in an animation played by its [`AnimationPlayer`].
+///
+/// Each marker pairs a normalized time in the animation's clip, from `0.0` at the start to `1.0` at the end, with an
+/// [`Event`] that is triggered on the update that the player's playback crosses it. Because the markers follow the
+/// clip's playback time rather than [`Time`], they stay on the right frames when the animation is sped up, slowed
+/// down, paused or played in reverse, which makes them suitable for footsteps and hit frames. Markers are triggered on
+/// every repetition of the animation, and in reverse order when it plays backwards. Seeking, or replaying the
+/// animation, doesn't trigger the markers in between.
+///
+/// The `AnimationTimeline` must be on the same entity as the `AnimationPlayer`, and is updated just after the player
+/// advances, in [`PostUpdate`]. Markers are only triggered while the animation is playing and its clip is loaded.
+///
+/// Requires the `animation` feature.
+///
+/// ```no_run
+/// # use bevy::{animation::graph::AnimationNodeIndex, prelude::*};
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Event, Clone)]
+/// # enum Footstep {
+/// #     Left,
+/// #     Right,
+/// # }
+/// # fn setup(mut commands: Commands, player_entity: Entity, walk_animation: AnimationNodeIndex) {
+/// commands.entity(player_entity).insert(
+///     AnimationTimeline::new(walk_animation)
+///         .at(0.25, Footstep::Left)
+///         .at(0.75, Footstep::Right),
+/// );
+/// # }
+/// ```
+#[derive(Component)]
+pub struct AnimationTimeline {
+    /// The animation in the player's [`AnimationGraph`] whose clip time the markers follow.
+    pub animation: AnimationNodeIndex,
+    markers: Vec<AnimationMarker>,
+    /// The completion count and normalized clip time at the last update.
+    last: Option<(u32, f32)>,
+}
+
+impl AnimationTimeline {
+    /// Creates a timeline without any markers that follows the given animation.
+    pub fn new(animation: AnimationNodeIndex) -> Self {
+        Self {
+            animation,
+            markers: Vec::new(),
+            last: None,
+        }
+    }
+
+    /// Adds a marker that triggers a clone of `event` when playback crosses the normalized clip time `time`.
+    ///
+    /// The time is clamped between `0.0` and `1.0`. Markers at the same time are triggered in the order they were
+    /// added.
+    pub fn at<E: Event + Clone>(mut self, time: f32, event: E) -> Self {
+        let time = time.clamp(0.0, 1.0);
+        let index = self.markers.partition_point(|marker| marker.time <= time);
+        let trigger: KeyframeFn = Box::new(move |entity, commands| {
+            commands.trigger_targets(event.clone(), entity);
+        });
+        self.markers
+            .insert(index, AnimationMarker { time, trigger });
+        self
+    }
+
+    /// The number of markers.
+    pub fn len(&self) -> usize {
+        self.markers.len()
+    }
+
+    /// Returns `true` if the timeline has no markers.
+    pub fn is_empty(&self) -> bool {
+        self.markers.is_empty()
+    }
+
+    /// Triggers the markers with times after `start` (or at it, if `include_start` is set) up to `end`, in order.
+    fn trigger_forward(
+        &self,
+        entity: Entity,
+        start: f32,
+        end: f32,
+        include_start: bool,
+        commands: &mut Commands,
+    ) {
+        for marker in &self.markers {
+            let after_start = marker.time > start || (include_start && marker.time == start);
+            if after_start && marker.time <= end {
+                (marker.trigger)(entity, commands);
+            }
+        }
+    }
+
+    /// Triggers the markers with times before `start` (or at it, if `include_start` is set) down to `end`, in reverse
+    /// order.
+    fn trigger_backward(
+        &self,
+        entity: Entity,
+        start: f32,
+        end: f32,
+        include_start: bool,
+        commands: &mut Commands,
+    ) {
+        for marker in self.markers.iter().rev() {
+            let before_start = marker.time < start || (include_start && marker.time == start);
+            if before_start && marker.time >= end {
+                (marker.trigger)(entity, commands);
+            }
+        }
+    }
+}
+
+pub(crate) fn update_animation_timelines(
+    mut timelines: Query<(
+        Entity,
+        &mut AnimationTimeline,
+        &AnimationPlayer,
+        &Handle<AnimationGraph>,
+    )>,
+    graphs: Option<Res<Assets<AnimationGraph>>>,
+    clips: Option<Res<Assets<AnimationClip>>>,
+    mut commands: Commands,
+) {
+    let (Some(graphs), Some(clips)) = (graphs, clips) else {
+        return;
+    };
+
+    for (entity, mut timeline, player, graph) in timelines.iter_mut() {
+        let active = player.animation(timeline.animation);
+        let clip = graphs
+            .get(graph)
+            .and_then(|graph| graph.get(timeline.animation))
+            .and_then(|node| node.clip.as_ref())
+            .and_then(|clip| clips.get(clip));
+        let (Some(active), Some(clip)) = (active, clip) else {
+            timeline.last = None;
+            continue;
+        };
+
+        let time = if clip.duration() > 0.0 {
+            (active.seek_time() / clip.duration()).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let completions = active.completions();
+        let Some((last_completions, last_time)) = timeline.last.replace((completions, time)) else {
+            continue;
+        };
+
+        // The player completes at most one repetition per update. A finished animation stays at its end.
+        let forward = active.speed() >= 0.0;
+        if completions == last_completions {
+            if forward && time > last_time {
+                timeline.trigger_forward(entity, last_time, time, false, &mut commands);
+            } else if !forward && time < last_time {
+                timeline.trigger_backward(entity, last_time, time, false, &mut commands);
+            }
+        } else if completions == last_completions + 1 {
+            if forward {
+                timeline.trigger_forward(entity, last_time, 1.0, false, &mut commands);
+                if !active.is_finished() {
+                    timeline.trigger_forward(entity, 0.0, time, true, &mut commands);
+                }
+            } else {
+                timeline.trigger_backward(entity, last_time, 0.0, false, &mut commands);
+                if !active.is_finished() {
+                    timeline.trigger_backward(entity, 1.0, time, true, &mut commands);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::{
+    audio::{AudioSink, AudioSinkPlayback, SpatialAudioSink},
+    ecs::system::SystemParam,
+    prelude::*,
+};
+
+/// A component that runs the timers on its entity, and all of its descendants, at the playback speed of the audio sink
+/// on another entity.
+///
+/// Timers normally follow [`Time`], so they keep running while music is paused, slowed down or sped up. With an
+/// `AudioSpeedSource`, timers only advance while the [`AudioSink`] (or [`SpatialAudioSink`]) on the source entity is
+/// playing, at the sink's [speed](AudioSinkPlayback::speed). Before the sink has been created, after it has finished
+/// playing, or if the source entity doesn't exist, the timers don't advance at all.
+///
+/// This only follows the sink's playback state and speed, not its playback position, which `bevy_audio` 0.14 doesn't
+/// expose. Timers still advance by the frame's [`Time`] delta while the sink plays, so they don't follow seeking, and
+/// drift from the audio when its output stutters. Start the timers together with the audio to keep them aligned.
+///
+/// This applies to the same timers as [`TimeDilation`](crate::TimeDilation), and combines with any dilation in the
+/// hierarchy.
+///
+/// Requires the `bevy_audio` feature.
+///
+/// ```ignore
+/// let music = commands
+///     .spawn(AudioBundle {
+///         source: asset_server.load("song.ogg"),
+///         ..default()
+///     })
+///     .id();
+///
+/// commands.spawn((
+///     AudioSpeedSource(music),
+///     ObservableTimer::once_from_seconds(42.5),
+/// ))
+/// .observe(|_: Trigger<TimerFinished>| info!("The chorus starts"));
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct AudioSpeedSource(pub Entity);
+
+/// Looks up the playback speed of the sinks referenced by [`AudioSpeedSource`]s.
+#[derive(SystemParam)]
+pub(crate) struct AudioSpeedSources<'w, 's> {
+    sources: Query<'w, 's, &'static AudioSpeedSource>,
+    sinks: Query<
+        'w,
+        's,
+        (
+            Option<&'static AudioSink>,
+            Option<&'static SpatialAudioSink>,
+        ),
+    >,
+}
+
+impl AudioSpeedSources<'_, '_> {
+    /// Returns `true` if any entity has an [`AudioSpeedSource`].
+    pub(crate) fn any(&self) -> bool {
+        !self.sources.is_empty()
+    }
+
+    /// The rate that timers on `entity` advance at, or `None` if it has no [`AudioSpeedSource`].
+    pub(crate) fn scale(&self, entity: Entity) -> Option<f64> {
+        let source = self.sources.get(entity).ok()?;
+        Some(match self.sinks.get(source.0) {
+            Ok((Some(sink), _)) => playback_speed(sink),
+            Ok((None, Some(sink))) => playback_speed(sink),
+            _ => 0.0,
+        })
+    }
+}
+
+fn playback_speed(sink: &impl AudioSinkPlayback) -> f64 {
+    if sink.is_paused() || sink.empty() {
+        0.0
+    } else {
+        f64::from(sink.speed().max(0.0))
+    }
+}
@@ -0,0 +1,186 @@
+use std::{sync::Arc, time::Duration};
+
+use bevy::prelude::*;
+
+/// Returns `true` while autosaving should be held back.
+type SuppressionFn = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// A [`Resource`] that periodically requests an autosave by triggering a global [`SaveRequested`] event.
+///
+/// The timer only counts down while it isn't [paused](Self::paused), and follows the
+/// [`TimerTickMode`](crate::TimerTickMode) and [`ObservableTimerPlugin`](crate::ObservableTimerPlugin) run conditions
+/// like [`ObservableTimer`](crate::ObservableTimer)s do, so only time actually spent playing counts towards the next
+/// save.
+///
+/// Saves can also be suppressed, for example during combat or cutscenes, by adding suppressions with
+/// [`Self::with_suppression()`], or with `suppress_in_state()` when the `bevy_state` feature is enabled. A save that
+/// comes due while suppressed is held back until every suppression has ended, and is then requested on the next
+/// update. Use [`Self::postpone()`] to push the next save back, such as after the player has just saved manually.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_autosave()`], and may also be inserted or removed at runtime.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Resource)]
+/// struct InCombat;
+///
+/// let mut world = World::new();
+/// world.insert_resource(
+///     AutosaveTimer::from_minutes(5.0).with_suppression(|world| world.contains_resource::<InCombat>()),
+/// );
+///
+/// world.insert_resource(InCombat);
+/// world.tick_observable_timers(Duration::from_secs(5 * 60));
+/// assert!(world.resource::<AutosaveTimer>().is_due());
+///
+/// world.remove_resource::<InCombat>();
+/// world.tick_observable_timers(Duration::ZERO);
+/// assert!(!world.resource::<AutosaveTimer>().is_due());
+/// ```
+///
+/// [`ObservableTimerPlugin::with_autosave()`]: crate::ObservableTimerPlugin::with_autosave
+#[derive(Resource, Clone)]
+pub struct AutosaveTimer {
+    /// The play time between autosaves.
+    pub interval: Duration,
+    /// Whether the timer is paused. Defaults to `false`.
+    pub paused: bool,
+    remaining: Duration,
+    since_last_save: Duration,
+    suppressions: Vec<SuppressionFn>,
+}
+
+impl AutosaveTimer {
+    /// Creates an autosave timer that requests a save every `interval` of play.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            paused: false,
+            remaining: interval,
+            since_last_save: Duration::ZERO,
+            suppressions: Vec::new(),
+        }
+    }
+
+    /// Creates an autosave timer that requests a save every given number of minutes of play.
+    pub fn from_minutes(minutes: f32) -> Self {
+        Self::new(Duration::from_secs_f32(minutes * 60.0))
+    }
+
+    /// Adds a suppression, which holds back saves whenever `suppressed` returns `true`.
+    ///
+    /// May be called multiple times. Saves are held back while any suppression applies.
+    pub fn with_suppression(
+        mut self,
+        suppressed: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.suppressions.push(Arc::new(suppressed));
+        self
+    }
+
+    /// Adds a suppression that holds back saves while the app is in `state`.
+    ///
+    /// Requires the `bevy_state` feature.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum GameState {
+    /// #     Combat,
+    /// #     Cutscene,
+    /// # }
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     ObservableTimerPlugin::default().with_autosave(
+    ///         AutosaveTimer::from_minutes(10.0)
+    ///             .suppress_in_state(GameState::Combat)
+    ///             .suppress_in_state(GameState::Cutscene),
+    ///     ),
+    /// );
+    /// ```
+    #[cfg(feature = "bevy_state")]
+    pub fn suppress_in_state<S: States>(self, state: S) -> Self {
+        self.with_suppression(move |world| {
+            world
+                .get_resource::<State<S>>()
+                .is_some_and(|current| *current.get() == state)
+        })
+    }
+
+    /// The play time remaining until the next save is due.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    /// The play time since the last save was requested.
+    pub fn since_last_save(&self) -> Duration {
+        self.since_last_save
+    }
+
+    /// Returns `true` if a save is due but is being held back by a suppression.
+    pub fn is_due(&self) -> bool {
+        self.remaining.is_zero()
+    }
+
+    /// Pushes the next save back by `delay`.
+    pub fn postpone(&mut self, delay: Duration) {
+        self.remaining += delay;
+    }
+
+    /// Restarts the countdown to the next save from the full [interval](Self::interval), as though a save had just
+    /// been made.
+    pub fn reset(&mut self) {
+        self.remaining = self.interval;
+        self.since_last_save = Duration::ZERO;
+    }
+}
+
+impl std::fmt::Debug for AutosaveTimer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutosaveTimer")
+            .field("interval", &self.interval)
+            .field("paused", &self.paused)
+            .field("remaining", &self.remaining)
+            .field("since_last_save", &self.since_last_save)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An [`Event`] that is triggered globally by the [`AutosaveTimer`] when the game should be saved.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct SaveRequested {
+    since_last_save: Duration,
+}
+
+impl SaveRequested {
+    /// The play time since the previous save was requested.
+    pub fn since_last_save(&self) -> Duration {
+        self.since_last_save
+    }
+}
+
+pub(crate) fn update_autosave(In(delta): In<Duration>, world: &mut World) {
+    let Some(autosave) = world.get_resource::<AutosaveTimer>() else {
+        return;
+    };
+    if autosave.paused {
+        return;
+    }
+    let suppressed = autosave
+        .suppressions
+        .iter()
+        .any(|suppressed| suppressed(world));
+
+    let mut autosave = world.resource_mut::<AutosaveTimer>();
+    autosave.remaining = autosave.remaining.saturating_sub(delta);
+    autosave.since_last_save += delta;
+    if autosave.remaining.is_zero() && !suppressed {
+        let since_last_save = autosave.since_last_save;
+        autosave.reset();
+        world.trigger(SaveRequested { since_last_save });
+    }
+}
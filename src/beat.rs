@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    dilation::TimeDilations, update::timer_delta, ObservableTimerSystems, ObservableTimerUpdate,
+    TimerTag,
+};
+
+/// A [`Resource`] holding the tempo that [`BeatTimer`]s follow.
+///
+/// The tempo may be changed at any point, such as when a song speeds up. Beat timers keep their position within the
+/// current beat, so the new tempo takes effect smoothly from the current update. Beat timers don't advance while
+/// [`Self::beats_per_minute`] is zero or negative.
+///
+/// This is inserted by [`BeatTimerPlugin`] with a default of 120 beats per minute and 4 beats per bar.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct Bpm {
+    /// The number of beats per minute.
+    pub beats_per_minute: f32,
+    /// The number of beats in a bar. Values below 1 are treated as 1.
+    pub beats_per_bar: u32,
+}
+
+impl Bpm {
+    /// Creates a tempo of `beats_per_minute`, with 4 beats per bar.
+    pub fn new(beats_per_minute: f32) -> Self {
+        Self {
+            beats_per_minute,
+            beats_per_bar: 4,
+        }
+    }
+
+    /// Sets the number of beats per bar.
+    pub fn with_beats_per_bar(mut self, beats_per_bar: u32) -> Self {
+        self.beats_per_bar = beats_per_bar;
+        self
+    }
+
+    /// The duration of a single beat, or `None` if the tempo isn't positive.
+    pub fn beat_duration(&self) -> Option<Duration> {
+        (self.beats_per_minute > 0.0)
+            .then(|| Duration::from_secs_f64(60.0 / self.beats_per_minute as f64))
+    }
+}
+
+impl Default for Bpm {
+    fn default() -> Self {
+        Self::new(120.0)
+    }
+}
+
+/// A timer component that follows the musical grid set by the [`Bpm`] resource.
+///
+/// A [`BeatElapsed`] event is triggered on the timer's entity at the start of every beat after the first, with the
+/// beat's position within its bar. Because the timer counts beats rather than time, changing the [`Bpm`] mid-song
+/// doesn't require re-deriving any intervals, and the events stay aligned to the beat.
+///
+/// Requires the [`BeatTimerPlugin`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual), BeatTimerPlugin));
+/// let world = app.world_mut();
+/// world.insert_resource(Bpm::new(120.0));
+/// let metronome = world.spawn(BeatTimer::new()).id();
+///
+/// // At 120 BPM a 4 beat bar lasts for 2 seconds
+/// world.tick_observable_timers(Duration::from_secs(2));
+/// let timer = world.get::<BeatTimer>(metronome).unwrap();
+/// assert_eq!((timer.bar(), timer.beat()), (1, 0));
+///
+/// // Doubling the tempo halves the length of each beat
+/// world.resource_mut::<Bpm>().beats_per_minute = 240.0;
+/// world.tick_observable_timers(Duration::from_millis(500));
+/// assert_eq!(world.get::<BeatTimer>(metronome).unwrap().beats(), 6);
+/// ```
+#[derive(Component, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct BeatTimer {
+    /// Whether the timer is paused. Defaults to `false`.
+    pub paused: bool,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    beat: u32,
+    bar: u32,
+    beats: u64,
+    beat_fraction: f64,
+}
+
+impl BeatTimer {
+    /// Creates a new timer, starting on the first beat of the first bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The index of the current beat within its bar, starting from 0.
+    pub fn beat(&self) -> u32 {
+        self.beat
+    }
+
+    /// The index of the current bar, starting from 0.
+    pub fn bar(&self) -> u32 {
+        self.bar
+    }
+
+    /// The total number of beats that have elapsed.
+    pub fn beats(&self) -> u64 {
+        self.beats
+    }
+
+    /// How far through the current beat the timer is, between 0 and 1.
+    pub fn beat_fraction(&self) -> f32 {
+        self.beat_fraction as f32
+    }
+
+    /// The time remaining until the next beat at the given tempo, or `None` if the tempo isn't positive.
+    pub fn until_next_beat(&self, bpm: &Bpm) -> Option<Duration> {
+        Some(bpm.beat_duration()?.mul_f64(1.0 - self.beat_fraction))
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`BeatTimer`]'s entity at the start of each beat.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct BeatElapsed {
+    beat: u32,
+    bar: u32,
+    tag: Option<TimerTag>,
+}
+
+impl BeatElapsed {
+    /// The index of the beat that started within its bar, starting from 0.
+    pub fn beat(&self) -> u32 {
+        self.beat
+    }
+
+    /// The index of the bar that the beat belongs to, starting from 0.
+    pub fn bar(&self) -> u32 {
+        self.bar
+    }
+
+    /// Returns `true` if this is the first beat of a bar.
+    pub fn is_downbeat(&self) -> bool {
+        self.beat == 0
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A plugin that updates [`BeatTimer`]s, and inserts the [`Bpm`] resource they follow. Requires
+/// [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), BeatTimerPlugin));
+/// ```
+pub struct BeatTimerPlugin;
+
+impl Plugin for BeatTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Bpm>()
+            .register_type::<BeatTimer>()
+            .register_type::<BeatElapsed>()
+            .init_resource::<Bpm>()
+            .add_systems(
+                ObservableTimerUpdate,
+                timer_delta
+                    .pipe(update_beat_timers)
+                    .after(ObservableTimerSystems::ApplyFinishBehavior),
+            );
+    }
+}
+
+pub(crate) fn update_beat_timers(
+    In(delta): In<Duration>,
+    bpm: Option<Res<Bpm>>,
+    mut timers: Query<(Entity, &mut BeatTimer)>,
+    dilations: TimeDilations,
+    mut commands: Commands,
+) {
+    let Some(bpm) = bpm.filter(|bpm| bpm.beats_per_minute > 0.0) else {
+        return;
+    };
+    let beats_per_second = bpm.beats_per_minute as f64 / 60.0;
+    let beats_per_bar = bpm.beats_per_bar.max(1);
+
+    let dilated = dilations.any();
+    for (entity, mut timer) in timers.iter_mut() {
+        if timer.paused {
+            continue;
+        }
+
+        let delta = if dilated {
+            dilations.delta(entity, delta)
+        } else {
+            delta
+        };
+        let timer = &mut *timer;
+        timer.beat_fraction += delta.as_secs_f64() * beats_per_second;
+        while timer.beat_fraction >= 1.0 {
+            timer.beat_fraction -= 1.0;
+            timer.beats += 1;
+            timer.beat += 1;
+            if timer.beat >= beats_per_bar {
+                timer.beat = 0;
+                timer.bar += 1;
+            }
+
+            let (beat, bar, tag) = (timer.beat, timer.bar, timer.tag);
+            commands.trigger_targets(BeatElapsed { beat, bar, tag }, entity);
+        }
+    }
+}
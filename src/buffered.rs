@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{StopReason, TimerFinished, TimerInterval, TimerStarted};
+
+/// A buffered [`Event`] that mirrors [`TimerStarted`], for reading with an [`EventReader`].
+///
+/// This is only sent when enabled with [`ObservableTimerPlugin::with_buffered_events()`].
+///
+/// [`ObservableTimerPlugin::with_buffered_events()`]: crate::ObservableTimerPlugin::with_buffered_events
+#[derive(Event, Reflect, Debug, Clone, Copy)]
+#[reflect(Debug)]
+pub struct TimerStartedEvent {
+    entity: Entity,
+}
+
+impl TimerStartedEvent {
+    /// The entity whose timer started.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// A buffered [`Event`] that mirrors [`TimerInterval`], for reading with an [`EventReader`].
+///
+/// This is only sent when enabled with [`ObservableTimerPlugin::with_buffered_events()`].
+///
+/// [`ObservableTimerPlugin::with_buffered_events()`]: crate::ObservableTimerPlugin::with_buffered_events
+#[derive(Event, Reflect, Debug, Clone, Copy)]
+#[reflect(Debug)]
+pub struct TimerIntervalEvent {
+    entity: Entity,
+    count: u32,
+}
+
+impl TimerIntervalEvent {
+    /// The entity whose timer completed an interval.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// The count of the interval. Starts from `1`.
+    ///
+    /// See [`TimerInterval::count()`].
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+/// A buffered [`Event`] that mirrors [`TimerFinished`], for reading with an [`EventReader`].
+///
+/// This is only sent when enabled with [`ObservableTimerPlugin::with_buffered_events()`].
+///
+/// [`ObservableTimerPlugin::with_buffered_events()`]: crate::ObservableTimerPlugin::with_buffered_events
+#[derive(Event, Reflect, Debug, Clone, Copy)]
+#[reflect(Debug)]
+pub struct TimerFinishedEvent {
+    entity: Entity,
+    reason: StopReason,
+    overshoot: Duration,
+}
+
+impl TimerFinishedEvent {
+    /// The entity whose timer finished, or was cancelled. The entity may have been despawned by its timer's finish
+    /// behavior by the time this is read.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// `true` when the timer was cancelled before finishing.
+    ///
+    /// See [`TimerFinished::cancelled()`].
+    pub fn cancelled(&self) -> bool {
+        self.reason != StopReason::Finished
+    }
+
+    /// Why the timer stopped.
+    ///
+    /// See [`TimerFinished::reason()`].
+    pub fn reason(&self) -> StopReason {
+        self.reason
+    }
+
+    /// The amount of time by which the timer's final interval was overshot.
+    ///
+    /// See [`TimerFinished::overshoot()`].
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+}
+
+pub(crate) fn send_started_events(
+    trigger: Trigger<TimerStarted>,
+    mut events: EventWriter<TimerStartedEvent>,
+) {
+    events.send(TimerStartedEvent {
+        entity: trigger.entity(),
+    });
+}
+
+pub(crate) fn send_interval_events(
+    trigger: Trigger<TimerInterval>,
+    mut events: EventWriter<TimerIntervalEvent>,
+) {
+    events.send(TimerIntervalEvent {
+        entity: trigger.entity(),
+        count: trigger.event().count(),
+    });
+}
+
+pub(crate) fn send_finished_events(
+    trigger: Trigger<TimerFinished>,
+    mut events: EventWriter<TimerFinishedEvent>,
+) {
+    events.send(TimerFinishedEvent {
+        entity: trigger.entity(),
+        reason: trigger.event().reason(),
+        overshoot: trigger.event().overshoot(),
+    });
+}
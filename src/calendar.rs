@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::GameClock;
+
+/// Triggers a calendar entry's event globally.
+type CalendarFn = Box<dyn FnOnce(&mut Commands) + Send + Sync>;
+
+/// An event scheduled in a [`GameCalendar`].
+struct CalendarEntry {
+    time: Duration,
+    trigger: CalendarFn,
+}
+
+/// A [`Resource`] for scheduling events at in-game times on the [`GameClock`].
+///
+/// Each entry pairs an in-game timestamp with an [`Event`], which is triggered globally on the update that the clock
+/// reaches it, and is then removed. Entries are triggered in order of their timestamps, interleaved with the clock's
+/// [`HourElapsed`](crate::HourElapsed) events, and entries at the same time are triggered in the order they were
+/// scheduled. Entries scheduled for a time that has already passed are triggered on the next update that the clock
+/// advances.
+///
+/// When the clock is fast-forwarded with [`GameClock::skip()`], the entries that the skip passes over are triggered
+/// too, unless [`Self::trigger_skipped`] is `false`, in which case they are dropped.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_game_clock()`], and may also be inserted or removed at runtime.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Event)]
+/// struct MerchantArrives;
+///
+/// let mut world = World::new();
+/// world.insert_resource(GameClock::default());
+/// world.init_resource::<GameCalendar>();
+/// world
+///     .resource_mut::<GameCalendar>()
+///     .schedule_at(3, 8, 0, MerchantArrives);
+///
+/// // Sleeping through to day 4 still brings the merchant
+/// world.resource_mut::<GameClock>().skip(GameClock::timestamp(4, 0, 0));
+/// world.tick_observable_timers(Duration::ZERO);
+/// assert!(world.resource::<GameCalendar>().is_empty());
+/// ```
+///
+/// [`ObservableTimerPlugin::with_game_clock()`]: crate::ObservableTimerPlugin::with_game_clock
+#[derive(Resource)]
+pub struct GameCalendar {
+    entries: Vec<CalendarEntry>,
+    /// Whether entries passed over by [`GameClock::skip()`] are triggered. Defaults to `true`.
+    pub trigger_skipped: bool,
+}
+
+impl GameCalendar {
+    /// Creates an empty calendar.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            trigger_skipped: true,
+        }
+    }
+
+    /// Schedules `event` to be triggered once the clock's [elapsed](GameClock::elapsed()) time reaches `time`.
+    pub fn schedule<E: Event>(&mut self, time: Duration, event: E) -> &mut Self {
+        let index = self.entries.partition_point(|entry| entry.time <= time);
+        let trigger: CalendarFn = Box::new(move |commands| commands.trigger(event));
+        self.entries.insert(index, CalendarEntry { time, trigger });
+        self
+    }
+
+    /// Schedules `event` to be triggered at the given day, hour and minute. See [`GameClock::timestamp()`].
+    pub fn schedule_at<E: Event>(
+        &mut self,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        event: E,
+    ) -> &mut Self {
+        self.schedule(GameClock::timestamp(day, hour, minute), event)
+    }
+
+    /// The timestamp of the next scheduled entry.
+    pub fn next(&self) -> Option<Duration> {
+        self.entries.first().map(|entry| entry.time)
+    }
+
+    /// The number of scheduled entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no entries are scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every scheduled entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Triggers the entries that are due before `until` (or at it, if `inclusive` is set).
+    ///
+    /// Entries in `skipped` were passed over by a skip, and are dropped instead unless [`Self::trigger_skipped`] is
+    /// set.
+    pub(crate) fn trigger_due(
+        &mut self,
+        until: Duration,
+        inclusive: bool,
+        skipped: (Duration, Duration),
+        commands: &mut Commands,
+    ) {
+        let count = self
+            .entries
+            .partition_point(|entry| entry.time < until || (inclusive && entry.time == until));
+        let (skip_start, skip_end) = skipped;
+        for entry in self.entries.drain(..count) {
+            let was_skipped = entry.time > skip_start && entry.time <= skip_end;
+            if self.trigger_skipped || !was_skipped {
+                (entry.trigger)(commands);
+            }
+        }
+    }
+}
+
+impl Default for GameCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    future::{register_awaiter, TimerAwaiter},
+    ObservableTimer, TimerFinishedFuture,
+};
+
+/// A request to change timers, sent through a [`TimerSender`].
+#[derive(Debug)]
+pub enum TimerRequest {
+    /// Spawns a new entity with the timer.
+    Spawn(ObservableTimer),
+    /// Inserts the timer on the entity, replacing any existing timer.
+    Insert(Entity, ObservableTimer),
+    /// Adds time to the entity's timer. See [`ObservableTimer::extend()`].
+    Extend(Entity, Duration),
+    /// Removes time from the entity's timer. See [`ObservableTimer::shorten()`].
+    Shorten(Entity, Duration),
+    /// Pauses the entity's timer.
+    Pause(Entity),
+    /// Resumes the entity's timer.
+    Resume(Entity),
+    /// Restarts the entity's timer. See [`ObservableTimer::restart()`].
+    Restart(Entity),
+    /// Cancels the entity's timer by removing it.
+    Cancel(Entity),
+}
+
+/// A message sent through a [`TimerCommandChannel`].
+#[derive(Debug)]
+enum TimerMessage {
+    Request(Box<TimerRequest>),
+    Await(Entity, TimerAwaiter),
+}
+
+/// A cloneable handle for sending [`TimerRequest`]s from other threads or async tasks.
+///
+/// Get one from the [`TimerCommandChannel`] resource. Requests are applied in the order they were sent, at the start
+/// of the next timer update. Requests for entities that no longer exist, or that don't have a timer, are ignored.
+#[derive(Debug, Clone)]
+pub struct TimerSender(Sender<TimerMessage>);
+
+impl TimerSender {
+    /// Sends a request, returning `false` if the app has shut down.
+    pub fn send(&self, request: TimerRequest) -> bool {
+        self.0
+            .send(TimerMessage::Request(Box::new(request)))
+            .is_ok()
+    }
+
+    /// Requests spawning a new entity with the timer.
+    pub fn spawn(&self, timer: ObservableTimer) -> bool {
+        self.send(TimerRequest::Spawn(timer))
+    }
+
+    /// Requests inserting the timer on the entity.
+    pub fn start(&self, entity: Entity, timer: ObservableTimer) -> bool {
+        self.send(TimerRequest::Insert(entity, timer))
+    }
+
+    /// Requests adding time to the entity's timer.
+    pub fn extend(&self, entity: Entity, delta: Duration) -> bool {
+        self.send(TimerRequest::Extend(entity, delta))
+    }
+
+    /// Requests cancelling the entity's timer.
+    pub fn cancel(&self, entity: Entity) -> bool {
+        self.send(TimerRequest::Cancel(entity))
+    }
+
+    /// Returns a [`TimerFinishedFuture`] that resolves when the entity's timer finishes.
+    pub fn await_timer(&self, entity: Entity) -> TimerFinishedFuture {
+        let (future, awaiter) = TimerFinishedFuture::new();
+        if let Err(error) = self.0.send(TimerMessage::Await(entity, awaiter)) {
+            if let TimerMessage::Await(_, awaiter) = error.0 {
+                awaiter.resolve(None);
+            }
+        }
+        future
+    }
+}
+
+/// A [`Resource`] that lets code running off the main schedule control timers safely.
+///
+/// Background threads and async tasks can't access the [`World`], so instead they send [`TimerRequest`]s through a
+/// [`TimerSender`], and [`ObservableTimerPlugin`](crate::ObservableTimerPlugin) applies them at the start of each timer
+/// update. This is inserted by the plugin.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.init_resource::<TimerCommandChannel>();
+/// let timeout = world.spawn(ObservableTimer::from_seconds(1, 5.0)).id();
+///
+/// let sender = world.resource::<TimerCommandChannel>().sender();
+/// std::thread::spawn(move || sender.extend(timeout, Duration::from_secs(10)))
+///     .join()
+///     .unwrap();
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// let timer = world.get::<ObservableTimer>(timeout).unwrap();
+/// assert_eq!(timer.remaining(), Some(Duration::from_secs(14)));
+/// ```
+#[derive(Resource)]
+pub struct TimerCommandChannel {
+    sender: Sender<TimerMessage>,
+    receiver: Mutex<Receiver<TimerMessage>>,
+}
+
+impl TimerCommandChannel {
+    /// Returns a new handle for sending requests.
+    pub fn sender(&self) -> TimerSender {
+        TimerSender(self.sender.clone())
+    }
+}
+
+impl Default for TimerCommandChannel {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+        }
+    }
+}
+
+pub(crate) fn apply_timer_requests(
+    channel: Option<ResMut<TimerCommandChannel>>,
+    mut timers: Query<&mut ObservableTimer>,
+    mut commands: Commands,
+) {
+    let Some(mut channel) = channel else {
+        return;
+    };
+
+    let receiver = channel.receiver.get_mut().unwrap();
+    for message in receiver.try_iter() {
+        let request = match message {
+            TimerMessage::Request(request) => *request,
+            TimerMessage::Await(entity, awaiter) => {
+                commands.add(move |world: &mut World| register_awaiter(world, entity, awaiter));
+                continue;
+            }
+        };
+
+        match request {
+            TimerRequest::Spawn(timer) => {
+                commands.spawn(timer);
+            }
+            TimerRequest::Insert(entity, timer) => {
+                if let Some(mut entity) = commands.get_entity(entity) {
+                    entity.insert(timer);
+                }
+            }
+            TimerRequest::Cancel(entity) => {
+                if timers.contains(entity) {
+                    commands.entity(entity).remove::<ObservableTimer>();
+                }
+            }
+            TimerRequest::Extend(entity, delta) => {
+                if let Ok(mut timer) = timers.get_mut(entity) {
+                    timer.extend(delta);
+                }
+            }
+            TimerRequest::Shorten(entity, delta) => {
+                if let Ok(mut timer) = timers.get_mut(entity) {
+                    timer.shorten(delta);
+                }
+            }
+            TimerRequest::Pause(entity) => {
+                if let Ok(mut timer) = timers.get_mut(entity) {
+                    timer.pause();
+                }
+            }
+            TimerRequest::Resume(entity) => {
+                if let Ok(mut timer) = timers.get_mut(entity) {
+                    timer.unpause();
+                }
+            }
+            TimerRequest::Restart(entity) => {
+                if let Ok(mut timer) = timers.get_mut(entity) {
+                    timer.restart();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{ObservableTimer, TimerTag};
+
+/// A [`Resource`] that enables cleaning up timers that have stayed finished for longer than a grace period.
+///
+/// Timers with [`TimerFinishBehavior::None`](crate::TimerFinishBehavior::None) keep their [`ObservableTimer`] after
+/// finishing, so that they can be inspected or restarted. When nothing ever does, they are updated forever for no
+/// reason. With a `TimerCollector`, the `ObservableTimer` of each such timer is removed once it has been finished for
+/// the grace period, just after a [`TimerCollected`] event is triggered on its entity. The entity itself is left
+/// alone.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_timer_collection()`], and may also be inserted or removed at
+/// runtime. Paused timers and timers in the [`TimerRegistry`](crate::TimerRegistry) are never collected.
+///
+/// [`ObservableTimerPlugin::with_timer_collection()`]: crate::ObservableTimerPlugin::with_timer_collection
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct TimerCollector {
+    /// How long a timer must have been finished for before it is collected.
+    pub grace_period: Duration,
+}
+
+impl TimerCollector {
+    /// Creates a `TimerCollector` with the given grace period.
+    pub fn new(grace_period: Duration) -> Self {
+        Self { grace_period }
+    }
+}
+
+/// A timer [`Event`] that is triggered on a timer's entity just before a [`TimerCollector`] removes its
+/// [`ObservableTimer`].
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerCollected {
+    finished_for: Duration,
+    tag: Option<TimerTag>,
+}
+
+impl TimerCollected {
+    /// How long the timer had been finished for.
+    pub fn finished_for(&self) -> Duration {
+        self.finished_for
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+pub(crate) fn collect_finished_timers(
+    collector: Option<Res<TimerCollector>>,
+    timers: Query<(Entity, &ObservableTimer)>,
+    mut commands: Commands,
+) {
+    let Some(collector) = collector else {
+        return;
+    };
+
+    for (entity, timer) in timers.iter() {
+        if !timer.is_done() || timer.finished_for < collector.grace_period {
+            continue;
+        }
+
+        let (finished_for, tag) = (timer.finished_for, timer.tag);
+        commands.trigger_targets(TimerCollected { finished_for, tag }, entity);
+        commands.entity(entity).remove::<ObservableTimer>();
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::{query::QueryFilter, system::EntityCommands},
+    prelude::*,
+};
+
+use crate::{resource_timer::start_resource_timer, ObservableTimer, RespawnAfter, TriggerAfter};
+
+/// An extension trait for controlling many [`ObservableTimer`]s at once, and scheduling delayed changes, from
+/// [`Commands`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Component)]
+/// # struct LevelEntity;
+/// fn enter_cutscene(mut commands: Commands) {
+///     commands.pause_all_timers();
+/// }
+///
+/// fn unload_level(mut commands: Commands) {
+///     commands.cancel_all_timers::<With<LevelEntity>>();
+/// }
+/// ```
+pub trait TimerCommandsExt {
+    /// Pauses every [`ObservableTimer`].
+    fn pause_all_timers(&mut self);
+
+    /// Resumes every paused [`ObservableTimer`].
+    fn resume_all_timers(&mut self);
+
+    /// Cancels every [`ObservableTimer`] on an entity matching the filter `F`, by removing the component.
+    ///
+    /// This triggers [`TimerFinished`](crate::TimerFinished) with [`cancelled()`](crate::TimerFinished::cancelled)
+    /// set to `true` for every timer that hadn't finished yet. Use `()` to cancel every timer.
+    fn cancel_all_timers<F: QueryFilter + 'static>(&mut self);
+
+    /// Cancels every [`ObservableTimer`] on an entity matching the filter `F` like [`Self::cancel_all_timers()`],
+    /// but without triggering [`TimerFinished`](crate::TimerFinished) for them.
+    ///
+    /// See [`TimerEntityCommandsExt::cancel_timer_silently()`].
+    fn cancel_all_timers_silently<F: QueryFilter + 'static>(&mut self);
+
+    /// Inserts `resource` once `duration` has passed, using a [`ResourceTimer`](crate::ResourceTimer).
+    fn insert_resource_after<R: Resource>(&mut self, duration: Duration, resource: R);
+
+    /// Removes the resource `R` once `duration` has passed, using a [`ResourceTimer`](crate::ResourceTimer).
+    fn remove_resource_after<R: Resource>(&mut self, duration: Duration);
+
+    /// Triggers `event` globally once `duration` has passed, using a [`TriggerAfter`] on a new entity.
+    ///
+    /// Returns the new entity, which can be despawned to cancel the trigger.
+    fn trigger_after<E: Event>(&mut self, duration: Duration, event: E) -> Entity;
+
+    /// Triggers `event` on each of `targets` once `duration` has passed, using a [`TriggerAfter`] on a new entity.
+    ///
+    /// Returns the new entity, which can be despawned to cancel the trigger.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Event)]
+    /// # struct Explode;
+    /// # fn setup(mut commands: Commands, bomb: Entity) {
+    /// let fuse = commands.trigger_targets_after(Duration::from_secs(3), Explode, [bomb]);
+    /// // Defused!
+    /// commands.entity(fuse).despawn();
+    /// # }
+    /// ```
+    fn trigger_targets_after<E: Event>(
+        &mut self,
+        duration: Duration,
+        event: E,
+        targets: impl IntoIterator<Item = Entity>,
+    ) -> Entity;
+
+    /// Despawns `entity` and its descendants, then spawns `respawn` on a new entity to replace it.
+    ///
+    /// Returns the new entity, which [`Respawned`](crate::Respawned) is triggered on and which can be despawned to
+    /// cancel the respawn.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Bundle, Default)]
+    /// # struct EnemyBundle {
+    /// #     transform: Transform,
+    /// # }
+    /// # fn setup(mut commands: Commands, enemy: Entity) {
+    /// let respawn = RespawnAfter::from_bundle(Duration::from_secs(10), EnemyBundle::default());
+    /// let respawn = commands.respawn_after(enemy, respawn);
+    /// commands.entity(respawn).observe(|trigger: Trigger<Respawned>| {
+    ///     info!("Enemy respawned as {}", trigger.event().new_entity());
+    /// });
+    /// # }
+    /// ```
+    fn respawn_after(&mut self, entity: Entity, respawn: RespawnAfter) -> Entity;
+}
+
+impl TimerCommandsExt for Commands<'_, '_> {
+    fn pause_all_timers(&mut self) {
+        self.add(|world: &mut World| {
+            for mut timer in world.query::<&mut ObservableTimer>().iter_mut(world) {
+                if !timer.paused() {
+                    timer.pause();
+                }
+            }
+        });
+    }
+
+    fn resume_all_timers(&mut self) {
+        self.add(|world: &mut World| {
+            for mut timer in world.query::<&mut ObservableTimer>().iter_mut(world) {
+                if timer.paused() {
+                    timer.unpause();
+                }
+            }
+        });
+    }
+
+    fn cancel_all_timers<F: QueryFilter + 'static>(&mut self) {
+        self.add(|world: &mut World| {
+            let entities: Vec<Entity> = world
+                .query_filtered::<Entity, (With<ObservableTimer>, F)>()
+                .iter(world)
+                .collect();
+            for entity in entities {
+                world.entity_mut(entity).remove::<ObservableTimer>();
+            }
+        });
+    }
+
+    fn cancel_all_timers_silently<F: QueryFilter + 'static>(&mut self) {
+        self.add(|world: &mut World| {
+            let entities: Vec<Entity> = world
+                .query_filtered::<Entity, (With<ObservableTimer>, F)>()
+                .iter(world)
+                .collect();
+            for entity in entities {
+                remove_timer_silently(world.entity_mut(entity));
+            }
+        });
+    }
+
+    fn insert_resource_after<R: Resource>(&mut self, duration: Duration, resource: R) {
+        self.add(move |world: &mut World| {
+            start_resource_timer(world, duration, Some(resource));
+        });
+    }
+
+    fn remove_resource_after<R: Resource>(&mut self, duration: Duration) {
+        self.add(move |world: &mut World| {
+            start_resource_timer::<R>(world, duration, None);
+        });
+    }
+
+    fn trigger_after<E: Event>(&mut self, duration: Duration, event: E) -> Entity {
+        self.spawn(TriggerAfter::new(duration, event)).id()
+    }
+
+    fn trigger_targets_after<E: Event>(
+        &mut self,
+        duration: Duration,
+        event: E,
+        targets: impl IntoIterator<Item = Entity>,
+    ) -> Entity {
+        self.spawn(TriggerAfter::with_targets(duration, event, targets))
+            .id()
+    }
+
+    fn respawn_after(&mut self, entity: Entity, respawn: RespawnAfter) -> Entity {
+        if let Some(entity_commands) = self.get_entity(entity) {
+            entity_commands.despawn_recursive();
+        }
+        self.spawn(respawn.with_previous(entity)).id()
+    }
+}
+
+/// An extension trait for controlling the [`ObservableTimer`] on a single entity from [`EntityCommands`].
+pub trait TimerEntityCommandsExt {
+    /// Cancels the entity's [`ObservableTimer`] by removing the component, without triggering
+    /// [`TimerFinished`](crate::TimerFinished) for it.
+    ///
+    /// This is meant for cleanup, such as tearing down a level, where observers that react to cancelled timers must
+    /// not run. It does nothing if the entity has no `ObservableTimer`.
+    ///
+    /// ```
+    /// # use bevy::{ecs::world::CommandQueue, prelude::*};
+    /// # use bevy_mod_observable_timer::*;
+    /// #[derive(Resource, Default)]
+    /// struct Cancellations(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Cancellations>();
+    /// world.observe(|_: Trigger<TimerFinished>, mut cancellations: ResMut<Cancellations>| {
+    ///     cancellations.0 += 1;
+    /// });
+    /// let entity = world.spawn(ObservableTimer::once_from_seconds(1.0)).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).entity(entity).cancel_timer_silently();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.get::<ObservableTimer>(entity).is_none());
+    /// assert_eq!(world.resource::<Cancellations>().0, 0);
+    /// ```
+    fn cancel_timer_silently(&mut self) -> &mut Self;
+}
+
+impl TimerEntityCommandsExt for EntityCommands<'_> {
+    fn cancel_timer_silently(&mut self) -> &mut Self {
+        self.add(remove_timer_silently)
+    }
+}
+
+/// Marks a timer that is being cancelled silently, so that the remove hook doesn't trigger its `TimerFinished`.
+#[derive(Component)]
+pub(crate) struct TimerCancelledSilently;
+
+fn remove_timer_silently(mut entity: EntityWorldMut) {
+    if entity.contains::<ObservableTimer>() {
+        entity
+            .insert(TimerCancelledSilently)
+            .remove::<ObservableTimer>()
+            .remove::<TimerCancelledSilently>();
+    }
+}
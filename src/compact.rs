@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+    utils::Parallel,
+};
+
+use crate::{
+    update::timer_delta, ObservableTimerSystems, ObservableTimerUpdate, StopReason, TimerDirection,
+    TimerFinished, TimerInterval,
+};
+
+/// The shared clock that [`CompactTimer`]s are due against.
+///
+/// Keeping the full precision [`Duration`] here means the millisecond values stored by each timer never accumulate
+/// rounding error.
+#[derive(Resource, Default)]
+pub(crate) struct CompactClock {
+    elapsed: Duration,
+}
+
+impl CompactClock {
+    /// The current time in whole milliseconds, wrapping around after about 49 days.
+    fn now_ms(&self) -> u32 {
+        self.elapsed.as_millis() as u32
+    }
+}
+
+/// A memory-compact timer component for very large numbers of simple lifetimes and repeating timers.
+///
+/// A `CompactTimer` takes 16 bytes, compared to the couple of hundred taken by an
+/// [`ObservableTimer`](crate::ObservableTimer), which reduces memory use and cache pressure when running hundreds of
+/// thousands of timers. In exchange it has millisecond resolution and only a small set of features:
+///
+/// - A once timer triggers [`TimerFinished`] on its entity when it elapses, then removes itself, or despawns its entity
+///   if [`Self::with_despawn()`] is set.
+/// - A repeating timer triggers [`TimerInterval`] on its entity after every interval, and runs until it is removed.
+///   Intervals that complete in the same update each trigger an event.
+///
+/// Like [`DespawnAfter`](crate::DespawnAfter), compact timers aren't affected by pausing, groups, time dilation or
+/// catch-up, trigger no [`TimerStarted`](crate::TimerStarted) event, and don't carry a [`TimerTag`](crate::TimerTag).
+/// Each timer records when it is next due against a shared clock, so no rounding error builds up across intervals.
+/// Intervals are limited to about 24.8 days (`u32::MAX / 2` milliseconds), and longer ones are shortened to that.
+///
+/// Requires the [`CompactTimerPlugin`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual), CompactTimerPlugin));
+/// let world = app.world_mut();
+/// let projectile = world
+///     .spawn(CompactTimer::once(Duration::from_millis(500)).with_despawn(true))
+///     .id();
+///
+/// world.tick_observable_timers(Duration::from_millis(500));
+/// assert!(world.get_entity(projectile).is_none());
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct CompactTimer {
+    due_ms: u32,
+    interval_ms: u32,
+    elapsed_intervals: u32,
+    flags: u8,
+}
+
+impl CompactTimer {
+    /// The longest supported interval, in milliseconds.
+    ///
+    /// Timers are due once the wrapping clock is less than half a cycle past them, so a timer that is further than
+    /// half a cycle ahead of the clock would be mistaken for one that is overdue.
+    const MAX_INTERVAL_MS: u32 = u32::MAX / 2;
+
+    const REPEATING: u8 = 1 << 0;
+    const DESPAWN: u8 = 1 << 1;
+    const SCHEDULED: u8 = 1 << 2;
+
+    fn new(interval: Duration, flags: u8) -> Self {
+        Self {
+            due_ms: 0,
+            interval_ms: interval.as_millis().clamp(1, Self::MAX_INTERVAL_MS.into()) as u32,
+            elapsed_intervals: 0,
+            flags,
+        }
+    }
+
+    /// Creates a timer that finishes once `duration` has passed.
+    ///
+    /// The duration is rounded down to whole milliseconds, with a minimum of one and a maximum of about 24.8 days.
+    pub fn once(duration: Duration) -> Self {
+        Self::new(duration, 0)
+    }
+
+    /// Creates a timer that finishes once the given number of seconds have passed.
+    pub fn once_from_seconds(seconds: f32) -> Self {
+        Self::once(Duration::from_secs_f32(seconds))
+    }
+
+    /// Creates a timer that completes an interval every `interval`, indefinitely.
+    ///
+    /// The interval is rounded down to whole milliseconds, with a minimum of one and a maximum of about 24.8 days.
+    pub fn repeating(interval: Duration) -> Self {
+        Self::new(interval, Self::REPEATING)
+    }
+
+    /// Creates a timer that completes an interval every given number of seconds, indefinitely.
+    pub fn repeating_from_seconds(interval_seconds: f32) -> Self {
+        Self::repeating(Duration::from_secs_f32(interval_seconds))
+    }
+
+    /// Sets whether a once timer despawns its entity when it finishes, instead of removing itself.
+    pub fn with_despawn(mut self, despawn: bool) -> Self {
+        self.set_flag(Self::DESPAWN, despawn);
+        self
+    }
+
+    /// The length of a single interval.
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.into())
+    }
+
+    /// Returns `true` for repeating timers.
+    pub fn is_repeating(&self) -> bool {
+        self.flags & Self::REPEATING != 0
+    }
+
+    /// Returns `true` if a once timer despawns its entity when it finishes.
+    pub fn despawns(&self) -> bool {
+        self.flags & Self::DESPAWN != 0
+    }
+
+    /// The number of intervals that have elapsed.
+    pub fn elapsed_intervals(&self) -> u32 {
+        self.elapsed_intervals
+    }
+
+    fn set_flag(&mut self, flag: u8, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
+
+impl Component for CompactTimer {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_insert(on_compact_timer_inserted);
+    }
+}
+
+fn on_compact_timer_inserted(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let now_ms = world
+        .get_resource::<CompactClock>()
+        .map_or(0, CompactClock::now_ms);
+    let mut timer = world.get_mut::<CompactTimer>(entity).unwrap();
+    let timer = timer.bypass_change_detection();
+    if timer.flags & CompactTimer::SCHEDULED == 0 {
+        // Timers created by reflection haven't been through the constructors' clamping
+        timer.interval_ms = timer.interval_ms.clamp(1, CompactTimer::MAX_INTERVAL_MS);
+        timer.due_ms = now_ms.wrapping_add(timer.interval_ms);
+        timer.flags |= CompactTimer::SCHEDULED;
+    }
+}
+
+/// A [`CompactTimer`] that completed one or more intervals this update.
+pub(crate) struct CompactElapsed {
+    entity: Entity,
+    /// The counts of the intervals that completed.
+    first_count: u32,
+    intervals: u32,
+    overshoot: Duration,
+}
+
+/// A plugin that updates [`CompactTimer`]s. Requires [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), CompactTimerPlugin));
+/// ```
+pub struct CompactTimerPlugin;
+
+impl Plugin for CompactTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CompactTimer>()
+            .init_resource::<CompactClock>()
+            .add_systems(
+                ObservableTimerUpdate,
+                timer_delta
+                    .pipe(update_compact_timers)
+                    .after(ObservableTimerSystems::ApplyFinishBehavior),
+            );
+    }
+}
+
+pub(crate) fn update_compact_timers(
+    In(delta): In<Duration>,
+    mut clock: ResMut<CompactClock>,
+    mut timers: Query<(Entity, &mut CompactTimer)>,
+    mut thread_elapsed: Local<Parallel<Vec<CompactElapsed>>>,
+    mut commands: Commands,
+) {
+    clock.elapsed += delta;
+    let now_ms = clock.now_ms();
+
+    timers.par_iter_mut().for_each(|(entity, mut timer)| {
+        // The clock wraps around, so a timer is due once it is less than half a cycle behind
+        let behind_ms = now_ms.wrapping_sub(timer.due_ms);
+        if behind_ms >= u32::MAX / 2 {
+            return;
+        }
+
+        // Bypass change detection, since the due time is internal bookkeeping
+        let timer = timer.bypass_change_detection();
+        let (intervals, overshoot_ms) = if timer.is_repeating() {
+            (
+                behind_ms / timer.interval_ms + 1,
+                behind_ms % timer.interval_ms,
+            )
+        } else {
+            (1, behind_ms)
+        };
+        let first_count = timer.elapsed_intervals + 1;
+        timer.elapsed_intervals += intervals;
+        timer.due_ms = timer
+            .due_ms
+            .wrapping_add(timer.interval_ms.wrapping_mul(intervals));
+
+        let overshoot = Duration::from_millis(overshoot_ms.into());
+        thread_elapsed.scope(|elapsed| {
+            elapsed.push(CompactElapsed {
+                entity,
+                first_count,
+                intervals,
+                overshoot,
+            });
+        });
+    });
+
+    for elapsed in thread_elapsed.iter_mut() {
+        for CompactElapsed {
+            entity,
+            first_count,
+            intervals,
+            overshoot,
+        } in elapsed.drain(..)
+        {
+            let timer = timers.get(entity).unwrap().1;
+            if timer.is_repeating() {
+                for count in first_count..first_count + intervals {
+                    commands.trigger_targets(TimerInterval { count, tag: None }, entity);
+                }
+                continue;
+            }
+
+            let finished = TimerFinished {
+                reason: StopReason::Finished,
+                condition_met: false,
+                overshoot,
+                interval_duration: timer.interval(),
+                direction: TimerDirection::Forward,
+                tag: None,
+            };
+            commands.trigger_targets(finished, entity);
+            if timer.despawns() {
+                commands.entity(entity).despawn();
+            } else {
+                commands.entity(entity).remove::<CompactTimer>();
+            }
+        }
+    }
+}
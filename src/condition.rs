@@ -0,0 +1,84 @@
+use bevy::{ecs::system::BoxedSystem, prelude::*};
+
+use crate::ObservableTimer;
+
+/// A component that cancels its entity's [`ObservableTimer`] early once a condition becomes `true`.
+///
+/// The condition is a system that takes the timer's entity as input, and is checked on every timer update before the
+/// timer is ticked. Once it returns `true` the timer component is removed, which triggers
+/// [`TimerFinished`](crate::TimerFinished) with [`cancelled_by_condition()`](crate::TimerFinished::cancelled_by_condition)
+/// set to `true`. The finish behavior isn't performed, just as when a timer is cancelled manually.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands) {
+/// // Interrupt the cast if the caster moves
+/// fn caster_moved(In(entity): In<Entity>, casters: Query<Ref<Transform>>) -> bool {
+///     casters.get(entity).is_ok_and(|transform| transform.is_changed())
+/// }
+///
+/// commands
+///     .spawn((ObservableTimer::once_from_seconds(2.5), TimerCancelCondition::new(caster_moved)))
+///     .observe(|trigger: Trigger<TimerFinished>| {
+///         if trigger.event().cancelled_by_condition() {
+///             info!("Cast interrupted");
+///         }
+///     });
+/// # }
+/// ```
+#[derive(Component)]
+pub struct TimerCancelCondition {
+    condition: Option<BoxedSystem<Entity, bool>>,
+    initialized: bool,
+}
+
+impl TimerCancelCondition {
+    /// Creates a `TimerCancelCondition` from a system that takes the timer's entity as input.
+    pub fn new<M>(condition: impl IntoSystem<Entity, bool, M>) -> Self {
+        Self {
+            condition: Some(Box::new(IntoSystem::into_system(condition))),
+            initialized: false,
+        }
+    }
+}
+
+/// Marks a timer that is being cancelled because its [`TimerCancelCondition`] was met, for the remove hook to report.
+#[derive(Component)]
+pub(crate) struct TimerConditionMet;
+
+pub(crate) fn check_timer_cancel_conditions(
+    world: &mut World,
+    timers: &mut QueryState<Entity, (With<TimerCancelCondition>, With<ObservableTimer>)>,
+) {
+    let entities: Vec<Entity> = timers.iter(world).collect();
+    for entity in entities {
+        // The condition is taken out of its component while it runs, since it needs the whole world
+        let Some(mut cancel_condition) = world.get_mut::<TimerCancelCondition>(entity) else {
+            continue;
+        };
+        let initialized = std::mem::replace(&mut cancel_condition.initialized, true);
+        let Some(mut condition) = cancel_condition.condition.take() else {
+            continue;
+        };
+
+        if !initialized {
+            condition.initialize(world);
+        }
+        let met = condition.run(entity, world);
+        condition.apply_deferred(world);
+
+        if let Some(mut cancel_condition) = world.get_mut::<TimerCancelCondition>(entity) {
+            cancel_condition.condition = Some(condition);
+        }
+
+        if met {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity
+                    .insert(TimerConditionMet)
+                    .remove::<ObservableTimer>()
+                    .remove::<TimerConditionMet>();
+            }
+        }
+    }
+}
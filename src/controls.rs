@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::ObservableTimer;
+
+/// A [`SystemParam`] for controlling individual [`ObservableTimer`]s by entity.
+///
+/// Each method takes the timer's entity, and does nothing (returning `false` or `None`) if the entity doesn't have an
+/// `ObservableTimer`.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Resource)]
+/// # struct CooldownTimer(Entity);
+/// fn toggle_pause(input: Res<ButtonInput<KeyCode>>, mut timers: TimerControls, cooldown: Res<CooldownTimer>) {
+///     if input.just_pressed(KeyCode::KeyP) && !timers.resume(cooldown.0) {
+///         timers.pause(cooldown.0);
+///     }
+/// }
+///
+/// fn show_cooldown(timers: TimerControls, cooldown: Res<CooldownTimer>) {
+///     if let Some(remaining) = timers.remaining(cooldown.0) {
+///         info!("Cooldown: {:.1}s", remaining.as_secs_f32());
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct TimerControls<'w, 's> {
+    timers: Query<'w, 's, &'static mut ObservableTimer>,
+    commands: Commands<'w, 's>,
+}
+
+impl TimerControls<'_, '_> {
+    /// Returns a reference to the entity's timer.
+    pub fn get(&self, entity: Entity) -> Option<&ObservableTimer> {
+        self.timers.get(entity).ok()
+    }
+
+    /// Returns a mutable reference to the entity's timer.
+    pub fn get_mut(&mut self, entity: Entity) -> Option<Mut<'_, ObservableTimer>> {
+        self.timers.get_mut(entity).ok()
+    }
+
+    /// Returns `true` if the entity has a timer.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.timers.contains(entity)
+    }
+
+    /// Returns whether the entity's timer is paused.
+    pub fn is_paused(&self, entity: Entity) -> Option<bool> {
+        self.get(entity).map(ObservableTimer::paused)
+    }
+
+    /// Pauses the entity's timer. Returns `true` if the timer was running.
+    pub fn pause(&mut self, entity: Entity) -> bool {
+        match self.get_mut(entity) {
+            Some(mut timer) if !timer.paused() => {
+                timer.pause();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Resumes the entity's timer. Returns `true` if the timer was paused.
+    pub fn resume(&mut self, entity: Entity) -> bool {
+        match self.get_mut(entity) {
+            Some(mut timer) if timer.paused() => {
+                timer.unpause();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Restarts the entity's timer. See [`ObservableTimer::restart()`]. Returns `true` if the entity has a timer.
+    pub fn restart(&mut self, entity: Entity) -> bool {
+        let Some(mut timer) = self.get_mut(entity) else {
+            return false;
+        };
+        timer.restart();
+        true
+    }
+
+    /// The time remaining until the entity's timer finishes. See [`ObservableTimer::remaining()`].
+    ///
+    /// This is `None` if the entity has no timer, or if the timer runs indefinitely.
+    pub fn remaining(&self, entity: Entity) -> Option<Duration> {
+        self.get(entity)?.remaining()
+    }
+
+    /// The time elapsed since the entity's timer started. See [`ObservableTimer::elapsed()`].
+    pub fn elapsed(&self, entity: Entity) -> Option<Duration> {
+        self.get(entity).map(ObservableTimer::elapsed)
+    }
+
+    /// Cancels the entity's timer by removing the component. Returns `true` if the entity has a timer.
+    ///
+    /// The timer is removed when commands are next applied, which triggers [`TimerFinished`](crate::TimerFinished)
+    /// with [`cancelled()`](crate::TimerFinished::cancelled) set to `true` if it hadn't finished yet.
+    pub fn cancel(&mut self, entity: Entity) -> bool {
+        if !self.timers.contains(entity) {
+            return false;
+        }
+        self.commands.entity(entity).remove::<ObservableTimer>();
+        true
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    fmt,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{
+    start::{flush_timer_start, queue_timer_start, trigger_timer_starts},
+    trigger_cancelled, ObservableTimerUpdate, StopReason, TimerDirection, TimerFinished,
+    TimerInterval, TimerTag,
+};
+
+/// A [`Resource`] that selects the clock [`CronTimer`]s are evaluated against.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum CronClock {
+    /// The virtual time elapsed since startup ([`Time<Virtual>`]), which respects pausing and time scaling.
+    ///
+    /// This is the default clock.
+    #[default]
+    Virtual,
+    /// The real time elapsed since startup ([`Time<Real>`]).
+    Real,
+    /// The system's wall-clock time (UTC), measured from the Unix epoch.
+    System,
+}
+
+/// A timer component that triggers on a cron-like schedule, rather than after a fixed number of intervals.
+///
+/// Schedules are written in a small English-like syntax:
+/// - `every 5 minutes`, `every 30 seconds`, `every hour`, `every 2 days`: Triggers whenever the clock passes a
+///   multiple of the given period (so `every 5 minutes` triggers at :00, :05, :10, and so on).
+/// - `at :00 and :30`, `at :15, :45`: Triggers at the given minutes past every hour.
+/// - `at 08:00`, `at 06:00 and 18:30`: Triggers at the given times every day.
+///
+/// The schedule is evaluated against the [`CronClock`] resource. Like [`ObservableTimer`](crate::ObservableTimer), a
+/// [`TimerStarted`](crate::TimerStarted) event is triggered on the next timer update after the component is inserted,
+/// and a [`TimerFinished`] event (with [`TimerFinished::cancelled()`] set to `true`) when it is removed. Each time the
+/// schedule triggers, a [`TimerInterval`] event is triggered. If the clock passes several scheduled times in one
+/// update, only a single event is triggered.
+///
+/// Requires the [`CronTimerPlugin`].
+///
+/// ```
+/// # use bevy_mod_observable_timer::*;
+/// let half_hourly: CronTimer = "at :00 and :30".parse().unwrap();
+/// let frequent = CronTimer::new("every 5 minutes").unwrap();
+/// assert!(CronTimer::new("whenever").is_err());
+/// ```
+#[derive(Reflect, Debug, Clone)]
+#[reflect(Component, Debug)]
+pub struct CronTimer {
+    schedule: CronSchedule,
+    count: u32,
+    next: Option<Duration>,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+}
+
+impl CronTimer {
+    /// Parses a schedule expression into a new `CronTimer`. See [`CronTimer`] for the supported syntax.
+    pub fn new(expression: &str) -> Result<Self, CronParseError> {
+        expression.parse()
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The number of times the schedule has triggered.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The clock time at which the schedule will next trigger, once it has been evaluated for the first time.
+    ///
+    /// This is `None` before the first evaluation, and if the schedule has no occurrences left before [`Duration::MAX`].
+    pub fn next(&self) -> Option<Duration> {
+        self.next
+    }
+
+    /// Skips any occurrences that have already passed, so the schedule next triggers at its first occurrence after
+    /// the following update.
+    pub(crate) fn skip_missed(&mut self) {
+        self.next = None;
+    }
+}
+
+impl FromStr for CronTimer {
+    type Err = CronParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            schedule: expression.parse()?,
+            count: 0,
+            next: None,
+            tag: None,
+        })
+    }
+}
+
+impl Component for CronTimer {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_insert(on_cron_timer_inserted)
+            .on_remove(on_cron_timer_removed);
+    }
+}
+
+/// The error returned when a [`CronTimer`] schedule expression can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError {
+    expression: String,
+}
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid cron timer schedule `{}`", self.expression)
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+/// A parsed [`CronTimer`] schedule.
+#[derive(Reflect, Debug, Clone, PartialEq, Eq)]
+enum CronSchedule {
+    /// Every multiple of the period.
+    Every(Duration),
+    /// At each of the given offsets into each period.
+    At {
+        period: Duration,
+        offsets: Vec<Duration>,
+    },
+}
+
+impl CronSchedule {
+    /// The first scheduled time that is strictly after `now`, or `None` if there is none that a [`Duration`] can
+    /// represent.
+    fn next_after(&self, now: Duration) -> Option<Duration> {
+        match self {
+            CronSchedule::Every(period) => next_multiple_after(now, *period, Duration::ZERO),
+            CronSchedule::At { period, offsets } => offsets
+                .iter()
+                .filter_map(|offset| next_multiple_after(now, *period, *offset))
+                .min(),
+        }
+    }
+}
+
+/// The first time `offset + n * period` that is strictly after `now`, or `None` if it would overflow a [`Duration`].
+fn next_multiple_after(now: Duration, period: Duration, offset: Duration) -> Option<Duration> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    let period = period.as_nanos().max(1);
+    let offset = offset.as_nanos();
+    let now = now.as_nanos();
+    let next = if now < offset {
+        offset
+    } else {
+        offset + ((now - offset) / period + 1) * period
+    };
+    let secs = u64::try_from(next / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (next % NANOS_PER_SEC) as u32))
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let error = || CronParseError {
+            expression: expression.to_string(),
+        };
+        let lowercase = expression.trim().to_lowercase();
+
+        if let Some(rest) = lowercase.strip_prefix("every ") {
+            let mut words = rest.split_whitespace();
+            let (count, unit): (u64, _) = match (words.next(), words.next(), words.next()) {
+                (Some(unit), None, None) => (1, unit),
+                (Some(count), Some(unit), None) => (count.parse().map_err(|_| error())?, unit),
+                _ => return Err(error()),
+            };
+            let unit_secs = match unit.trim_end_matches('s') {
+                "second" | "sec" => 1,
+                "minute" | "min" => 60,
+                "hour" => 3600,
+                "day" => 86400,
+                _ => return Err(error()),
+            };
+            if count == 0 {
+                return Err(error());
+            }
+            let secs = count.checked_mul(unit_secs).ok_or_else(error)?;
+            return Ok(CronSchedule::Every(Duration::from_secs(secs)));
+        }
+
+        if let Some(rest) = lowercase.strip_prefix("at ") {
+            let mut period = None;
+            let mut offsets = Vec::new();
+            for time in rest
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|time| !time.is_empty() && *time != "and")
+            {
+                let (hours, minutes) = time.split_once(':').ok_or_else(error)?;
+                let minutes: u64 = minutes.parse().map_err(|_| error())?;
+                let (time_period, hours) = match hours {
+                    "" => (Duration::from_secs(3600), 0),
+                    hours => (
+                        Duration::from_secs(86400),
+                        hours.parse().map_err(|_| error())?,
+                    ),
+                };
+                if minutes >= 60 || hours >= 24 || *period.get_or_insert(time_period) != time_period
+                {
+                    return Err(error());
+                }
+                offsets.push(Duration::from_secs(hours * 3600 + minutes * 60));
+            }
+            return match period {
+                Some(period) => Ok(CronSchedule::At { period, offsets }),
+                None => Err(error()),
+            };
+        }
+
+        Err(error())
+    }
+}
+
+fn on_cron_timer_inserted(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    let tag = world.get::<CronTimer>(entity).unwrap().tag;
+    queue_timer_start(&mut world, entity, component, tag, false);
+}
+
+fn on_cron_timer_removed(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    flush_timer_start(&mut world, entity, component);
+    let tag = world.get::<CronTimer>(entity).unwrap().tag;
+    trigger_cancelled(
+        &mut world,
+        TimerFinished {
+            reason: StopReason::Cancelled,
+            condition_met: false,
+            overshoot: Duration::ZERO,
+            interval_duration: Duration::ZERO,
+            direction: TimerDirection::Forward,
+            tag,
+        },
+        entity,
+    );
+}
+
+/// A plugin that updates [`CronTimer`]s. Requires [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), CronTimerPlugin));
+/// ```
+pub struct CronTimerPlugin;
+
+impl Plugin for CronTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CronTimer>()
+            .register_type::<CronClock>()
+            .init_resource::<CronClock>()
+            .add_systems(
+                ObservableTimerUpdate,
+                update_cron_timers.after(trigger_timer_starts),
+            );
+    }
+}
+
+pub(crate) fn update_cron_timers(
+    clock: Option<Res<CronClock>>,
+    virtual_time: Option<Res<Time<Virtual>>>,
+    real_time: Option<Res<Time<Real>>>,
+    mut timers: Query<(Entity, &mut CronTimer)>,
+    mut commands: Commands,
+) {
+    let now = match clock.as_deref().copied().unwrap_or_default() {
+        CronClock::Virtual => virtual_time.map(|time| time.elapsed()),
+        CronClock::Real => real_time.map(|time| time.elapsed()),
+        CronClock::System => Some(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default(),
+        ),
+    };
+    let Some(now) = now else {
+        return;
+    };
+
+    for (entity, mut timer) in timers.iter_mut() {
+        match timer.next {
+            Some(next) if now >= next => {
+                timer.count += 1;
+                let count = timer.count;
+                let tag = timer.tag;
+                commands.trigger_targets(TimerInterval { count, tag }, entity);
+            }
+            Some(_) => continue,
+            None => {}
+        }
+        timer.next = timer.schedule.next_after(now);
+    }
+}
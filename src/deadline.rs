@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{
+    start::{flush_timer_start, queue_timer_start, trigger_timer_starts},
+    trigger_cancelled, ObservableTimerUpdate, StopReason, TimerDirection, TimerFinishBehavior,
+    TimerFinished, TimerTag,
+};
+
+/// A timer component that finishes once [`Time::elapsed()`] reaches a target timestamp.
+///
+/// Unlike [`ObservableTimer`](crate::ObservableTimer), which counts down its remaining time, a `Deadline` stores the
+/// absolute time it finishes at. Cloning, moving or serializing it therefore can't skew the target, and many deadlines
+/// can be compared by simply sorting them by [`Self::at()`].
+///
+/// The target is compared against the [`Time`] resource of the schedule that timers update in, so deadlines follow
+/// virtual time (including pausing and relative speed) rather than the delta that timers are advanced by. Deadlines
+/// are only checked when timers update, so with [`TimerTickMode::Manual`](crate::TimerTickMode::Manual) they finish on
+/// the first call to
+/// [`ObservableTimerWorldExt::tick_observable_timers()`](crate::ObservableTimerWorldExt::tick_observable_timers) after
+/// their target.
+///
+/// A [`TimerStarted`](crate::TimerStarted) event is triggered on the next timer update after the component is
+/// inserted, and a [`TimerFinished`] event once the target is reached, with [`TimerFinished::overshoot()`] holding how
+/// long ago that was. Removing the component before then triggers a cancelled `TimerFinished` event. After finishing
+/// the [`TimerFinishBehavior`] is performed, where
+/// [`TimerFinishBehavior::RemoveComponent`] removes the `Deadline` component, and
+/// [`TimerFinishBehavior::ReplaceWith`] replaces it with the given [`ObservableTimer`](crate::ObservableTimer).
+///
+/// Requires the [`DeadlinePlugin`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut time = Time::<()>::default();
+/// # time.advance_by(Duration::from_secs(10));
+/// let mut deadlines = vec![
+///     Deadline::after(&time, Duration::from_secs(30)),
+///     Deadline::new(Duration::from_secs(20)),
+/// ];
+/// deadlines.sort_by_key(Deadline::at);
+/// assert_eq!(deadlines[0].remaining(&time), Duration::from_secs(10));
+/// ```
+#[derive(Reflect, Debug, Clone)]
+#[reflect(Component, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct Deadline {
+    at: Duration,
+    finished: bool,
+    /// The behavior to perform once the deadline has been reached.
+    pub finish_behavior: TimerFinishBehavior,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+}
+
+impl Deadline {
+    /// Create a new `Deadline` that finishes once [`Time::elapsed()`] reaches `at`.
+    pub fn new(at: Duration) -> Self {
+        Self {
+            at,
+            finished: false,
+            finish_behavior: TimerFinishBehavior::default(),
+            tag: None,
+        }
+    }
+
+    /// Create a new `Deadline` that finishes once `duration` has passed from the current time.
+    pub fn after<T: Default>(time: &Time<T>, duration: Duration) -> Self {
+        Self::new(time.elapsed() + duration)
+    }
+
+    /// Sets the [`TimerFinishBehavior`].
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The [`Time::elapsed()`] at which this finishes.
+    pub fn at(&self) -> Duration {
+        self.at
+    }
+
+    /// `true` once the deadline has been reached.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The time remaining until the deadline, or zero if it has already been reached.
+    pub fn remaining<T: Default>(&self, time: &Time<T>) -> Duration {
+        self.at.saturating_sub(time.elapsed())
+    }
+}
+
+impl Component for Deadline {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_insert(on_deadline_inserted)
+            .on_remove(on_deadline_removed);
+    }
+}
+
+fn on_deadline_inserted(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    let tag = world.get::<Deadline>(entity).unwrap().tag;
+    queue_timer_start(&mut world, entity, component, tag, false);
+}
+
+fn on_deadline_removed(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    flush_timer_start(&mut world, entity, component);
+    let deadline = world.get::<Deadline>(entity).unwrap();
+    let (finished, tag) = (deadline.finished, deadline.tag);
+    if !finished {
+        trigger_cancelled(
+            &mut world,
+            TimerFinished {
+                reason: StopReason::Cancelled,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag,
+            },
+            entity,
+        );
+    }
+}
+
+/// A plugin that updates [`Deadline`]s. Requires [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), DeadlinePlugin));
+/// ```
+pub struct DeadlinePlugin;
+
+impl Plugin for DeadlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Deadline>().add_systems(
+            ObservableTimerUpdate,
+            update_deadlines.after(trigger_timer_starts),
+        );
+    }
+}
+
+pub(crate) fn update_deadlines(
+    time: Option<Res<Time>>,
+    mut deadlines: Query<(Entity, &mut Deadline)>,
+    mut commands: Commands,
+) {
+    let Some(now) = time.map(|time| time.elapsed()) else {
+        return;
+    };
+
+    for (entity, mut deadline) in deadlines.iter_mut() {
+        if deadline.finished || now < deadline.at {
+            continue;
+        }
+
+        deadline.finished = true;
+        commands.trigger_targets(
+            TimerFinished {
+                reason: StopReason::Finished,
+                condition_met: false,
+                overshoot: now - deadline.at,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag: deadline.tag,
+            },
+            entity,
+        );
+
+        match &deadline.finish_behavior {
+            TimerFinishBehavior::None => {}
+            TimerFinishBehavior::RemoveComponent => {
+                commands.entity(entity).remove::<Deadline>();
+            }
+            TimerFinishBehavior::DespawnEntity => {
+                commands.entity(entity).despawn();
+            }
+            TimerFinishBehavior::DespawnRecursive => {
+                commands.entity(entity).despawn_recursive();
+            }
+            TimerFinishBehavior::ReplaceWith(timer) => {
+                commands
+                    .entity(entity)
+                    .remove::<Deadline>()
+                    .insert(timer.as_ref().clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::{color::palettes::css, prelude::*};
+
+use crate::ObservableTimer;
+
+/// A plugin that draws a gizmo above every [`ObservableTimer`] entity with a [`GlobalTransform`], showing the
+/// fraction of the timer's time remaining.
+///
+/// This requires the `debug_gizmos` feature, and bevy's `GizmoPlugin` (included in `DefaultPlugins`). The gizmos can be
+/// toggled and styled with the [`TimerGizmos`] resource. They are drawn in the XY plane, which suits 2D scenes and
+/// side-on views of 3D scenes.
+pub struct TimerGizmosPlugin;
+
+impl Plugin for TimerGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimerGizmos>()
+            .add_systems(Update, draw_timer_gizmos);
+    }
+}
+
+/// A [`Resource`] that controls the gizmos drawn by [`TimerGizmosPlugin`].
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TimerGizmos {
+    /// Whether the gizmos are drawn. Defaults to `true`.
+    pub enabled: bool,
+    /// The shape of the gizmos. Defaults to [`TimerGizmoStyle::Radial`].
+    pub style: TimerGizmoStyle,
+    /// The offset from each entity's translation at which its gizmo is drawn. Defaults to 32 units up.
+    pub offset: Vec3,
+    /// The radius of radial gizmos, or the half-width of bar gizmos. Defaults to 8 units.
+    pub size: f32,
+    /// The color of the remaining time.
+    pub color: Color,
+    /// The color of the elapsed time.
+    pub background_color: Color,
+    /// The color of paused timers' remaining time.
+    pub paused_color: Color,
+}
+
+impl Default for TimerGizmos {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            style: TimerGizmoStyle::default(),
+            offset: Vec3::new(0.0, 32.0, 0.0),
+            size: 8.0,
+            color: css::LIME.into(),
+            background_color: css::DIM_GRAY.into(),
+            paused_color: css::GOLD.into(),
+        }
+    }
+}
+
+/// The shape of the gizmos drawn by [`TimerGizmosPlugin`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimerGizmoStyle {
+    /// A circle, with an arc that sweeps away from the top as time elapses.
+    ///
+    /// This is the default style.
+    #[default]
+    Radial,
+    /// A horizontal bar that shrinks towards the left as time elapses.
+    Bar,
+}
+
+fn draw_timer_gizmos(
+    settings: Res<TimerGizmos>,
+    timers: Query<(&ObservableTimer, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    // Arcs are drawn counterclockwise in the XZ plane starting from +X, so this turns them to face +Z and start at +Y
+    let arc_rotation = Quat::from_rotation_z(FRAC_PI_2) * Quat::from_rotation_arc(Vec3::Y, Vec3::Z);
+
+    for (timer, transform) in timers.iter() {
+        let position = transform.translation() + settings.offset;
+        let fraction = timer.fraction_remaining();
+        let color = if timer.paused() {
+            settings.paused_color
+        } else {
+            settings.color
+        };
+
+        match settings.style {
+            TimerGizmoStyle::Radial => {
+                gizmos.circle(position, Dir3::Z, settings.size, settings.background_color);
+                if fraction > 0.0 {
+                    gizmos.arc_3d(fraction * TAU, settings.size, position, arc_rotation, color);
+                }
+            }
+            TimerGizmoStyle::Bar => {
+                let left = position - Vec3::X * settings.size;
+                gizmos.line(
+                    left,
+                    position + Vec3::X * settings.size,
+                    settings.background_color,
+                );
+                if fraction > 0.0 {
+                    let right = left + Vec3::X * (2.0 * settings.size * fraction);
+                    gizmos.line(left, right, color);
+                }
+            }
+        }
+    }
+}
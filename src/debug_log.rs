@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::{StopReason, TimerFinished, TimerReplaced, TimerStarted};
+
+/// Formats an entity for logging, including its [`Name`] if it has one.
+fn describe(entity: Entity, names: &Query<&Name>) -> String {
+    match names.get(entity) {
+        Ok(name) => format!("{entity} ({name})"),
+        Err(_) => entity.to_string(),
+    }
+}
+
+pub(crate) fn log_timer_started(trigger: Trigger<TimerStarted>, names: Query<&Name>) {
+    debug!("timer started on {}", describe(trigger.entity(), &names));
+}
+
+pub(crate) fn log_timer_replaced(trigger: Trigger<TimerReplaced>, names: Query<&Name>) {
+    debug!("timer replaced on {}", describe(trigger.entity(), &names));
+}
+
+pub(crate) fn log_timer_finished(trigger: Trigger<TimerFinished>, names: Query<&Name>) {
+    let entity = describe(trigger.entity(), &names);
+    match trigger.event().reason() {
+        StopReason::Finished => debug!(
+            "timer finished on {entity} (overshoot {:?})",
+            trigger.event().overshoot()
+        ),
+        StopReason::Cancelled => debug!("timer cancelled on {entity}"),
+        StopReason::EntityDespawned => debug!("timer cancelled on {entity} by despawning it"),
+        StopReason::Replaced => debug!("timer cancelled on {entity} by replacing it"),
+    }
+}
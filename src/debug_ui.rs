@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::ObservableTimer;
+
+/// A plugin that shows an egui window listing every live [`ObservableTimer`], with buttons to pause, resume, cancel or
+/// restart each one.
+///
+/// This requires the `bevy_egui` feature, and `bevy_egui`'s `EguiPlugin` to be added to the app. The window can be
+/// hidden with the [`TimerDebugPanel`] resource.
+pub struct TimerDebugPanelPlugin;
+
+impl Plugin for TimerDebugPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimerDebugPanel>()
+            .add_systems(Update, show_timer_debug_panel);
+    }
+}
+
+/// A [`Resource`] that controls the window shown by [`TimerDebugPanelPlugin`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerDebugPanel {
+    /// Whether the window is shown. Defaults to `true`.
+    pub open: bool,
+}
+
+impl Default for TimerDebugPanel {
+    fn default() -> Self {
+        Self { open: true }
+    }
+}
+
+fn show_timer_debug_panel(
+    mut panel: ResMut<TimerDebugPanel>,
+    mut contexts: EguiContexts,
+    mut timers: Query<(Entity, Option<&Name>, &mut ObservableTimer)>,
+    mut commands: Commands,
+) {
+    if !panel.open {
+        return;
+    }
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+
+    egui::Window::new("Observable Timers")
+        .open(&mut panel.open)
+        .show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("observable_timers")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Entity");
+                        ui.strong("Intervals");
+                        ui.strong("Remaining");
+                        ui.strong("State");
+                        ui.end_row();
+
+                        for (entity, name, mut timer) in timers.iter_mut() {
+                            match name {
+                                Some(name) => ui.label(format!("{name} ({entity})")),
+                                None => ui.label(entity.to_string()),
+                            };
+                            match timer.remaining_intervals() {
+                                Some(remaining) => ui.label(format!(
+                                    "{} / {} x {:.2?}",
+                                    timer.elapsed_intervals(),
+                                    timer.elapsed_intervals() + remaining,
+                                    timer.interval_duration()
+                                )),
+                                None => ui.label(format!(
+                                    "{} / \u{221e} x {:.2?}",
+                                    timer.elapsed_intervals(),
+                                    timer.interval_duration()
+                                )),
+                            };
+                            match timer.remaining() {
+                                Some(remaining) => ui.label(format!("{remaining:.2?}")),
+                                None => ui.label(format!("{:.2?}", timer.interval_remaining())),
+                            };
+                            ui.label(if timer.is_done() {
+                                "Done"
+                            } else if timer.paused() {
+                                "Paused"
+                            } else {
+                                "Running"
+                            });
+
+                            if timer.paused() {
+                                if ui.button("Resume").clicked() {
+                                    timer.unpause();
+                                }
+                            } else if ui.button("Pause").clicked() {
+                                timer.pause();
+                            }
+                            if ui.button("Restart").clicked() {
+                                timer.restart();
+                            }
+                            if ui.button("Cancel").clicked() {
+                                commands.entity(entity).remove::<ObservableTimer>();
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+}
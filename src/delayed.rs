@@ -0,0 +1,389 @@
+use std::{any::TypeId, marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        system::SystemId,
+        world::DeferredWorld,
+    },
+    prelude::*,
+    utils::HashSet,
+};
+
+/// A component that inserts a [`Bundle`] on its entity once the given time has passed, then removes itself.
+///
+/// This covers delayed activation patterns ("become collidable after 0.2s") without writing a timer and observer
+/// pair. Like [`DespawnAfter`](crate::DespawnAfter), it is updated alongside
+/// [`ObservableTimer`](crate::ObservableTimer)s but triggers no timer events.
+///
+/// The contained [`Duration`] is the time remaining, and may be changed at any point. Each bundle type is set up the
+/// first time an `InsertAfter` for it is added, so there's nothing to register beforehand.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Component)]
+/// struct Collidable;
+///
+/// let mut world = World::new();
+/// let projectile = world.spawn(InsertAfter(Duration::from_millis(200), Collidable)).id();
+///
+/// world.tick_observable_timers(Duration::from_millis(200));
+/// assert!(world.get::<Collidable>(projectile).is_some());
+/// assert!(world.get::<InsertAfter<Collidable>>(projectile).is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct InsertAfter<B: Bundle>(pub Duration, pub B);
+
+impl<B: Bundle> InsertAfter<B> {
+    /// Create a new `InsertAfter` that inserts `bundle` after the given number of seconds.
+    pub fn from_seconds(seconds: f32, bundle: B) -> Self {
+        Self(Duration::from_secs_f32(seconds), bundle)
+    }
+}
+
+impl<B: Bundle> Component for InsertAfter<B> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_insert_after::<B>);
+        });
+    }
+}
+
+fn update_insert_after<B: Bundle>(
+    In(delta): In<Duration>,
+    mut delayed: Query<(Entity, &mut InsertAfter<B>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut insert_after) in delayed.iter_mut() {
+        let insert_after = insert_after.bypass_change_detection();
+        insert_after.0 = insert_after.0.saturating_sub(delta);
+        if insert_after.0.is_zero() {
+            commands
+                .entity(entity)
+                .add(|entity: Entity, world: &mut World| {
+                    // The entity may have been despawned since
+                    let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                        return;
+                    };
+                    if let Some(InsertAfter(_, bundle)) = entity_mut.take::<InsertAfter<B>>() {
+                        entity_mut.insert(bundle);
+                    }
+                });
+        }
+    }
+}
+
+/// A component that removes the component `T` from its entity once the given time has passed, then removes itself.
+///
+/// This is the usual pattern for buff and debuff expiry. When `T` is removed an [`EffectExpired<T>`] event is triggered
+/// on the entity, holding the removed component. Nothing is triggered if the entity no longer has a `T` by then. Like
+/// [`InsertAfter`], each component type is set up the first time a `RemoveAfter` for it is added.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Component)]
+/// # struct Haste {
+/// #     speed_bonus: f32,
+/// # }
+/// # fn setup(mut commands: Commands, player: Entity) {
+/// commands
+///     .entity(player)
+///     .insert((Haste { speed_bonus: 2.0 }, RemoveAfter::<Haste>::from_seconds(10.0)))
+///     .observe(|trigger: Trigger<EffectExpired<Haste>>| {
+///         info!("Haste wore off (+{} speed)", trigger.event().component().speed_bonus);
+///     });
+/// # }
+/// ```
+pub struct RemoveAfter<T: Component> {
+    /// The time remaining until `T` is removed.
+    pub remaining: Duration,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> RemoveAfter<T> {
+    /// Create a new `RemoveAfter` that removes `T` once `duration` has passed.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            remaining: duration,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a new `RemoveAfter` that removes `T` after the given number of seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+}
+
+impl<T: Component> Clone for RemoveAfter<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.remaining)
+    }
+}
+
+impl<T: Component> std::fmt::Debug for RemoveAfter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoveAfter")
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<T: Component> Component for RemoveAfter<T> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_remove_after::<T>);
+        });
+    }
+}
+
+/// An [`Event`] triggered on an entity when a [`RemoveAfter<T>`] removes its component `T`.
+#[derive(Event, Debug)]
+pub struct EffectExpired<T: Component> {
+    component: T,
+}
+
+impl<T: Component> EffectExpired<T> {
+    /// The component that was removed.
+    pub fn component(&self) -> &T {
+        &self.component
+    }
+}
+
+fn update_remove_after<T: Component>(
+    In(delta): In<Duration>,
+    mut delayed: Query<(Entity, &mut RemoveAfter<T>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut remove_after) in delayed.iter_mut() {
+        let remove_after = remove_after.bypass_change_detection();
+        remove_after.remaining = remove_after.remaining.saturating_sub(delta);
+        if remove_after.remaining.is_zero() {
+            commands
+                .entity(entity)
+                .add(|entity: Entity, world: &mut World| {
+                    let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                        return;
+                    };
+                    entity_mut.remove::<RemoveAfter<T>>();
+                    if let Some(component) = entity_mut.take::<T>() {
+                        world.trigger_targets(EffectExpired { component }, entity);
+                    }
+                });
+        }
+    }
+}
+
+/// A component that sends a buffered [`Event`] once the given time has passed, then removes itself.
+///
+/// The event is written to [`Events<E>`], so it must have been added with [`App::add_event()`], and is read with an
+/// ordinary [`EventReader`]. If the entity has no other components once the `EventAfter` is removed, it is despawned,
+/// so `EventAfter` can be spawned on its own. Like [`InsertAfter`], each event type is set up the first time an
+/// `EventAfter` for it is added.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Event)]
+/// # struct GameOver;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(EventAfter::from_seconds(3.0, GameOver));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventAfter<E: Event>(pub Duration, pub E);
+
+impl<E: Event> EventAfter<E> {
+    /// Create a new `EventAfter` that sends `event` after the given number of seconds.
+    pub fn from_seconds(seconds: f32, event: E) -> Self {
+        Self(Duration::from_secs_f32(seconds), event)
+    }
+}
+
+impl<E: Event> Component for EventAfter<E> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_event_after::<E>);
+        });
+    }
+}
+
+fn update_event_after<E: Event>(
+    In(delta): In<Duration>,
+    mut delayed: Query<(Entity, &mut EventAfter<E>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut event_after) in delayed.iter_mut() {
+        let event_after = event_after.bypass_change_detection();
+        event_after.0 = event_after.0.saturating_sub(delta);
+        if event_after.0.is_zero() {
+            commands
+                .entity(entity)
+                .add(|entity: Entity, world: &mut World| {
+                    let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                        return;
+                    };
+                    let Some(EventAfter(_, event)) = entity_mut.take::<EventAfter<E>>() else {
+                        return;
+                    };
+                    if entity_mut.archetype().component_count() == 0 {
+                        entity_mut.despawn();
+                    }
+                    world.send_event(event);
+                });
+        }
+    }
+}
+
+/// A component that triggers an observer [`Event`] once the given time has passed, then removes itself.
+///
+/// This is usually created with [`TimerCommandsExt::trigger_after()`] or
+/// [`TimerCommandsExt::trigger_targets_after()`], which spawn it on a new entity and return that entity. Despawning the
+/// entity before then cancels the trigger. As with [`EventAfter`], the entity is despawned once the event has been
+/// triggered if it has no other components.
+///
+/// [`TimerCommandsExt::trigger_after()`]: crate::TimerCommandsExt::trigger_after
+/// [`TimerCommandsExt::trigger_targets_after()`]: crate::TimerCommandsExt::trigger_targets_after
+#[derive(Debug, Clone)]
+pub struct TriggerAfter<E: Event> {
+    /// The time remaining until the event is triggered.
+    pub remaining: Duration,
+    event: E,
+    targets: Vec<Entity>,
+}
+
+impl<E: Event> TriggerAfter<E> {
+    /// Create a new `TriggerAfter` that triggers `event` globally once `duration` has passed.
+    pub fn new(duration: Duration, event: E) -> Self {
+        Self::with_targets(duration, event, Vec::new())
+    }
+
+    /// Create a new `TriggerAfter` that triggers `event` on each of `targets` once `duration` has passed.
+    ///
+    /// If `targets` is empty the event is triggered globally instead.
+    pub fn with_targets(
+        duration: Duration,
+        event: E,
+        targets: impl IntoIterator<Item = Entity>,
+    ) -> Self {
+        Self {
+            remaining: duration,
+            event,
+            targets: targets.into_iter().collect(),
+        }
+    }
+
+    /// The event that will be triggered.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// The entities the event will be triggered on.
+    pub fn targets(&self) -> &[Entity] {
+        &self.targets
+    }
+}
+
+impl<E: Event> Component for TriggerAfter<E> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_trigger_after::<E>);
+        });
+    }
+}
+
+fn update_trigger_after<E: Event>(
+    In(delta): In<Duration>,
+    mut delayed: Query<(Entity, &mut TriggerAfter<E>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut trigger_after) in delayed.iter_mut() {
+        let trigger_after = trigger_after.bypass_change_detection();
+        trigger_after.remaining = trigger_after.remaining.saturating_sub(delta);
+        if trigger_after.remaining.is_zero() {
+            commands
+                .entity(entity)
+                .add(|entity: Entity, world: &mut World| {
+                    // The entity may have been despawned since, such as to cancel the trigger
+                    let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                        return;
+                    };
+                    let Some(trigger_after) = entity_mut.take::<TriggerAfter<E>>() else {
+                        return;
+                    };
+                    if entity_mut.archetype().component_count() == 0 {
+                        entity_mut.despawn();
+                    }
+                    if trigger_after.targets.is_empty() {
+                        world.trigger(trigger_after.event);
+                    } else {
+                        world.trigger_targets(trigger_after.event, trigger_after.targets);
+                    }
+                });
+        }
+    }
+}
+
+/// The systems that update generic delayed types (such as [`InsertAfter`]), which can't be added to the schedule
+/// up front. Each one is registered as a one-shot system the first time its type is used.
+#[derive(Resource, Default)]
+pub(crate) struct DelayedSystems {
+    registered: HashSet<TypeId>,
+    systems: Vec<SystemId<Duration>>,
+}
+
+/// Queues registering the update system for the delayed component `T`, if it hasn't been registered already.
+pub(crate) fn register_delayed_system<T: 'static, M>(
+    world: &mut DeferredWorld,
+    system: impl IntoSystem<Duration, (), M> + Send + 'static,
+) {
+    let type_id = TypeId::of::<T>();
+    if world
+        .get_resource::<DelayedSystems>()
+        .is_some_and(|systems| systems.registered.contains(&type_id))
+    {
+        return;
+    }
+
+    world.commands().add(move |world: &mut World| {
+        register_delayed_system_in_world::<T, _>(world, system);
+    });
+}
+
+/// Registers the update system for the delayed type `T` immediately, if it hasn't been registered already.
+pub(crate) fn register_delayed_system_in_world<T: 'static, M>(
+    world: &mut World,
+    system: impl IntoSystem<Duration, (), M> + 'static,
+) {
+    let mut systems = world.get_resource_or_insert_with(DelayedSystems::default);
+    if !systems.registered.insert(TypeId::of::<T>()) {
+        return;
+    }
+    let system_id = world.register_system(system);
+    world
+        .resource_mut::<DelayedSystems>()
+        .systems
+        .push(system_id);
+}
+
+pub(crate) fn update_delayed_components(In(delta): In<Duration>, world: &mut World) {
+    let Some(systems) = world.get_resource::<DelayedSystems>() else {
+        return;
+    };
+    for system_id in systems.systems.clone() {
+        if let Err(error) = world.run_system_with_input(system_id, delta) {
+            warn!("Failed to update delayed components: {error}");
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::Parallel};
+
+/// A lightweight lifetime component that despawns its entity once the given time has passed.
+///
+/// `DespawnAfter` is updated alongside [`ObservableTimer`](crate::ObservableTimer)s, but takes a minimal fast path: it
+/// triggers no events at all (not even [`TimerStarted`](crate::TimerStarted) or
+/// [`TimerFinished`](crate::TimerFinished)), has no component hooks, and isn't affected by pausing, groups or
+/// catch-up. This keeps the per-entity overhead low for large numbers of short-lived entities such as particles and
+/// projectiles. Use an `ObservableTimer` instead when anything needs to react to the entity's lifetime.
+///
+/// The contained [`Duration`] is the time remaining, and may be changed at any point to extend or shorten the lifetime.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let projectile = world.spawn(DespawnAfter::from_seconds(0.5)).id();
+///
+/// world.tick_observable_timers(Duration::from_millis(500));
+/// assert!(world.get_entity(projectile).is_none());
+/// ```
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct DespawnAfter(pub Duration);
+
+impl DespawnAfter {
+    /// Create a new `DespawnAfter` that despawns its entity after the given number of seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self(Duration::from_secs_f32(seconds))
+    }
+}
+
+pub(crate) fn update_despawn_after(
+    In(delta): In<Duration>,
+    mut lifetimes: Query<(Entity, &mut DespawnAfter)>,
+    mut thread_expired: Local<Parallel<Vec<Entity>>>,
+    mut commands: Commands,
+) {
+    lifetimes.par_iter_mut().for_each(|(entity, mut lifetime)| {
+        // Bypass change detection, since nothing observes the remaining time changing
+        let lifetime = lifetime.bypass_change_detection();
+        lifetime.0 = lifetime.0.saturating_sub(delta);
+        if lifetime.0.is_zero() {
+            thread_expired.scope(|expired| expired.push(entity));
+        }
+    });
+
+    for expired in thread_expired.iter_mut() {
+        for entity in expired.drain(..) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+/// A [`Resource`] that limits how many entities finished [`ObservableTimer`](crate::ObservableTimer)s despawn in a
+/// single update.
+///
+/// When thousands of timers with [`TimerFinishBehavior::DespawnEntity`](crate::TimerFinishBehavior::DespawnEntity) (or
+/// [`DespawnRecursive`](crate::TimerFinishBehavior::DespawnRecursive)) finish on the same update, despawning all of
+/// their entities at once causes a frame spike. With a `TimerDespawnBudget`, their [`TimerFinished`](crate::TimerFinished)
+/// events are still triggered immediately, but the despawns are queued and spread over the following updates, at most
+/// [`Self::per_update`] at a time. Entities that are despawned by something else while queued are skipped.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_despawn_budget()`], and may also be inserted or removed at
+/// runtime. Removing it drops any queued despawns.
+///
+/// [`ObservableTimerPlugin::with_despawn_budget()`]: crate::ObservableTimerPlugin::with_despawn_budget
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct TimerDespawnBudget {
+    /// The maximum number of entities despawned per update.
+    pub per_update: usize,
+    /// Queued despawns, and whether each is recursive.
+    pending: VecDeque<(Entity, bool)>,
+}
+
+impl TimerDespawnBudget {
+    /// Creates a budget of `per_update` despawns per update.
+    pub fn new(per_update: usize) -> Self {
+        Self {
+            per_update,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// The number of despawns that are waiting for budget.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Despawns the entities of finished timers, or queues them if there is a [`TimerDespawnBudget`].
+pub(crate) fn despawn_finished(world: &mut World, despawns: Vec<(Entity, bool)>) {
+    if let Some(mut budget) = world.get_resource_mut::<TimerDespawnBudget>() {
+        budget.pending.extend(despawns);
+        return;
+    }
+
+    for (entity, recursive) in despawns {
+        despawn(world, entity, recursive);
+    }
+}
+
+fn despawn(world: &mut World, entity: Entity, recursive: bool) {
+    let Some(entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if recursive {
+        entity.despawn_recursive();
+    } else {
+        entity.despawn();
+    }
+}
+
+pub(crate) fn apply_despawn_budget(world: &mut World) {
+    let Some(mut budget) = world.get_resource_mut::<TimerDespawnBudget>() else {
+        return;
+    };
+    let count = budget.per_update.max(1).min(budget.pending.len());
+    let despawns: Vec<_> = budget.pending.drain(..count).collect();
+
+    for (entity, recursive) in despawns {
+        despawn(world, entity, recursive);
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// A component that scales the speed of time for the timers on its entity and all of its descendants.
+///
+/// Dilations multiply together down the hierarchy, so a `TimeDilation(0.5)` inside another `TimeDilation(0.5)` runs
+/// timers at a quarter of their usual speed. This makes "slow-time bubbles" and per-region time effects possible by
+/// dilating a single parent entity, without touching every affected timer. Negative, infinite and NaN dilations are
+/// treated as zero.
+///
+/// Dilation applies to [`ObservableTimer`](crate::ObservableTimer)s, [`DutyCycleTimer`](crate::DutyCycleTimer)s and
+/// [`Timeline`](crate::Timeline)s, on top of any [`TimerGroup`](crate::TimerGroup) scale. Timers on entities with a
+/// `TimeDilation` or a [`Parent`] are never scheduled by
+/// [`TimerScheduling::DueQueue`](crate::TimerScheduling::DueQueue).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let bubble = world.spawn(TimeDilation(0.5)).id();
+/// let timer = world.spawn(ObservableTimer::once_from_seconds(2.0)).set_parent(bubble).id();
+///
+/// world.tick_observable_timers(Duration::from_secs(2));
+/// assert_eq!(world.get::<ObservableTimer>(timer).unwrap().elapsed(), Duration::from_secs(1));
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Deref, DerefMut)]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TimeDilation(pub f32);
+
+impl Default for TimeDilation {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Looks up the combined [`TimeDilation`] of entities through the hierarchy, along with any
+/// [`AudioSpeedSource`](crate::AudioSpeedSource)s.
+#[derive(SystemParam)]
+pub(crate) struct TimeDilations<'w, 's> {
+    dilations: Query<'w, 's, &'static TimeDilation>,
+    parents: Query<'w, 's, &'static Parent>,
+    #[cfg(feature = "bevy_audio")]
+    audio: crate::audio::AudioSpeedSources<'w, 's>,
+}
+
+impl TimeDilations<'_, '_> {
+    /// Returns `true` if any entity has a [`TimeDilation`], so that lookups can be skipped otherwise.
+    pub(crate) fn any(&self) -> bool {
+        #[cfg(feature = "bevy_audio")]
+        if self.audio.any() {
+            return true;
+        }
+        !self.dilations.is_empty()
+    }
+
+    /// The amount that a timer on `entity` should advance when `delta` has passed.
+    pub(crate) fn delta(&self, entity: Entity, delta: Duration) -> Duration {
+        let mut scale = 1.0;
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            if let Ok(dilation) = self.dilations.get(entity) {
+                scale *= f64::from(dilation.0.max(0.0));
+            }
+            #[cfg(feature = "bevy_audio")]
+            if let Some(audio_scale) = self.audio.scale(entity) {
+                scale *= audio_scale;
+            }
+            current = self.parents.get(entity).ok().map(Parent::get);
+        }
+
+        scale_delta(delta, scale)
+    }
+}
+
+/// Scales `delta` by `scale` without panicking like [`Duration::mul_f64()`] does.
+///
+/// Scales that are negative, infinite or NaN are treated as zero, and results that are too large for a [`Duration`]
+/// saturate at [`Duration::MAX`].
+pub(crate) fn scale_delta(delta: Duration, scale: f64) -> Duration {
+    if scale == 1.0 {
+        delta
+    } else if !scale.is_finite() || scale <= 0.0 {
+        Duration::ZERO
+    } else {
+        Duration::try_from_secs_f64(delta.as_secs_f64() * scale).unwrap_or(Duration::MAX)
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::{
+    ecs::{entity::EntityHashMap, world::DeferredWorld},
+    prelude::*,
+};
+
+use crate::{ObservableTimer, TimerTag};
+
+/// A marker component that is added to entities whose [`ObservableTimer`] has been paused because the entity was
+/// disabled, and removed again when it is re-enabled.
+///
+/// Entities are disabled by inserting one of the components registered with
+/// [`ObservableTimerPlugin::with_disabling_component()`], such as a `Disabled` marker used to hide and deactivate
+/// pooled entities. While any of them are present the timer is paused, rather than ticking (and triggering events)
+/// for an entity that is otherwise inactive, and once they have all been removed the timer resumes. A
+/// [`TimerPaused`] event is triggered on the entity when it is disabled, and a [`TimerResumed`] event when it is
+/// re-enabled.
+///
+/// Timers that were already paused when their entity was disabled stay paused when it is re-enabled. Timers that are
+/// inserted while their entity is disabled start out paused (without a [`TimerPaused`] event), and resume once it is
+/// re-enabled.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Component)]
+/// struct Disabled;
+///
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     ObservableTimerPlugin::default().with_disabling_component::<Disabled>(),
+/// ));
+/// let enemy = app.world_mut().spawn(ObservableTimer::indefinite_from_seconds(1.0)).id();
+///
+/// app.world_mut().entity_mut(enemy).insert(Disabled);
+/// app.update();
+/// assert!(app.world().get::<ObservableTimer>(enemy).unwrap().paused());
+///
+/// app.world_mut().entity_mut(enemy).remove::<Disabled>();
+/// app.update();
+/// assert!(!app.world().get::<ObservableTimer>(enemy).unwrap().paused());
+/// ```
+///
+/// [`ObservableTimerPlugin::with_disabling_component()`]: crate::ObservableTimerPlugin::with_disabling_component
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct TimerDisabled;
+
+/// A timer [`Event`] that is triggered when an [`ObservableTimer`] is paused because its entity was disabled.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerPaused {
+    tag: Option<TimerTag>,
+}
+
+impl TimerPaused {
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered when an [`ObservableTimer`] resumes because its entity was re-enabled.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerResumed {
+    tag: Option<TimerTag>,
+}
+
+impl TimerResumed {
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A [`Resource`] counting the disabling components on each disabled entity, and whether its timer was paused by
+/// being disabled.
+///
+/// This is updated synchronously by the observers of the disabling components, so that several disabling components
+/// inserted together (such as in one bundle) are each counted, even though the [`TimerDisabled`] marker is only
+/// inserted once commands are applied.
+#[derive(Resource, Default)]
+pub(crate) struct DisabledEntities(EntityHashMap<DisabledEntity>);
+
+#[derive(Default)]
+struct DisabledEntity {
+    /// The number of disabling components on the entity.
+    disablers: u32,
+    /// Whether the entity's timer was paused because the entity was disabled.
+    paused_timer: bool,
+}
+
+/// Sets up pausing timers while their entity has the disabling component `C`.
+pub(crate) fn register_disabling_component<C: Component>(app: &mut App) {
+    app.init_resource::<DisabledEntities>()
+        .observe(disable_timer::<C>)
+        .observe(enable_timer::<C>);
+}
+
+/// Pauses a timer that has just been inserted on `entity` if the entity is disabled.
+///
+/// No [`TimerPaused`] event is triggered, since the timer has never run.
+pub(crate) fn pause_if_disabled(world: &mut DeferredWorld, entity: Entity) {
+    let Some(paused_timer) = world
+        .get_resource::<DisabledEntities>()
+        .and_then(|disabled| disabled.0.get(&entity))
+        .map(|disabled| disabled.paused_timer)
+    else {
+        return;
+    };
+
+    // A timer replacing one that was paused by disabling the entity is paused the same way
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    if !paused_timer && timer.paused() {
+        return;
+    }
+    timer.pause();
+    if !paused_timer {
+        let mut disabled = world.resource_mut::<DisabledEntities>();
+        disabled.0.get_mut(&entity).unwrap().paused_timer = true;
+        world.commands().entity(entity).insert(TimerDisabled);
+    }
+}
+
+/// Forgets that the timer on `entity` was paused by disabling the entity, as the timer is being removed.
+pub(crate) fn forget_disabled_timer(world: &mut DeferredWorld, entity: Entity) {
+    let Some(mut disabled) = world.get_resource_mut::<DisabledEntities>() else {
+        return;
+    };
+    if let Some(disabled) = disabled.0.get_mut(&entity) {
+        disabled.paused_timer = false;
+    }
+    if world.get::<TimerDisabled>(entity).is_some() {
+        world.commands().entity(entity).remove::<TimerDisabled>();
+    }
+}
+
+fn disable_timer<C: Component>(
+    trigger: Trigger<OnAdd, C>,
+    mut disabled: ResMut<DisabledEntities>,
+    mut timers: Query<&mut ObservableTimer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let disabled = disabled.0.entry(entity).or_default();
+    disabled.disablers += 1;
+    if disabled.disablers > 1 {
+        return;
+    }
+    let Ok(mut timer) = timers.get_mut(entity) else {
+        return;
+    };
+    if timer.paused() {
+        return;
+    }
+
+    timer.pause();
+    disabled.paused_timer = true;
+    let tag = timer.tag;
+    commands.entity(entity).insert(TimerDisabled);
+    commands.trigger_targets(TimerPaused { tag }, entity);
+}
+
+fn enable_timer<C: Component>(
+    trigger: Trigger<OnRemove, C>,
+    mut disabled: ResMut<DisabledEntities>,
+    mut timers: Query<&mut ObservableTimer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let Some(entry) = disabled.0.get_mut(&entity) else {
+        return;
+    };
+    entry.disablers -= 1;
+    if entry.disablers > 0 {
+        return;
+    }
+    let paused_timer = disabled.0.remove(&entity).unwrap().paused_timer;
+    if !paused_timer {
+        return;
+    }
+    let Ok(mut timer) = timers.get_mut(entity) else {
+        return;
+    };
+
+    timer.unpause();
+    let tag = timer.tag;
+    commands.entity(entity).remove::<TimerDisabled>();
+    commands.trigger_targets(TimerResumed { tag }, entity);
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// A component that despawns its entity and all of its descendants after a delay, giving it a grace period to animate
+/// out first.
+///
+/// This is intended for transient UI such as toasts and pickup popups. Once only the grace period remains, a
+/// [`Dismissing`] event is triggered on the entity so that a fade-out animation can start, and the entity tree is
+/// despawned when the grace period ends. Without a grace period the event is triggered just before the despawn.
+///
+/// The remaining time may be changed at any point, for example to keep a toast open while it is hovered. Setting it
+/// back above the grace period after [`Dismissing`] has been triggered lets the event trigger again.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let toast = world
+///     .spawn(DismissAfter::from_seconds(3.0).with_grace(Duration::from_millis(500)))
+///     .id();
+///
+/// world.tick_observable_timers(Duration::from_millis(2500));
+/// assert!(world.get::<DismissAfter>(toast).unwrap().is_dismissing());
+///
+/// world.tick_observable_timers(Duration::from_millis(500));
+/// assert!(world.get_entity(toast).is_none());
+/// ```
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct DismissAfter {
+    /// The time remaining until the entity is despawned.
+    pub remaining: Duration,
+    /// How long before the despawn [`Dismissing`] is triggered. Defaults to zero.
+    pub grace: Duration,
+    dismissing: bool,
+}
+
+impl DismissAfter {
+    /// Creates a `DismissAfter` that despawns its entity tree once `delay` has passed.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            remaining: delay,
+            grace: Duration::ZERO,
+            dismissing: false,
+        }
+    }
+
+    /// Creates a `DismissAfter` that despawns its entity tree after the given number of seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// Sets how long before the despawn [`Dismissing`] is triggered.
+    pub fn with_grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// Returns `true` once [`Dismissing`] has been triggered.
+    pub fn is_dismissing(&self) -> bool {
+        self.dismissing
+    }
+}
+
+/// An [`Event`] that is triggered on a [`DismissAfter`]'s entity once only its grace period remains.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct Dismissing {
+    remaining: Duration,
+}
+
+impl Dismissing {
+    /// The time remaining until the entity is despawned.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+}
+
+pub(crate) fn update_dismiss_after(
+    In(delta): In<Duration>,
+    mut dismissals: Query<(Entity, &mut DismissAfter)>,
+    mut commands: Commands,
+) {
+    for (entity, mut dismissal) in dismissals.iter_mut() {
+        dismissal.remaining = dismissal.remaining.saturating_sub(delta);
+        if dismissal.dismissing && dismissal.remaining > dismissal.grace {
+            dismissal.dismissing = false;
+        }
+
+        if !dismissal.dismissing && dismissal.remaining <= dismissal.grace {
+            dismissal.dismissing = true;
+            let remaining = dismissal.remaining;
+            commands.trigger_targets(Dismissing { remaining }, entity);
+        }
+        if dismissal.remaining.is_zero() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{dilation::TimeDilations, TimerTag};
+
+/// One of the two phases of a [`DutyCycleTimer`].
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum TimerPhase {
+    /// The phase that lasts for [`DutyCycleTimer::on_duration`].
+    #[default]
+    On,
+    /// The phase that lasts for [`DutyCycleTimer::off_duration`].
+    Off,
+}
+
+impl TimerPhase {
+    /// Returns the other phase.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::On => Self::Off,
+            Self::Off => Self::On,
+        }
+    }
+}
+
+/// A repeating timer component that alternates between an "on" and an "off" phase.
+///
+/// The timer starts in [`TimerPhase::On`], and triggers a [`TimerPhaseChanged`] event on its entity at each
+/// transition. This suits blinking lights, hazard emitters and breather windows in boss fights, which would otherwise
+/// need an [`ObservableTimer`](crate::ObservableTimer) whose interval is changed from an observer every cycle.
+///
+/// The durations may be changed at any point, and take effect from the current update. A phase with a zero duration is
+/// skipped over, but its [`TimerPhaseChanged`] event is still triggered. A timer with two zero durations doesn't run.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let emitter = world
+///     .spawn(DutyCycleTimer::new(Duration::from_secs(2), Duration::from_secs(1)))
+///     .id();
+///
+/// world.tick_observable_timers(Duration::from_secs(2));
+/// assert_eq!(world.get::<DutyCycleTimer>(emitter).unwrap().phase(), TimerPhase::Off);
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.get::<DutyCycleTimer>(emitter).unwrap().phase(), TimerPhase::On);
+/// ```
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct DutyCycleTimer {
+    /// The duration of the [`TimerPhase::On`] phase.
+    pub on_duration: Duration,
+    /// The duration of the [`TimerPhase::Off`] phase.
+    pub off_duration: Duration,
+    /// Whether the timer is paused. Defaults to `false`.
+    pub paused: bool,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    phase: TimerPhase,
+    phase_elapsed: Duration,
+    cycles: u32,
+}
+
+impl DutyCycleTimer {
+    /// Creates a new timer that is on for `on_duration`, then off for `off_duration`, repeating indefinitely.
+    pub fn new(on_duration: Duration, off_duration: Duration) -> Self {
+        Self {
+            on_duration,
+            off_duration,
+            paused: false,
+            tag: None,
+            phase: TimerPhase::On,
+            phase_elapsed: Duration::ZERO,
+            cycles: 0,
+        }
+    }
+
+    /// Creates a new timer that is on for `on_seconds`, then off for `off_seconds`, repeating indefinitely.
+    pub fn from_seconds(on_seconds: f32, off_seconds: f32) -> Self {
+        Self::new(
+            Duration::from_secs_f32(on_seconds),
+            Duration::from_secs_f32(off_seconds),
+        )
+    }
+
+    /// Sets the phase that the timer starts in.
+    pub fn with_phase(mut self, phase: TimerPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The current phase.
+    pub fn phase(&self) -> TimerPhase {
+        self.phase
+    }
+
+    /// Returns `true` if the timer is in the [`TimerPhase::On`] phase.
+    pub fn is_on(&self) -> bool {
+        self.phase == TimerPhase::On
+    }
+
+    /// The duration of the given phase.
+    pub fn phase_duration(&self, phase: TimerPhase) -> Duration {
+        match phase {
+            TimerPhase::On => self.on_duration,
+            TimerPhase::Off => self.off_duration,
+        }
+    }
+
+    /// The time elapsed in the current phase.
+    pub fn phase_elapsed(&self) -> Duration {
+        self.phase_elapsed
+    }
+
+    /// The time remaining in the current phase.
+    pub fn phase_remaining(&self) -> Duration {
+        self.phase_duration(self.phase)
+            .saturating_sub(self.phase_elapsed)
+    }
+
+    /// The number of full on-off cycles that have elapsed.
+    pub fn cycles(&self) -> u32 {
+        self.cycles
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`DutyCycleTimer`]'s entity each time it changes phase.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerPhaseChanged {
+    phase: TimerPhase,
+    tag: Option<TimerTag>,
+}
+
+impl TimerPhaseChanged {
+    /// The phase that the timer changed to.
+    pub fn phase(&self) -> TimerPhase {
+        self.phase
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+pub(crate) fn update_duty_cycle_timers(
+    In(delta): In<Duration>,
+    mut timers: Query<(Entity, &mut DutyCycleTimer)>,
+    dilations: TimeDilations,
+    mut commands: Commands,
+) {
+    let dilated = dilations.any();
+    for (entity, mut timer) in timers.iter_mut() {
+        if timer.paused || (timer.on_duration + timer.off_duration).is_zero() {
+            continue;
+        }
+
+        let timer = &mut *timer;
+        timer.phase_elapsed += if dilated {
+            dilations.delta(entity, delta)
+        } else {
+            delta
+        };
+        while timer.phase_elapsed >= timer.phase_duration(timer.phase) {
+            timer.phase_elapsed -= timer.phase_duration(timer.phase);
+            timer.phase = timer.phase.toggled();
+            if timer.phase == TimerPhase::On {
+                timer.cycles += 1;
+            }
+
+            let (phase, tag) = (timer.phase, timer.tag);
+            commands.trigger_targets(TimerPhaseChanged { phase, tag }, entity);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{
+    TimerAdjusted, TimerFinishBehavior, TimerFinished, TimerInterval, TimerProgress,
+    TimerRestarted, TimerSecondElapsed,
+};
+
+/// A [`Resource`] that limits how many events updating [`ObservableTimer`](crate::ObservableTimer)s triggers in a
+/// single update.
+///
+/// When a huge number of timers complete on the same update, running all of their observers at once causes a frame
+/// spike. With a `TimerEventBudget`, at most [`Self::per_update`] timer events are triggered per update, and the rest
+/// are queued and triggered on the following updates, in the order they would otherwise have been triggered. The
+/// events of each individual timer therefore always keep their order, and a timer's
+/// [`TimerFinishBehavior`] is only performed once its [`TimerFinished`] event has been triggered. Queued events for
+/// entities that have since been despawned are dropped.
+///
+/// This covers the [`TimerRestarted`], [`TimerAdjusted`], [`TimerInterval`], [`TimerSecondElapsed`],
+/// [`TimerProgress`] and [`TimerFinished`] events triggered while updating timers. Events are only counted when
+/// something observes them, and while nothing is queued and the budget isn't exceeded, events are triggered exactly as
+/// they would be without a budget.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_event_budget()`], and may also be inserted or removed at runtime.
+/// Removing it drops any queued events.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.insert_resource(TimerEventBudget::new(100));
+/// for _ in 0..250 {
+///     world.spawn(ObservableTimer::once_from_seconds(1.0));
+/// }
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.resource::<TimerEventBudget>().pending(), 150);
+///
+/// world.tick_observable_timers(Duration::ZERO);
+/// world.tick_observable_timers(Duration::ZERO);
+/// assert_eq!(world.query::<&ObservableTimer>().iter(&world).count(), 0);
+/// ```
+///
+/// [`ObservableTimerPlugin::with_event_budget()`]: crate::ObservableTimerPlugin::with_event_budget
+#[derive(Resource, Debug, Clone)]
+pub struct TimerEventBudget {
+    /// The maximum number of timer events triggered per update.
+    pub per_update: usize,
+    /// Queued events, along with their targets.
+    pending: VecDeque<(BudgetedEvent, Entity)>,
+}
+
+impl TimerEventBudget {
+    /// Creates a budget of `per_update` timer events per update.
+    pub fn new(per_update: usize) -> Self {
+        Self {
+            per_update,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// The number of events that are waiting for budget.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns `true` if `count` new events can't be triggered right away.
+    pub(crate) fn exceeded_by(&self, count: usize) -> bool {
+        !self.pending.is_empty() || count > self.per_update.max(1)
+    }
+
+    /// Queues events behind any that are already waiting.
+    pub(crate) fn queue(&mut self, events: impl IntoIterator<Item = (BudgetedEvent, Entity)>) {
+        self.pending.extend(events);
+    }
+
+    /// Removes the events that fit in this update's budget, in the order they were queued.
+    pub(crate) fn take(&mut self) -> impl Iterator<Item = (BudgetedEvent, Entity)> + '_ {
+        let count = self.per_update.max(1).min(self.pending.len());
+        self.pending.drain(..count)
+    }
+}
+
+/// A timer event waiting in a [`TimerEventBudget`].
+#[derive(Debug, Clone)]
+pub(crate) enum BudgetedEvent {
+    Restarted(TimerRestarted),
+    Adjusted(TimerAdjusted),
+    Interval(TimerInterval),
+    Second(TimerSecondElapsed),
+    Progress(TimerProgress),
+    /// A finished event, followed by the timer's finish behavior.
+    Finished(TimerFinished, TimerFinishBehavior),
+}
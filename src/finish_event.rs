@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{ObservableTimer, TimerFinished};
+
+/// Triggers a user-defined event on the given entity.
+type TriggerFn = Box<dyn Fn(Entity, &mut Commands) + Send + Sync>;
+
+/// A component that triggers a user-defined [`Event`] on its entity when the entity's timer finishes.
+///
+/// This is usually created with [`ObservableTimer::with_event()`]. The event is triggered right after
+/// [`TimerFinished`], and before the timer's finish behavior is performed. It isn't triggered if the timer is
+/// cancelled.
+#[derive(Component)]
+pub struct TimerFinishEvent {
+    trigger: TriggerFn,
+}
+
+impl TimerFinishEvent {
+    /// Creates a `TimerFinishEvent` that triggers a clone of `event`.
+    pub fn new<E: Event + Clone>(event: E) -> Self {
+        Self {
+            trigger: Box::new(move |entity, commands| {
+                commands.trigger_targets(event.clone(), entity);
+            }),
+        }
+    }
+}
+
+impl ObservableTimer {
+    /// Bundles the timer with a [`TimerFinishEvent`], so that `event` is triggered on the timer's entity when it
+    /// finishes. This saves writing an observer that only translates [`TimerFinished`] into a domain event.
+    ///
+    /// Since this returns a bundle, it should be called after any other builder methods.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # fn setup(mut commands: Commands) {
+    /// #[derive(Event, Clone)]
+    /// struct Explode;
+    ///
+    /// commands
+    ///     .spawn(ObservableTimer::once_from_seconds(3.0).with_event(Explode))
+    ///     .observe(|_: Trigger<Explode>| info!("Boom!"));
+    /// # }
+    /// ```
+    pub fn with_event<E: Event + Clone>(self, event: E) -> (Self, TimerFinishEvent) {
+        (self, TimerFinishEvent::new(event))
+    }
+}
+
+pub(crate) fn trigger_finish_events(
+    trigger: Trigger<TimerFinished>,
+    finish_events: Query<&TimerFinishEvent>,
+    mut commands: Commands,
+) {
+    if trigger.event().cancelled() {
+        return;
+    }
+    if let Ok(finish_event) = finish_events.get(trigger.entity()) {
+        (finish_event.trigger)(trigger.entity(), &mut commands);
+    }
+}
@@ -0,0 +1,201 @@
+use std::time::{Duration, SystemTime};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{
+    start::{flush_timer_start, queue_timer_start, trigger_timer_starts},
+    trigger_cancelled, ObservableTimerUpdate, StopReason, TimerDirection, TimerFinishBehavior,
+    TimerFinished, TimerTag,
+};
+
+/// A timer component that finishes once the system's wall-clock time passes a target instant.
+///
+/// Unlike [`ObservableTimer`](crate::ObservableTimer), which accumulates frame deltas, `FireAt` compares against the
+/// current [`SystemTime`] every update. It therefore still finishes at the right moment after long frames, or after
+/// the app has been suspended, which makes it suitable for reminders and daily resets.
+///
+/// A [`TimerStarted`](crate::TimerStarted) event is triggered on the next timer update after the component is
+/// inserted, and a [`TimerFinished`] event once the target passes, with [`TimerFinished::overshoot()`] holding how long
+/// ago that was. Removing the component before then triggers a cancelled `TimerFinished` event. After finishing the
+/// [`TimerFinishBehavior`] is performed, where
+/// [`TimerFinishBehavior::RemoveComponent`] removes the `FireAt` component, and
+/// [`TimerFinishBehavior::ReplaceWith`] replaces it with the given [`ObservableTimer`](crate::ObservableTimer).
+///
+/// Requires the [`FireAtPlugin`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_mod_observable_timer::*;
+/// let reminder = FireAt::after(Duration::from_secs(60 * 60))
+///     .with_finish_behavior(TimerFinishBehavior::RemoveComponent);
+/// ```
+#[derive(Reflect, Debug, Clone)]
+#[reflect(Component, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct FireAt {
+    since_epoch: Duration,
+    finished: bool,
+    /// The behavior to perform once the target instant has passed.
+    pub finish_behavior: TimerFinishBehavior,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+}
+
+impl FireAt {
+    /// Create a new `FireAt` that finishes at the given instant.
+    pub fn new(at: SystemTime) -> Self {
+        Self {
+            since_epoch: at
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default(),
+            finished: false,
+            finish_behavior: TimerFinishBehavior::default(),
+            tag: None,
+        }
+    }
+
+    /// Create a new `FireAt` that finishes once `duration` of wall-clock time has passed from now.
+    pub fn after(duration: Duration) -> Self {
+        Self::new(SystemTime::now() + duration)
+    }
+
+    /// Sets the [`TimerFinishBehavior`].
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The instant at which this finishes.
+    pub fn at(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH + self.since_epoch
+    }
+
+    /// `true` once the target instant has passed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Pushes the target instant back by `delay`.
+    pub fn postpone(&mut self, delay: Duration) {
+        self.since_epoch += delay;
+    }
+
+    /// The wall-clock time remaining until the target instant, or zero if it has already passed.
+    pub fn remaining(&self) -> Duration {
+        self.at()
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
+impl Component for FireAt {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_insert(on_fire_at_inserted)
+            .on_remove(on_fire_at_removed);
+    }
+}
+
+fn on_fire_at_inserted(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    let tag = world.get::<FireAt>(entity).unwrap().tag;
+    queue_timer_start(&mut world, entity, component, tag, false);
+}
+
+fn on_fire_at_removed(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    flush_timer_start(&mut world, entity, component);
+    let timer = world.get::<FireAt>(entity).unwrap();
+    let (finished, tag) = (timer.finished, timer.tag);
+    if !finished {
+        trigger_cancelled(
+            &mut world,
+            TimerFinished {
+                reason: StopReason::Cancelled,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag,
+            },
+            entity,
+        );
+    }
+}
+
+/// A plugin that updates [`FireAt`] timers. Requires [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), FireAtPlugin));
+/// ```
+pub struct FireAtPlugin;
+
+impl Plugin for FireAtPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FireAt>().add_systems(
+            ObservableTimerUpdate,
+            update_fire_at.after(trigger_timer_starts),
+        );
+    }
+}
+
+pub(crate) fn update_fire_at(mut timers: Query<(Entity, &mut FireAt)>, mut commands: Commands) {
+    let now = SystemTime::now();
+
+    for (entity, mut timer) in timers.iter_mut() {
+        if timer.finished {
+            continue;
+        }
+        let Ok(overshoot) = now.duration_since(timer.at()) else {
+            continue;
+        };
+
+        timer.finished = true;
+        commands.trigger_targets(
+            TimerFinished {
+                reason: StopReason::Finished,
+                condition_met: false,
+                overshoot,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag: timer.tag,
+            },
+            entity,
+        );
+
+        match &timer.finish_behavior {
+            TimerFinishBehavior::None => {}
+            TimerFinishBehavior::RemoveComponent => {
+                commands.entity(entity).remove::<FireAt>();
+            }
+            TimerFinishBehavior::DespawnEntity => {
+                commands.entity(entity).despawn();
+            }
+            TimerFinishBehavior::DespawnRecursive => {
+                commands.entity(entity).despawn_recursive();
+            }
+            TimerFinishBehavior::ReplaceWith(timer) => {
+                commands
+                    .entity(entity)
+                    .remove::<FireAt>()
+                    .insert(timer.as_ref().clone());
+            }
+        }
+    }
+}
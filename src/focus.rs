@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+/// A [`Resource`] that pauses timers while the primary window doesn't have focus, so that single-player gameplay
+/// timers don't run while the player is alt-tabbed or has minimized the game.
+///
+/// While the [`PrimaryWindow`] is unfocused, [`ObservableTimerSet`](crate::ObservableTimerSet) doesn't run, in the
+/// same way as with [`ObservableTimerPlugin::run_if()`](crate::ObservableTimerPlugin::run_if). Timer components are
+/// not modified, they simply aren't updated. Once the window regains focus, a global [`TimersRefocused`] event is
+/// triggered with how long timers were paused for, which can be used to compensate anything driven by other clocks.
+/// Apps without a primary window are never paused.
+///
+/// Only the timers updated in [`ObservableTimerSet`](crate::ObservableTimerSet) are paused. [`Deadline`]s still
+/// finish on time once the window regains focus, as they follow [`Time`] itself.
+///
+/// This is inserted by [`ObservableTimerPlugin::with_focus_pause()`].
+///
+/// ```
+/// # use bevy::{prelude::*, window::PrimaryWindow};
+/// # use bevy_mod_observable_timer::*;
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, ObservableTimerPlugin::default().with_focus_pause(true)));
+/// let window = app.world_mut().spawn((Window::default(), PrimaryWindow)).id();
+///
+/// app.world_mut().get_mut::<Window>(window).unwrap().focused = false;
+/// app.update();
+/// assert!(app.world().resource::<TimerFocusPause>().is_paused());
+/// ```
+///
+/// [`Deadline`]: crate::Deadline
+/// [`ObservableTimerPlugin::with_focus_pause()`]: crate::ObservableTimerPlugin::with_focus_pause
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TimerFocusPause {
+    /// The real time at which the window lost focus, while it is unfocused.
+    unfocused_since: Option<Duration>,
+}
+
+impl TimerFocusPause {
+    /// Returns `true` while timers are paused because the primary window is unfocused.
+    pub fn is_paused(&self) -> bool {
+        self.unfocused_since.is_some()
+    }
+}
+
+/// An [`Event`] that is triggered globally when the primary window regains focus, after a [`TimerFocusPause`] paused
+/// timers.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimersRefocused {
+    unfocused_for: Duration,
+}
+
+impl TimersRefocused {
+    /// The real time that the window was unfocused, and timers were paused, for.
+    pub fn unfocused_for(&self) -> Duration {
+        self.unfocused_for
+    }
+}
+
+pub(crate) fn track_window_focus(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    time: Option<Res<Time<Real>>>,
+    mut focus: ResMut<TimerFocusPause>,
+    mut commands: Commands,
+) {
+    let focused = windows.get_single().map_or(true, |window| window.focused);
+    let now = time.map_or(Duration::ZERO, |time| time.elapsed());
+    match (focus.unfocused_since, focused) {
+        (None, false) => focus.unfocused_since = Some(now),
+        (Some(since), true) => {
+            focus.unfocused_since = None;
+            let unfocused_for = now.saturating_sub(since);
+            commands.trigger(TimersRefocused { unfocused_for });
+        }
+        _ => {}
+    }
+}
+
+/// A run condition that is `true` unless timers are paused by a [`TimerFocusPause`].
+pub(crate) fn window_focused(focus: Option<Res<TimerFocusPause>>) -> bool {
+    !focus.is_some_and(|focus| focus.is_paused())
+}
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{
+    start::{flush_timer_start, queue_timer_start},
+    trigger_cancelled, StopReason, TimerDirection, TimerFinishBehavior, TimerFinished,
+    TimerInterval, TimerTag,
+};
+
+/// A timer component that counts updates (frames) instead of time.
+///
+/// Each time [`ObservableTimerSet`](crate::ObservableTimerSet) runs counts as one frame (or each call to
+/// [`tick_observable_timers()`](crate::ObservableTimerWorldExt::tick_observable_timers) when ticking manually). This
+/// expresses things like "wait one frame, then do X" reliably, which a duration-based timer can't.
+///
+/// `FrameTimer` triggers the same events as an [`ObservableTimer`](crate::ObservableTimer):
+/// [`TimerStarted`](crate::TimerStarted) on the next timer update after it is inserted, [`TimerInterval`] after each
+/// interval of frames, and [`TimerFinished`] after the final interval or when the component is removed early. Since no
+/// time is involved, [`TimerFinished::overshoot()`] and [`TimerFinished::interval_duration()`] are always zero. After
+/// finishing the [`TimerFinishBehavior`] is performed, where [`TimerFinishBehavior::RemoveComponent`] removes the
+/// `FrameTimer` component, and
+/// [`TimerFinishBehavior::ReplaceWith`] replaces it with the given [`ObservableTimer`](crate::ObservableTimer).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let entity = world.spawn(FrameTimer::once(2)).id();
+///
+/// world.tick_observable_timers(Duration::ZERO);
+/// assert!(world.get_entity(entity).is_some());
+///
+/// world.tick_observable_timers(Duration::ZERO);
+/// assert!(world.get_entity(entity).is_none());
+/// ```
+#[derive(Reflect, Debug, Clone)]
+#[reflect(Component, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct FrameTimer {
+    interval_frames: u32,
+    elapsed_frames: u32,
+    elapsed_intervals: u32,
+    remaining_intervals: Option<u32>,
+    /// The behavior to perform once the final interval has elapsed.
+    pub finish_behavior: TimerFinishBehavior,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+}
+
+impl FrameTimer {
+    /// Create a new timer that will run for `interval_count` intervals of `interval_frames` frames each.
+    ///
+    /// An `interval_count` of `0` will result in a timer that runs indefinitely. An `interval_frames` of `0` is
+    /// treated as `1`.
+    pub fn new(interval_count: u32, interval_frames: u32) -> Self {
+        Self {
+            interval_frames: interval_frames.max(1),
+            elapsed_frames: 0,
+            elapsed_intervals: 0,
+            remaining_intervals: (interval_count > 0).then_some(interval_count),
+            finish_behavior: TimerFinishBehavior::default(),
+            tag: None,
+        }
+    }
+
+    /// Creates a new timer with a single interval of `frames` frames.
+    pub fn once(frames: u32) -> Self {
+        Self::new(1, frames)
+    }
+
+    /// Creates a new timer that runs indefinitely with intervals of `interval_frames` frames.
+    pub fn indefinite(interval_frames: u32) -> Self {
+        Self::new(0, interval_frames)
+    }
+
+    /// Sets the [`TimerFinishBehavior`].
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The number of frames in each interval.
+    pub fn interval_frames(&self) -> u32 {
+        self.interval_frames
+    }
+
+    /// The number of frames that have elapsed in the current interval.
+    pub fn elapsed_frames(&self) -> u32 {
+        self.elapsed_frames
+    }
+
+    /// The number of intervals that have elapsed.
+    pub fn elapsed_intervals(&self) -> u32 {
+        self.elapsed_intervals
+    }
+
+    /// The number of intervals remaining, or `None` if the timer runs indefinitely.
+    pub fn remaining_intervals(&self) -> Option<u32> {
+        self.remaining_intervals
+    }
+
+    /// Returns `true` once the final interval has elapsed.
+    pub fn is_done(&self) -> bool {
+        self.remaining_intervals == Some(0)
+    }
+}
+
+impl Component for FrameTimer {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_insert(on_frame_timer_inserted)
+            .on_remove(on_frame_timer_removed);
+    }
+}
+
+fn on_frame_timer_inserted(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    let tag = world.get::<FrameTimer>(entity).unwrap().tag;
+    queue_timer_start(&mut world, entity, component, tag, false);
+}
+
+fn on_frame_timer_removed(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    flush_timer_start(&mut world, entity, component);
+    let timer = world.get::<FrameTimer>(entity).unwrap();
+    let (finished, tag) = (timer.is_done(), timer.tag);
+    if !finished {
+        trigger_cancelled(
+            &mut world,
+            TimerFinished {
+                reason: StopReason::Cancelled,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag,
+            },
+            entity,
+        );
+    }
+}
+
+pub(crate) fn update_frame_timers(
+    mut timers: Query<(Entity, &mut FrameTimer)>,
+    mut commands: Commands,
+) {
+    for (entity, mut timer) in timers.iter_mut() {
+        if timer.is_done() {
+            continue;
+        }
+
+        timer.elapsed_frames += 1;
+        if timer.elapsed_frames < timer.interval_frames {
+            continue;
+        }
+
+        timer.elapsed_frames = 0;
+        timer.elapsed_intervals += 1;
+        let count = timer.elapsed_intervals;
+        let tag = timer.tag;
+        commands.trigger_targets(TimerInterval { count, tag }, entity);
+
+        let Some(remaining_intervals) = timer.remaining_intervals.as_mut() else {
+            continue;
+        };
+        *remaining_intervals -= 1;
+        if *remaining_intervals > 0 {
+            continue;
+        }
+
+        commands.trigger_targets(
+            TimerFinished {
+                reason: StopReason::Finished,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: Duration::ZERO,
+                direction: TimerDirection::Forward,
+                tag,
+            },
+            entity,
+        );
+
+        match &timer.finish_behavior {
+            TimerFinishBehavior::None => {}
+            TimerFinishBehavior::RemoveComponent => {
+                commands.entity(entity).remove::<FrameTimer>();
+            }
+            TimerFinishBehavior::DespawnEntity => {
+                commands.entity(entity).despawn();
+            }
+            TimerFinishBehavior::DespawnRecursive => {
+                commands.entity(entity).despawn_recursive();
+            }
+            TimerFinishBehavior::ReplaceWith(timer) => {
+                commands
+                    .entity(entity)
+                    .remove::<FrameTimer>()
+                    .insert(timer.as_ref().clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{ObservableTimer, TimerFinished};
+
+/// A [`Future`] that resolves when an [`ObservableTimer`] finishes.
+///
+/// This is returned by [`TimerSender::await_timer()`](crate::TimerSender::await_timer), and lets code running on one
+/// of bevy's task pools `.await` a timer instead of polling its component. It resolves to the timer's
+/// [`TimerFinished`] event, including when the timer is cancelled by being removed. It resolves to `None` if the
+/// entity has no timer when the request is applied, if the entity is despawned before the timer finishes, or if the
+/// app has shut down.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::{prelude::*, tasks::block_on};
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.init_resource::<TimerCommandChannel>();
+/// let countdown = world.spawn(ObservableTimer::once_from_seconds(3.0)).id();
+///
+/// let finished = world.resource::<TimerCommandChannel>().sender().await_timer(countdown);
+/// world.tick_observable_timers(Duration::from_secs(3));
+///
+/// let finished = block_on(finished).unwrap();
+/// assert!(!finished.cancelled());
+/// ```
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct TimerFinishedFuture(TimerAwaiter);
+
+impl TimerFinishedFuture {
+    pub(crate) fn new() -> (Self, TimerAwaiter) {
+        let awaiter = TimerAwaiter::default();
+        (Self(awaiter.clone()), awaiter)
+    }
+}
+
+impl Future for TimerFinishedFuture {
+    type Output = Option<TimerFinished>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.0 .0.lock().unwrap();
+        match state.finished.take() {
+            Some(finished) => Poll::Ready(finished),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct AwaitState {
+    finished: Option<Option<TimerFinished>>,
+    waker: Option<Waker>,
+}
+
+/// The sending half of a [`TimerFinishedFuture`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TimerAwaiter(Arc<Mutex<AwaitState>>);
+
+impl TimerAwaiter {
+    pub(crate) fn resolve(&self, finished: Option<TimerFinished>) {
+        let mut state = self.0.lock().unwrap();
+        if state.finished.is_none() {
+            state.finished = Some(finished);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// The futures waiting on the [`ObservableTimer`] of the same entity.
+struct TimerAwaiters(Vec<TimerAwaiter>);
+
+impl Component for TimerAwaiters {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_add(on_awaiters_added)
+            .on_remove(on_awaiters_removed);
+    }
+}
+
+/// Marks that the observer resolving [`TimerFinishedFuture`]s has been added.
+#[derive(Resource)]
+struct AwaiterNotification;
+
+fn on_awaiters_added(mut world: DeferredWorld, _: Entity, _: ComponentId) {
+    if !world.contains_resource::<AwaiterNotification>() {
+        world.commands().add(|world: &mut World| {
+            if !world.contains_resource::<AwaiterNotification>() {
+                world.insert_resource(AwaiterNotification);
+                world.observe(notify_awaiters);
+            }
+        });
+    }
+}
+
+fn on_awaiters_removed(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let mut awaiters = world.get_mut::<TimerAwaiters>(entity).unwrap();
+    for awaiter in awaiters.0.drain(..) {
+        awaiter.resolve(None);
+    }
+}
+
+fn notify_awaiters(
+    trigger: Trigger<TimerFinished>,
+    mut awaiters: Query<&mut TimerAwaiters>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let Ok(mut awaiters) = awaiters.get_mut(entity) else {
+        return;
+    };
+
+    for awaiter in awaiters.0.drain(..) {
+        awaiter.resolve(Some(trigger.event().clone()));
+    }
+    commands.entity(entity).remove::<TimerAwaiters>();
+}
+
+/// Registers a future to be resolved when the entity's timer finishes.
+pub(crate) fn register_awaiter(world: &mut World, entity: Entity, awaiter: TimerAwaiter) {
+    let Some(mut entity) = world
+        .get_entity_mut(entity)
+        .filter(|entity| entity.contains::<ObservableTimer>())
+    else {
+        awaiter.resolve(None);
+        return;
+    };
+
+    match entity.get_mut::<TimerAwaiters>() {
+        Some(mut awaiters) => awaiters.0.push(awaiter),
+        None => {
+            entity.insert(TimerAwaiters(vec![awaiter]));
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{dilation::scale_delta, GameCalendar};
+
+const SECONDS_PER_HOUR: u64 = 60 * 60;
+const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+
+/// One of the four seasons of a [`GameClock`] year.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum Season {
+    /// The first season of the year.
+    #[default]
+    Spring,
+    /// The second season of the year.
+    Summer,
+    /// The third season of the year.
+    Autumn,
+    /// The fourth season of the year.
+    Winter,
+}
+
+impl Season {
+    const ALL: [Self; 4] = [Self::Spring, Self::Summer, Self::Autumn, Self::Winter];
+}
+
+/// A [`Resource`] that tracks in-game time for simulation games, running at a configurable ratio to real time.
+///
+/// Each time the clock passes a whole in-game hour a [`HourElapsed`] event is triggered globally, followed by a
+/// [`DayElapsed`] event at midnight, and a [`SeasonChanged`] event when a new season starts. If the clock passes
+/// several hours in one update, such as after a [skip](Self::skip()), an event is triggered for each of them in order.
+///
+/// Days, seasons and years are counted from 0, so the clock starts at 00:00 on day 0, in the [`Season::Spring`] of
+/// year 0. The clock advances along with [`ObservableTimer`](crate::ObservableTimer)s, and so follows the
+/// [`TimerTickMode`](crate::TimerTickMode). It is inserted by [`ObservableTimerPlugin::with_game_clock()`], and may
+/// also be inserted or removed at runtime.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// // A full in-game day passes every 24 real minutes
+/// world.insert_resource(GameClock::from_day_length(Duration::from_secs(24 * 60)));
+///
+/// world.tick_observable_timers(Duration::from_secs(90));
+/// let clock = world.resource::<GameClock>();
+/// assert_eq!((clock.day(), clock.hour(), clock.minute()), (0, 1, 30));
+/// ```
+///
+/// [`ObservableTimerPlugin::with_game_clock()`]: crate::ObservableTimerPlugin::with_game_clock
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct GameClock {
+    /// The number of in-game seconds that pass per real second. Defaults to `60.0`, so an in-game day lasts 24 real
+    /// minutes. The clock stays still while this is negative, infinite or NaN.
+    pub scale: f32,
+    /// The number of days in each season. Defaults to `28`. No [`SeasonChanged`] events are triggered when this is
+    /// zero.
+    pub days_per_season: u32,
+    /// Whether the clock is paused. Defaults to `false`. Skipped time is still applied while paused.
+    pub paused: bool,
+    elapsed: Duration,
+    skipped: Duration,
+}
+
+impl GameClock {
+    /// Creates a clock where `scale` in-game seconds pass per real second.
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            days_per_season: 28,
+            paused: false,
+            elapsed: Duration::ZERO,
+            skipped: Duration::ZERO,
+        }
+    }
+
+    /// Creates a clock where a full in-game day passes in `day_length` of real time, which must not be zero.
+    pub fn from_day_length(day_length: Duration) -> Self {
+        Self::new((SECONDS_PER_DAY as f64 / day_length.as_secs_f64()) as f32)
+    }
+
+    /// Sets the number of days in each season.
+    pub fn with_days_per_season(mut self, days_per_season: u32) -> Self {
+        self.days_per_season = days_per_season;
+        self
+    }
+
+    /// Sets the in-game time that the clock starts at, without triggering any events.
+    pub fn with_start(mut self, day: u32, hour: u32, minute: u32) -> Self {
+        self.elapsed = Self::timestamp(day, hour, minute);
+        self
+    }
+
+    /// The in-game time elapsed since 00:00 on day 0.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// The in-game time elapsed at the given day, hour and minute.
+    pub fn timestamp(day: u32, hour: u32, minute: u32) -> Duration {
+        Duration::from_secs(
+            u64::from(day) * SECONDS_PER_DAY
+                + u64::from(hour) * SECONDS_PER_HOUR
+                + u64::from(minute) * 60,
+        )
+    }
+
+    /// The current day.
+    pub fn day(&self) -> u32 {
+        (self.elapsed.as_secs() / SECONDS_PER_DAY) as u32
+    }
+
+    /// The current hour of the day, between 0 and 23.
+    pub fn hour(&self) -> u32 {
+        (self.elapsed.as_secs() % SECONDS_PER_DAY / SECONDS_PER_HOUR) as u32
+    }
+
+    /// The current minute of the hour, between 0 and 59.
+    pub fn minute(&self) -> u32 {
+        (self.elapsed.as_secs() % SECONDS_PER_HOUR / 60) as u32
+    }
+
+    /// The in-game time elapsed since midnight.
+    pub fn time_of_day(&self) -> Duration {
+        self.elapsed - Duration::from_secs(u64::from(self.day()) * SECONDS_PER_DAY)
+    }
+
+    /// How far through the current day the clock is, between 0 and 1. Useful for driving a day/night cycle.
+    pub fn day_fraction(&self) -> f32 {
+        (self.time_of_day().as_secs_f64() / SECONDS_PER_DAY as f64) as f32
+    }
+
+    /// The current season.
+    pub fn season(&self) -> Season {
+        self.season_on(self.day())
+    }
+
+    /// The current year, which is made up of four seasons.
+    pub fn year(&self) -> u32 {
+        self.year_on(self.day())
+    }
+
+    /// Advances the clock by an amount of in-game time, such as when the player sleeps.
+    ///
+    /// The skip is applied on the next update, even if the clock is paused, and triggers the events for every hour
+    /// that it passes.
+    pub fn skip(&mut self, duration: Duration) {
+        self.skipped += duration;
+    }
+
+    fn season_on(&self, day: u32) -> Season {
+        match self.days_per_season {
+            0 => Season::Spring,
+            days => Season::ALL[(day / days % 4) as usize],
+        }
+    }
+
+    fn year_on(&self, day: u32) -> u32 {
+        day.checked_div(self.days_per_season.saturating_mul(4))
+            .unwrap_or(0)
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new(60.0)
+    }
+}
+
+/// An [`Event`] that is triggered globally each time a [`GameClock`] passes a whole in-game hour.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct HourElapsed {
+    day: u32,
+    hour: u32,
+}
+
+impl HourElapsed {
+    /// The day of the hour that started.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The hour that started, between 0 and 23.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+}
+
+/// An [`Event`] that is triggered globally each time a [`GameClock`] passes midnight.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct DayElapsed {
+    day: u32,
+}
+
+impl DayElapsed {
+    /// The day that started.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// An [`Event`] that is triggered globally each time a new season starts on a [`GameClock`].
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct SeasonChanged {
+    season: Season,
+    year: u32,
+}
+
+impl SeasonChanged {
+    /// The season that started.
+    pub fn season(&self) -> Season {
+        self.season
+    }
+
+    /// The year of the season that started.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+}
+
+pub(crate) fn update_game_clock(
+    In(delta): In<Duration>,
+    clock: Option<ResMut<GameClock>>,
+    mut calendar: Option<ResMut<GameCalendar>>,
+    mut commands: Commands,
+) {
+    let Some(mut clock) = clock else {
+        return;
+    };
+
+    let skipped = std::mem::take(&mut clock.skipped);
+    let mut advance = skipped;
+    if !clock.paused {
+        advance = advance.saturating_add(scale_delta(delta, f64::from(clock.scale)));
+    }
+    if advance.is_zero() {
+        return;
+    }
+
+    let start = clock.elapsed;
+    let skipped = (start, start + skipped);
+    let first_hour = clock.elapsed.as_secs() / SECONDS_PER_HOUR + 1;
+    clock.elapsed = clock.elapsed.saturating_add(advance);
+    let last_hour = clock.elapsed.as_secs() / SECONDS_PER_HOUR;
+
+    for hours in first_hour..=last_hour {
+        // Calendar entries are triggered in order with the hours, after the events for their own hour
+        if let Some(calendar) = calendar.as_deref_mut() {
+            let hour_start = Duration::from_secs(hours * SECONDS_PER_HOUR);
+            calendar.trigger_due(hour_start, false, skipped, &mut commands);
+        }
+
+        let day = (hours / 24) as u32;
+        let hour = (hours % 24) as u32;
+        commands.trigger(HourElapsed { day, hour });
+        if hour != 0 {
+            continue;
+        }
+
+        commands.trigger(DayElapsed { day });
+        if clock.days_per_season > 0 && day.is_multiple_of(clock.days_per_season) {
+            commands.trigger(SeasonChanged {
+                season: clock.season_on(day),
+                year: clock.year_on(day),
+            });
+        }
+    }
+
+    if let Some(calendar) = calendar.as_deref_mut() {
+        calendar.trigger_due(clock.elapsed, true, skipped, &mut commands);
+    }
+}
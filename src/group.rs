@@ -0,0 +1,302 @@
+use std::{borrow::Cow, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{dilation::scale_delta, ObservableTimer};
+
+/// A component that adds the [`ObservableTimer`] on the same entity to a named group, so that it can be controlled
+/// together with the rest of the group through the [`TimerGroups`] resource.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((ObservableTimer::indefinite_from_seconds(0.5), TimerGroup::new("enemy_ai")));
+/// # }
+/// ```
+///
+/// Grouped timers are never scheduled by [`TimerScheduling::DueQueue`](crate::TimerScheduling::DueQueue).
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct TimerGroup(pub Cow<'static, str>);
+
+impl TimerGroup {
+    /// Creates a new `TimerGroup` with the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+}
+
+impl Component for TimerGroup {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_insert(|mut world: DeferredWorld, entity: Entity, _: ComponentId| {
+            join_synchronized_group(&mut world, entity);
+        });
+    }
+}
+
+/// The settings of a single group in [`TimerGroups`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct TimerGroupSettings {
+    /// Whether the group's timers are paused. Defaults to `false`.
+    pub paused: bool,
+    /// The speed at which the group's timers run. Defaults to `1.0`. Negative, infinite and NaN scales are treated as
+    /// zero.
+    pub scale: f32,
+    /// Whether timers that join the group are aligned so that their next completion coincides with the rest of the
+    /// group's. Defaults to `false`.
+    ///
+    /// See [`TimerGroups::synchronize()`].
+    pub synchronized: bool,
+    /// Whether repeating timers that join the group have their initial phase offset, so that they don't all complete
+    /// on the same update. Defaults to `false`.
+    ///
+    /// See [`TimerStagger`](crate::TimerStagger).
+    pub staggered: bool,
+}
+
+impl Default for TimerGroupSettings {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            scale: 1.0,
+            synchronized: false,
+            staggered: false,
+        }
+    }
+}
+
+/// A [`Resource`] for controlling every timer in a [`TimerGroup`] at once.
+///
+/// Pausing a group stops its timers from advancing without changing [`ObservableTimer::paused()`], so timers that
+/// were paused individually stay paused when the group is resumed.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// fn open_pause_menu(mut groups: ResMut<TimerGroups>) {
+///     groups.pause("enemy_ai");
+///     groups.set_scale("ui", 0.5);
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct TimerGroups {
+    groups: HashMap<Cow<'static, str>, TimerGroupSettings>,
+    cancelled: Vec<Cow<'static, str>>,
+    /// Synchronized groups whose timers should all be aligned on the next update.
+    resynced: Vec<Cow<'static, str>>,
+    /// Timers that have joined a synchronized group since the last update.
+    joined: Vec<Entity>,
+}
+
+impl TimerGroups {
+    /// Pauses every timer in the group.
+    pub fn pause(&mut self, group: impl Into<Cow<'static, str>>) {
+        self.settings_mut(group).paused = true;
+    }
+
+    /// Resumes every timer in the group.
+    pub fn resume(&mut self, group: impl Into<Cow<'static, str>>) {
+        self.settings_mut(group).paused = false;
+    }
+
+    /// Sets the speed at which every timer in the group runs. Negative, infinite and NaN scales are treated as zero.
+    pub fn set_scale(&mut self, group: impl Into<Cow<'static, str>>, scale: f32) {
+        self.settings_mut(group).scale = if scale.is_finite() {
+            scale.max(0.0)
+        } else {
+            0.0
+        };
+    }
+
+    /// Cancels every timer in the group on the next timer update, by removing their [`ObservableTimer`] components.
+    pub fn cancel(&mut self, group: impl Into<Cow<'static, str>>) {
+        self.cancelled.push(group.into());
+    }
+
+    /// Synchronizes the group's timers, so that their next completions coincide, for things like traffic lights or
+    /// crop growth ticks that should all change at once.
+    ///
+    /// On the next timer update, the current interval of every running timer in the group is lengthened or shortened
+    /// so that it completes along with the group's soonest completing timer. From then on, each timer that joins the
+    /// group (by inserting either its [`TimerGroup`] or its [`ObservableTimer`]) is aligned mid-cycle with the timers
+    /// already in it. Timers with the same interval duration then stay in step, while timers with different interval
+    /// durations only complete together again when their intervals line up.
+    ///
+    /// Aligning a timer only changes the duration of its current interval, and triggers no events.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// let mut world = World::new();
+    /// world.init_resource::<TimerGroups>();
+    /// world.resource_mut::<TimerGroups>().synchronize("traffic_lights");
+    ///
+    /// let group = TimerGroup::new("traffic_lights");
+    /// world.spawn((ObservableTimer::indefinite_from_seconds(30.0), group.clone()));
+    /// world.tick_observable_timers(Duration::from_secs(10));
+    ///
+    /// // The new light changes along with the first one, then every 30 seconds
+    /// let light = world.spawn((ObservableTimer::indefinite_from_seconds(30.0), group)).id();
+    /// world.tick_observable_timers(Duration::ZERO);
+    /// let timer = world.get::<ObservableTimer>(light).unwrap();
+    /// assert_eq!(timer.interval_remaining(), Duration::from_secs(20));
+    /// ```
+    pub fn synchronize(&mut self, group: impl Into<Cow<'static, str>>) {
+        let group = group.into();
+        self.settings_mut(group.clone()).synchronized = true;
+        self.resynced.push(group);
+    }
+
+    /// Staggers the group's timers, so that repeating timers added to it have their first interval shortened by
+    /// differing amounts, spreading their completions out instead of having them all complete on the same update.
+    ///
+    /// Only timers that are inserted after this is called are staggered. See [`TimerStagger`](crate::TimerStagger).
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// fn setup(mut groups: ResMut<TimerGroups>) {
+    ///     groups.stagger("crop_growth");
+    /// }
+    /// ```
+    pub fn stagger(&mut self, group: impl Into<Cow<'static, str>>) {
+        self.settings_mut(group).staggered = true;
+    }
+
+    /// Returns `true` if the group is paused.
+    pub fn is_paused(&self, group: &str) -> bool {
+        self.settings(group).paused
+    }
+
+    /// The speed at which the group's timers run.
+    pub fn scale(&self, group: &str) -> f32 {
+        self.settings(group).scale
+    }
+
+    /// The settings of the group.
+    pub fn settings(&self, group: &str) -> TimerGroupSettings {
+        self.groups.get(group).copied().unwrap_or_default()
+    }
+
+    /// The mutable settings of the group.
+    pub fn settings_mut(&mut self, group: impl Into<Cow<'static, str>>) -> &mut TimerGroupSettings {
+        self.groups.entry(group.into()).or_default()
+    }
+
+    /// The amount that a timer in `group` should advance when `delta` has passed.
+    pub(crate) fn delta(
+        groups: Option<&Self>,
+        group: Option<&TimerGroup>,
+        delta: Duration,
+    ) -> Duration {
+        let (Some(groups), Some(group)) = (groups, group) else {
+            return delta;
+        };
+        let settings = groups.settings(&group.0);
+        if settings.paused {
+            Duration::ZERO
+        } else {
+            scale_delta(delta, f64::from(settings.scale))
+        }
+    }
+}
+
+pub(crate) fn cancel_timer_groups(
+    groups: Option<ResMut<TimerGroups>>,
+    timers: Query<(Entity, &TimerGroup), With<ObservableTimer>>,
+    mut commands: Commands,
+) {
+    let Some(mut groups) = groups else {
+        return;
+    };
+    if groups.cancelled.is_empty() {
+        return;
+    }
+
+    let cancelled = std::mem::take(&mut groups.cancelled);
+    for (entity, group) in timers.iter() {
+        if cancelled.contains(&group.0) {
+            commands.entity(entity).remove::<ObservableTimer>();
+        }
+    }
+}
+
+/// Records that the timer on `entity` has joined its group, if the group is synchronized.
+pub(crate) fn join_synchronized_group(world: &mut DeferredWorld, entity: Entity) {
+    let (Some(group), Some(groups)) = (
+        world.get::<TimerGroup>(entity),
+        world.get_resource::<TimerGroups>(),
+    ) else {
+        return;
+    };
+    if !groups.settings(&group.0).synchronized || world.get::<ObservableTimer>(entity).is_none() {
+        return;
+    }
+    world.resource_mut::<TimerGroups>().joined.push(entity);
+}
+
+pub(crate) fn sync_timer_groups(
+    groups: Option<ResMut<TimerGroups>>,
+    mut timers: Query<(Entity, &TimerGroup, &mut ObservableTimer)>,
+) {
+    let Some(mut groups) = groups else {
+        return;
+    };
+    if groups.joined.is_empty() && groups.resynced.is_empty() {
+        return;
+    }
+
+    let mut joined = std::mem::take(&mut groups.joined);
+    joined.sort_unstable();
+    joined.dedup();
+    let resynced = std::mem::take(&mut groups.resynced);
+    let aligns = |entity: Entity, group: &TimerGroup| {
+        resynced.contains(&group.0) || joined.binary_search(&entity).is_ok()
+    };
+
+    // Timers are aligned with the soonest next completion in their group, preferring timers that were already in step
+    let mut established: HashMap<Cow<'static, str>, Duration> = HashMap::default();
+    let mut newcomers: HashMap<Cow<'static, str>, Duration> = HashMap::default();
+    for (entity, group, timer) in timers.iter() {
+        if timer.paused() || timer.is_done() || !groups.settings(&group.0).synchronized {
+            continue;
+        }
+        let completions = if aligns(entity, group) && !resynced.contains(&group.0) {
+            &mut newcomers
+        } else {
+            &mut established
+        };
+        let remaining = timer.interval_remaining();
+        completions
+            .entry(group.0.clone())
+            .and_modify(|soonest| *soonest = (*soonest).min(remaining))
+            .or_insert(remaining);
+    }
+
+    for (entity, group, mut timer) in timers.iter_mut() {
+        if timer.is_done() || !aligns(entity, group) {
+            continue;
+        }
+        let Some(&remaining) = established
+            .get(&group.0)
+            .or_else(|| newcomers.get(&group.0))
+        else {
+            continue;
+        };
+        if timer.interval_remaining() != remaining {
+            let elapsed = timer.timer.elapsed();
+            timer.timer.set_duration(elapsed + remaining);
+        }
+    }
+}
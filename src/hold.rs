@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::TimerTag;
+
+/// A timer component for "hold to confirm" interactions, such as holding a button to delete a save.
+///
+/// The timer does nothing until it is [pressed](Self::press()). While held, a [`HoldProgress`] event is triggered on
+/// its entity every update. Once it has been held for its [duration](Self::duration), a [`HoldCompleted`] event is
+/// triggered and the timer stops. If it is [released](Self::release()) before then, a [`HoldAborted`] event is
+/// triggered on the next update instead. Pressing the timer again starts over from zero.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let delete_save = world.spawn(HoldTimer::from_seconds(1.5)).id();
+///
+/// world.get_mut::<HoldTimer>(delete_save).unwrap().press();
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.get::<HoldTimer>(delete_save).unwrap().fraction(), 1.0 / 1.5);
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert!(world.get::<HoldTimer>(delete_save).unwrap().is_completed());
+/// ```
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct HoldTimer {
+    /// How long the timer must be held for to complete.
+    pub duration: Duration,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    held: bool,
+    held_for: Duration,
+    completed: bool,
+    /// How long the timer had been held for when it was released, until [`HoldAborted`] is triggered.
+    aborted: Option<Duration>,
+}
+
+impl HoldTimer {
+    /// Creates a hold timer that completes after being held for `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            tag: None,
+            held: false,
+            held_for: Duration::ZERO,
+            completed: false,
+            aborted: None,
+        }
+    }
+
+    /// Creates a hold timer that completes after being held for the given number of seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Starts holding, from zero. Does nothing if the timer is already held.
+    pub fn press(&mut self) {
+        if !self.held {
+            self.held = true;
+            self.held_for = Duration::ZERO;
+            self.completed = false;
+        }
+    }
+
+    /// Stops holding. If the hold hadn't completed, [`HoldAborted`] is triggered on the next update.
+    pub fn release(&mut self) {
+        if self.held {
+            self.held = false;
+            self.aborted = Some(self.held_for);
+        }
+    }
+
+    /// Returns `true` while the timer is held.
+    pub fn is_held(&self) -> bool {
+        self.held
+    }
+
+    /// Returns `true` once the timer has been held for its full duration, until it is pressed again.
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// How long the timer has been held for.
+    pub fn held_for(&self) -> Duration {
+        self.held_for
+    }
+
+    /// How far through the hold the timer is, between 0 and 1.
+    pub fn fraction(&self) -> f32 {
+        if self.duration.is_zero() {
+            1.0
+        } else {
+            (self.held_for.as_secs_f64() / self.duration.as_secs_f64()).min(1.0) as f32
+        }
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`HoldTimer`]'s entity every update while it is held.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct HoldProgress {
+    fraction: f32,
+    tag: Option<TimerTag>,
+}
+
+impl HoldProgress {
+    /// How far through the hold the timer is, between 0 and 1.
+    pub fn fraction(&self) -> f32 {
+        self.fraction
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`HoldTimer`]'s entity once it has been held for its full duration.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct HoldCompleted {
+    tag: Option<TimerTag>,
+}
+
+impl HoldCompleted {
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`HoldTimer`]'s entity when it is released before completing.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct HoldAborted {
+    held_for: Duration,
+    tag: Option<TimerTag>,
+}
+
+impl HoldAborted {
+    /// How long the timer had been held for when it was released.
+    pub fn held_for(&self) -> Duration {
+        self.held_for
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+pub(crate) fn update_hold_timers(
+    In(delta): In<Duration>,
+    mut timers: Query<(Entity, &mut HoldTimer)>,
+    mut commands: Commands,
+) {
+    for (entity, mut timer) in timers.iter_mut() {
+        if let Some(held_for) = timer.aborted.take() {
+            let tag = timer.tag;
+            commands.trigger_targets(HoldAborted { held_for, tag }, entity);
+        }
+        if !timer.held {
+            continue;
+        }
+
+        timer.held_for = (timer.held_for + delta).min(timer.duration);
+        let (fraction, tag) = (timer.fraction(), timer.tag);
+        commands.trigger_targets(HoldProgress { fraction, tag }, entity);
+
+        if timer.held_for >= timer.duration {
+            timer.held = false;
+            timer.completed = true;
+            commands.trigger_targets(HoldCompleted { tag }, entity);
+        }
+    }
+}
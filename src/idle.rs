@@ -0,0 +1,253 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::{event::ManualEventReader, system::SystemParam},
+    input::{
+        gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent},
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+        touch::TouchInput,
+    },
+    prelude::*,
+};
+
+use crate::TimerTag;
+
+/// The kinds of input that count as activity for an [`IdleTimer`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct IdleInputs {
+    /// Key presses and releases.
+    pub keyboard: bool,
+    /// Mouse button presses and releases.
+    pub mouse_buttons: bool,
+    /// Mouse movement.
+    pub mouse_motion: bool,
+    /// Mouse wheel scrolling.
+    pub mouse_wheel: bool,
+    /// Gamepad button and axis changes.
+    pub gamepad: bool,
+    /// Touches.
+    pub touch: bool,
+}
+
+impl IdleInputs {
+    /// No input at all.
+    pub const NONE: Self = Self {
+        keyboard: false,
+        mouse_buttons: false,
+        mouse_motion: false,
+        mouse_wheel: false,
+        gamepad: false,
+        touch: false,
+    };
+
+    /// Every kind of input.
+    pub const ALL: Self = Self {
+        keyboard: true,
+        mouse_buttons: true,
+        mouse_motion: true,
+        mouse_wheel: true,
+        gamepad: true,
+        touch: true,
+    };
+
+    /// Returns `true` if any kind of input is in both `self` and `other`.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (self.keyboard && other.keyboard)
+            || (self.mouse_buttons && other.mouse_buttons)
+            || (self.mouse_motion && other.mouse_motion)
+            || (self.mouse_wheel && other.mouse_wheel)
+            || (self.gamepad && other.gamepad)
+            || (self.touch && other.touch)
+    }
+}
+
+impl Default for IdleInputs {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// A timer component that detects when the user has stopped providing input.
+///
+/// Once none of the configured [`IdleInputs`] have been received for the [threshold](Self::threshold), a [`UserIdle`]
+/// event is triggered on the timer's entity. The next time input is received a [`UserActive`] event is triggered, and
+/// the timer starts counting again. This suits attract modes, auto-pausing and screensaver-style behavior. Several
+/// idle timers with different thresholds may run at once.
+///
+/// Input is read from the events sent by bevy's `InputPlugin`. Without it, idle timers only ever become idle.
+///
+/// ```ignore
+/// commands
+///     .spawn(IdleTimer::from_seconds(60.0))
+///     .observe(|_: Trigger<UserIdle>, mut next_state: ResMut<NextState<GameState>>| {
+///         next_state.set(GameState::Paused);
+///     });
+/// ```
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct IdleTimer {
+    /// How long without input before the user is considered idle.
+    pub threshold: Duration,
+    /// The kinds of input that reset the timer. Defaults to [`IdleInputs::ALL`].
+    pub inputs: IdleInputs,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    idle_for: Duration,
+    idle: bool,
+}
+
+impl IdleTimer {
+    /// Creates an idle timer with the given threshold.
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            inputs: IdleInputs::ALL,
+            tag: None,
+            idle_for: Duration::ZERO,
+            idle: false,
+        }
+    }
+
+    /// Creates an idle timer with a threshold of the given number of seconds.
+    pub fn from_seconds(threshold_seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(threshold_seconds))
+    }
+
+    /// Sets the kinds of input that reset the timer.
+    pub fn with_inputs(mut self, inputs: IdleInputs) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The time since input was last received.
+    pub fn idle_for(&self) -> Duration {
+        self.idle_for
+    }
+
+    /// Returns `true` if the user is currently considered idle.
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Resets the timer as though input had been received, without triggering [`UserActive`].
+    pub fn reset(&mut self) {
+        self.idle_for = Duration::ZERO;
+        self.idle = false;
+    }
+}
+
+/// A timer [`Event`] that is triggered on an [`IdleTimer`]'s entity once its threshold passes without input.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct UserIdle {
+    tag: Option<TimerTag>,
+}
+
+impl UserIdle {
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered on an idle [`IdleTimer`]'s entity when input is received again.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct UserActive {
+    idle_for: Duration,
+    tag: Option<TimerTag>,
+}
+
+impl UserActive {
+    /// How long the user was without input, including the threshold.
+    pub fn idle_for(&self) -> Duration {
+        self.idle_for
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// Reads a single kind of input event, if its events exist.
+#[derive(SystemParam)]
+struct InputEvents<'w, 's, E: Event> {
+    events: Option<Res<'w, Events<E>>>,
+    reader: Local<'s, ManualEventReader<E>>,
+}
+
+impl<E: Event> InputEvents<'_, '_, E> {
+    /// Returns `true` if any events were sent since the last call.
+    fn received(&mut self) -> bool {
+        let Some(events) = &self.events else {
+            return false;
+        };
+        let received = !self.reader.is_empty(events);
+        self.reader.clear(events);
+        received
+    }
+}
+
+/// The input events that count as activity for [`IdleTimer`]s.
+#[derive(SystemParam)]
+pub(crate) struct InputActivity<'w, 's> {
+    keyboard: InputEvents<'w, 's, KeyboardInput>,
+    mouse_buttons: InputEvents<'w, 's, MouseButtonInput>,
+    mouse_motion: InputEvents<'w, 's, MouseMotion>,
+    mouse_wheel: InputEvents<'w, 's, MouseWheel>,
+    gamepad_buttons: InputEvents<'w, 's, GamepadButtonChangedEvent>,
+    gamepad_axes: InputEvents<'w, 's, GamepadAxisChangedEvent>,
+    touch: InputEvents<'w, 's, TouchInput>,
+}
+
+impl InputActivity<'_, '_> {
+    /// The kinds of input received since the last call.
+    fn received(&mut self) -> IdleInputs {
+        IdleInputs {
+            keyboard: self.keyboard.received(),
+            mouse_buttons: self.mouse_buttons.received(),
+            mouse_motion: self.mouse_motion.received(),
+            mouse_wheel: self.mouse_wheel.received(),
+            gamepad: self.gamepad_buttons.received() | self.gamepad_axes.received(),
+            touch: self.touch.received(),
+        }
+    }
+}
+
+pub(crate) fn update_idle_timers(
+    In(delta): In<Duration>,
+    mut activity: InputActivity,
+    mut timers: Query<(Entity, &mut IdleTimer)>,
+    mut commands: Commands,
+) {
+    let received = activity.received();
+    for (entity, mut timer) in timers.iter_mut() {
+        if received.intersects(&timer.inputs) {
+            if timer.idle {
+                let (idle_for, tag) = (timer.idle_for, timer.tag);
+                commands.trigger_targets(UserActive { idle_for, tag }, entity);
+            }
+            timer.reset();
+            continue;
+        }
+
+        timer.idle_for += delta;
+        if !timer.idle && timer.idle_for >= timer.threshold {
+            timer.idle = true;
+            commands.trigger_targets(UserIdle { tag: timer.tag }, entity);
+        }
+    }
+}
@@ -1,27 +1,273 @@
 #![doc = include_str!("../README.md")]
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use bevy::{
     ecs::{
         component::{ComponentHooks, ComponentId, StorageType},
-        schedule::{InternedScheduleLabel, ScheduleLabel},
+        schedule::{BoxedCondition, InternedScheduleLabel, ScheduleLabel},
         world::DeferredWorld,
     },
     prelude::*,
+    window::AppLifecycle,
 };
 
-/// The [`SystemSet`] during which [`ObservableTimer`]s are updated.
+#[cfg(feature = "animation")]
+mod animation;
+#[cfg(feature = "bevy_audio")]
+mod audio;
+mod autosave;
+mod beat;
+mod buffered;
+mod calendar;
+mod channel;
+mod collect;
+mod commands;
+mod compact;
+mod condition;
+mod controls;
+mod cron;
+mod deadline;
+#[cfg(feature = "debug_gizmos")]
+mod debug_gizmos;
+#[cfg(feature = "debug_log")]
+mod debug_log;
+#[cfg(feature = "bevy_egui")]
+mod debug_ui;
+mod delayed;
+mod despawn_after;
+mod despawn_budget;
+mod dilation;
+mod disabling;
+mod dismiss;
+mod duty_cycle;
+mod event_budget;
+mod finish_event;
+mod fire_at;
+mod focus;
+mod frame;
+mod future;
+mod game_clock;
+mod group;
+mod hold;
+mod idle;
+mod lifecycle;
+mod manual;
+mod observe;
+mod queue;
+mod registry;
+mod resource_timer;
+mod respawn;
+#[cfg(feature = "rollback")]
+mod rollback;
+mod run_conditions;
+mod snapshot;
+mod spawn;
+mod stagger;
+mod start;
+#[cfg(feature = "bevy_state")]
+mod state;
+mod stopwatch;
+mod subscribers;
+mod task;
+mod teardown;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
+#[cfg(feature = "bevy_text")]
+mod text;
+mod timeline;
+mod ttl_map;
+#[cfg(feature = "ui")]
+mod ui;
+mod update;
+mod watchdog;
+
+#[cfg(feature = "animation")]
+pub use animation::AnimationTimeline;
+#[cfg(feature = "bevy_audio")]
+pub use audio::AudioSpeedSource;
+pub use autosave::*;
+pub use beat::*;
+pub use buffered::*;
+pub use calendar::GameCalendar;
+pub use channel::{TimerCommandChannel, TimerRequest, TimerSender};
+pub use collect::{TimerCollected, TimerCollector};
+pub use commands::*;
+pub use compact::{CompactTimer, CompactTimerPlugin};
+pub use condition::TimerCancelCondition;
+pub use controls::*;
+pub use cron::*;
+pub use deadline::*;
+#[cfg(feature = "debug_gizmos")]
+pub use debug_gizmos::*;
+#[cfg(feature = "bevy_egui")]
+pub use debug_ui::*;
+pub use delayed::{EffectExpired, EventAfter, InsertAfter, RemoveAfter, TriggerAfter};
+pub use despawn_after::*;
+pub use despawn_budget::*;
+pub use dilation::TimeDilation;
+pub use disabling::{TimerDisabled, TimerPaused, TimerResumed};
+pub use dismiss::*;
+pub use duty_cycle::*;
+pub use event_budget::TimerEventBudget;
+pub use finish_event::*;
+pub use fire_at::*;
+pub use focus::{TimerFocusPause, TimersRefocused};
+pub use frame::*;
+pub use future::TimerFinishedFuture;
+pub use game_clock::*;
+pub use group::*;
+pub use hold::*;
+pub use idle::*;
+pub use lifecycle::{TimerSuspendPause, TimersResumed, WallClockCatchUp};
+pub use manual::*;
+pub use observe::*;
+pub use queue::TimerScheduling;
+pub use registry::*;
+pub use resource_timer::{ResourceTimer, ResourceTimerElapsed};
+pub use respawn::{RespawnAfter, Respawned};
+#[cfg(feature = "rollback")]
+pub use rollback::{RestoreTimerExt, RollbackFinishedEvents, TimerRollback};
+pub use run_conditions::*;
+pub use snapshot::*;
+pub use spawn::*;
+pub use stagger::TimerStagger;
+pub use stopwatch::*;
+pub use subscribers::*;
+pub use task::*;
+pub use teardown::TimerTeardown;
+#[cfg(feature = "bevy_text")]
+pub use text::*;
+pub use timeline::*;
+pub use ttl_map::{EntryExpired, TtlMap, TtlMapAppExt};
+#[cfg(feature = "ui")]
+pub use ui::*;
+pub use watchdog::*;
+
+use autosave::update_autosave;
+use buffered::{send_finished_events, send_interval_events, send_started_events};
+use channel::apply_timer_requests;
+use collect::collect_finished_timers;
+use commands::TimerCancelledSilently;
+use condition::{check_timer_cancel_conditions, TimerConditionMet};
+use delayed::update_delayed_components;
+use despawn_after::update_despawn_after;
+use despawn_budget::apply_despawn_budget;
+use disabling::{forget_disabled_timer, pause_if_disabled, register_disabling_component};
+use dismiss::update_dismiss_after;
+use duty_cycle::update_duty_cycle_timers;
+use finish_event::trigger_finish_events;
+use focus::{track_window_focus, window_focused};
+use frame::update_frame_timers;
+use game_clock::update_game_clock;
+use group::{cancel_timer_groups, join_synchronized_group, sync_timer_groups};
+use hold::update_hold_timers;
+use idle::update_idle_timers;
+use lifecycle::{app_running, track_app_lifecycle};
+use queue::{stop_queueing, TimerQueue};
+use registry::update_timer_registry;
+use respawn::update_respawn_after;
+use run_conditions::{clear_finished_timers, record_finished_timers};
+use spawn::spawn_on_interval;
+use stagger::{queue_staggered_timer, stagger_timers};
+use start::{flush_timer_start, queue_timer_start, trigger_timer_starts, PendingTimerStarts};
+#[cfg(feature = "bevy_state")]
+pub use state::*;
+use stopwatch::update_split_stopwatches;
+use teardown::{begin_teardown_on_exit, is_tearing_down};
+use update::{
+    apply_pending_finish_behaviors, ticks_automatically, timer_delta, trigger_timer_events,
+    trigger_timer_events_immediately, triggers_immediately, update_observable_timers,
+    update_timer_fractions, update_timers, PendingTimerUpdates, PhaseClock, RunningTimer,
+    RunningTimers,
+};
+use watchdog::update_watchdogs;
+
+/// The [`SystemSet`] during which [`ObservableTimer`]s are updated, by running the [`ObservableTimerUpdate`] schedule.
 ///
 /// Runs in [`Update`] by default, but this is configurable. See [`ObservableTimerPlugin::in_schedule()`].
+///
+/// Timers are ticked in parallel (when bevy's `multi_threaded` feature is enabled). The events for each individual
+/// timer are always triggered in order, but events belonging to different timers may be interleaved in any order.
 #[derive(SystemSet, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ObservableTimerSet;
 
+/// The [`Schedule`] that updates every kind of timer.
+///
+/// [`ObservableTimerPlugin`] runs this schedule within [`ObservableTimerSet`], and
+/// [`ObservableTimerWorldExt::tick_observable_timers()`] runs the very same schedule with an explicit delta, so both
+/// keep the state of the timer systems between updates. The update of [`ObservableTimer`]s is further split into the
+/// ordered [`ObservableTimerSystems`] sub-sets of this schedule.
+#[derive(ScheduleLabel, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObservableTimerUpdate;
+
+/// The ordered steps of updating [`ObservableTimer`]s, which run in turn within the [`ObservableTimerUpdate`]
+/// schedule.
+///
+/// Commands queued in each step are applied before the next one starts, so systems ordered between them see the
+/// timers at a precise point of the update. For example, a system that runs after [`Self::EmitEvents`] and before
+/// [`Self::ApplyFinishBehavior`] sees timers that have just finished after their observers have run, but before their
+/// entities are despawned.
+///
+/// The other kinds of timers (such as [`CronTimer`] and [`TimerRegistry`]) aren't part of these steps, and trigger
+/// their own events and apply their own finish behaviors as they update.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// fn count_finished_timers(timers: Query<&ObservableTimer>) {
+///     let finished = timers.iter().filter(|timer| timer.is_done()).count();
+///     info!("{finished} timers are about to be cleaned up");
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(ObservableTimerPlugin::default()).add_systems(
+///     ObservableTimerUpdate,
+///     count_finished_timers
+///         .after(ObservableTimerSystems::EmitEvents)
+///         .before(ObservableTimerSystems::ApplyFinishBehavior),
+/// );
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObservableTimerSystems {
+    /// Timers are advanced, recording the events they should trigger. Timer components are already up to date after
+    /// this step, but no events have been triggered yet.
+    Tick,
+    /// The recorded timer events are triggered, and their observers run.
+    EmitEvents,
+    /// The [`TimerFinishBehavior`]s of timers that finished are applied.
+    ApplyFinishBehavior,
+}
+
+/// Creates a new instance of a run condition added with [`ObservableTimerPlugin::run_if()`], so that the plugin can be
+/// built more than once.
+type ConditionFactory = Arc<dyn Fn() -> BoxedCondition + Send + Sync>;
+
 /// This plugin provides functionality for the [`ObservableTimer`] component.
 ///
 /// See the crate-level documentation for more information.
 pub struct ObservableTimerPlugin {
     schedule: InternedScheduleLabel,
+    tick_mode: TimerTickMode,
+    catch_up: TimerCatchUp,
+    scheduling: TimerScheduling,
+    event_order: TimerEventOrder,
+    trigger_mode: TimerTriggerMode,
+    start_mode: TimerStartMode,
+    buffered_events: bool,
+    collection: Option<TimerCollector>,
+    despawn_budget: Option<usize>,
+    event_budget: Option<usize>,
+    game_clock: Option<GameClock>,
+    phase_stagger: bool,
+    focus_pause: bool,
+    suspend_pause: Option<WallClockCatchUp>,
+    exit_teardown: bool,
+    disabling_components: Vec<fn(&mut App)>,
+    autosave: Option<AutosaveTimer>,
+    conditions: Vec<ConditionFactory>,
+    #[cfg(feature = "rollback")]
+    rollback: Option<u32>,
 }
 
 impl ObservableTimerPlugin {
@@ -41,8 +287,415 @@ impl ObservableTimerPlugin {
     pub fn in_schedule(schedule: impl ScheduleLabel) -> Self {
         Self {
             schedule: schedule.intern(),
+            tick_mode: TimerTickMode::default(),
+            catch_up: TimerCatchUp::default(),
+            scheduling: TimerScheduling::default(),
+            event_order: TimerEventOrder::default(),
+            trigger_mode: TimerTriggerMode::default(),
+            start_mode: TimerStartMode::default(),
+            buffered_events: false,
+            collection: None,
+            despawn_budget: None,
+            event_budget: None,
+            game_clock: None,
+            phase_stagger: false,
+            focus_pause: false,
+            suspend_pause: None,
+            exit_teardown: false,
+            disabling_components: Vec::new(),
+            autosave: None,
+            conditions: Vec::new(),
+            #[cfg(feature = "rollback")]
+            rollback: None,
         }
     }
+
+    /// Sets how timers are advanced each update. See [`TimerTickMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // Every update advances timers by exactly 1/60th of a second
+    /// app.add_plugins(
+    ///     ObservableTimerPlugin::in_schedule(FixedUpdate)
+    ///         .with_tick_mode(TimerTickMode::FixedStep(Duration::from_nanos(16_666_667))),
+    /// );
+    /// ```
+    pub fn with_tick_mode(mut self, tick_mode: TimerTickMode) -> Self {
+        self.tick_mode = tick_mode;
+        self
+    }
+
+    /// Sets the default [catch-up policy](TimerCatchUp) for timers that don't specify their own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // No timer will trigger more than 3 `TimerInterval`s in one update
+    /// app.add_plugins(ObservableTimerPlugin::default().with_catch_up(TimerCatchUp::DropExtra(3)));
+    /// ```
+    pub fn with_catch_up(mut self, catch_up: TimerCatchUp) -> Self {
+        self.catch_up = catch_up;
+        self
+    }
+
+    /// Sets how timers are scheduled for updates. See [`TimerScheduling`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_scheduling(TimerScheduling::DueQueue));
+    /// ```
+    pub fn with_scheduling(mut self, scheduling: TimerScheduling) -> Self {
+        self.scheduling = scheduling;
+        self
+    }
+
+    /// Sets the order in which the events of different timers are triggered. See [`TimerEventOrder`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // Identical inputs trigger identical events, in an identical order
+    /// app.add_plugins(
+    ///     ObservableTimerPlugin::in_schedule(FixedUpdate)
+    ///         .with_tick_mode(TimerTickMode::FixedStep(Duration::from_nanos(16_666_667)))
+    ///         .with_event_order(TimerEventOrder::ByEntity),
+    /// );
+    /// ```
+    pub fn with_event_order(mut self, event_order: TimerEventOrder) -> Self {
+        self.event_order = event_order;
+        self
+    }
+
+    /// Sets whether timer events are triggered through [`Commands`] or immediately. See [`TimerTriggerMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_trigger_mode(TimerTriggerMode::Immediate));
+    /// ```
+    pub fn with_trigger_mode(mut self, trigger_mode: TimerTriggerMode) -> Self {
+        self.trigger_mode = trigger_mode;
+        self
+    }
+
+    /// Sets whether [`TimerStarted`] is triggered on the next timer update, or as soon as a timer is inserted. See
+    /// [`TimerStartMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_start_mode(TimerStartMode::Immediate));
+    /// ```
+    pub fn with_start_mode(mut self, start_mode: TimerStartMode) -> Self {
+        self.start_mode = start_mode;
+        self
+    }
+
+    /// Sets whether timer events are also sent as buffered events, which can be read in bulk with an [`EventReader`].
+    ///
+    /// When enabled, every [`TimerStarted`], [`TimerInterval`] and [`TimerFinished`] is mirrored by a
+    /// [`TimerStartedEvent`], [`TimerIntervalEvent`] or [`TimerFinishedEvent`] carrying the timer's entity. This is
+    /// disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_buffered_events(true))
+    ///     .add_systems(Update, |mut finished: EventReader<TimerFinishedEvent>| {
+    ///         for event in finished.read() {
+    ///             info!("{:?} finished", event.entity());
+    ///         }
+    ///     });
+    /// ```
+    pub fn with_buffered_events(mut self, buffered_events: bool) -> Self {
+        self.buffered_events = buffered_events;
+        self
+    }
+
+    /// Enables collecting timers that have been finished for at least `grace_period`. See [`TimerCollector`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // Finished timers that are kept around are removed a minute after they finish
+    /// app.add_plugins(ObservableTimerPlugin::default().with_timer_collection(Duration::from_secs(60)));
+    /// ```
+    pub fn with_timer_collection(mut self, grace_period: Duration) -> Self {
+        self.collection = Some(TimerCollector::new(grace_period));
+        self
+    }
+
+    /// Limits how many entities finished timers despawn per update, queueing the rest for following updates. See
+    /// [`TimerDespawnBudget`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_despawn_budget(500));
+    /// ```
+    pub fn with_despawn_budget(mut self, per_update: usize) -> Self {
+        self.despawn_budget = Some(per_update);
+        self
+    }
+
+    /// Limits how many timer events are triggered per update, queueing the rest for following updates. See
+    /// [`TimerEventBudget`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_event_budget(2000));
+    /// ```
+    pub fn with_event_budget(mut self, per_update: usize) -> Self {
+        self.event_budget = Some(per_update);
+        self
+    }
+
+    /// Inserts a [`GameClock`], which tracks in-game hours, days and seasons, along with an empty [`GameCalendar`] for
+    /// scheduling events at in-game times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // Ten in-game minutes pass every real second
+    /// app.add_plugins(ObservableTimerPlugin::default().with_game_clock(GameClock::new(600.0)));
+    /// ```
+    pub fn with_game_clock(mut self, clock: GameClock) -> Self {
+        self.game_clock = Some(clock);
+        self
+    }
+
+    /// Sets whether every newly added repeating timer has its initial phase offset, so that timers with the same
+    /// interval duration don't all complete on the same update. See [`TimerStagger`].
+    ///
+    /// Staggering can also be enabled for individual groups with [`TimerGroups::stagger()`]. This is disabled by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_phase_stagger(true));
+    /// ```
+    pub fn with_phase_stagger(mut self, phase_stagger: bool) -> Self {
+        self.phase_stagger = phase_stagger;
+        self
+    }
+
+    /// Sets whether timers are paused while the primary window is unfocused or minimized. See [`TimerFocusPause`].
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_focus_pause(true))
+    ///     .observe(|trigger: Trigger<TimersRefocused>| {
+    ///         info!("Welcome back after {:?}", trigger.event().unfocused_for());
+    ///     });
+    /// ```
+    pub fn with_focus_pause(mut self, focus_pause: bool) -> Self {
+        self.focus_pause = focus_pause;
+        self
+    }
+
+    /// Freezes timers while the app is suspended in the background, as happens on Android and iOS. See
+    /// [`TimerSuspendPause`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// // Reminders that came due while in the background are delivered once the app resumes
+    /// app.add_plugins(ObservableTimerPlugin::default().with_suspend_pause(WallClockCatchUp::Fire));
+    /// ```
+    pub fn with_suspend_pause(mut self, wall_clock: WallClockCatchUp) -> Self {
+        self.suspend_pause = Some(wall_clock);
+        self
+    }
+
+    /// Sets whether timers stop triggering events when they are removed or despawned once the app is exiting. See
+    /// [`TimerTeardown`].
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_exit_teardown(true));
+    /// ```
+    pub fn with_exit_teardown(mut self, exit_teardown: bool) -> Self {
+        self.exit_teardown = exit_teardown;
+        self
+    }
+
+    /// Pauses the timers of entities that have a `C` component, and resumes them once it is removed. See
+    /// [`TimerDisabled`].
+    ///
+    /// May be called multiple times to register several disabling components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Component)]
+    /// # struct Disabled;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_disabling_component::<Disabled>());
+    /// ```
+    pub fn with_disabling_component<C: Component>(mut self) -> Self {
+        self.disabling_components
+            .push(register_disabling_component::<C>);
+        self
+    }
+
+    /// Inserts an [`AutosaveTimer`], which triggers a global [`SaveRequested`] event after each interval of play.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(ObservableTimerPlugin::default().with_autosave(AutosaveTimer::from_minutes(10.0)))
+    ///     .observe(|_: Trigger<SaveRequested>| info!("Saving..."));
+    /// ```
+    pub fn with_autosave(mut self, autosave: AutosaveTimer) -> Self {
+        self.autosave = Some(autosave);
+        self
+    }
+
+    /// Adds a run condition to [`ObservableTimerSet`]. Timers are only updated while every condition is `true`.
+    ///
+    /// May be called multiple times to add several conditions. Each condition is cloned when the plugin is built, which
+    /// bevy's own run conditions, and functions and closures whose captures are [`Clone`], all support.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Resource)]
+    /// # struct Paused;
+    /// # let mut app = App::new();
+    /// // Timers will not advance while the `Paused` resource exists
+    /// app.add_plugins(ObservableTimerPlugin::default().run_if(not(resource_exists::<Paused>)));
+    /// ```
+    pub fn run_if<M, C>(mut self, condition: C) -> Self
+    where
+        C: Condition<M>,
+        C::System: Clone,
+    {
+        let condition = IntoSystem::into_system(condition);
+        self.conditions
+            .push(Arc::new(move || Box::new(condition.clone())));
+        self
+    }
+
+    /// Deduplicates [`TimerFinished`] events across rollbacks of up to `max_rollback` ticks. See [`TimerRollback`].
+    ///
+    /// Requires the `rollback` feature.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::{app::FixedMain, prelude::*};
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Resource)]
+    /// # struct NetworkTick(u32);
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     ObservableTimerPlugin::in_schedule(FixedMain)
+    ///         .with_tick_mode(TimerTickMode::FixedStep(Duration::from_nanos(15_625_000)))
+    ///         .with_rollback(32),
+    /// )
+    /// // The netcode's tick is passed on before timers update, including while resimulating
+    /// .add_systems(FixedMain, set_timer_tick.before(ObservableTimerSet));
+    ///
+    /// fn set_timer_tick(tick: Res<NetworkTick>, mut rollback: ResMut<TimerRollback>) {
+    ///     rollback.set_tick(tick.0);
+    /// }
+    /// ```
+    #[cfg(feature = "rollback")]
+    pub fn with_rollback(mut self, max_rollback: u32) -> Self {
+        self.rollback = Some(max_rollback);
+        self
+    }
+
+    /// Pauses all timers while the app is in `state`. Timer components are not modified, they simply aren't updated.
+    ///
+    /// May be called multiple times to pause timers in several states.
+    ///
+    /// Requires the `bevy_state` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum GameState { Paused, Loading }
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     ObservableTimerPlugin::default()
+    ///         .pause_in_state(GameState::Paused)
+    ///         .pause_in_state(GameState::Loading),
+    /// );
+    /// ```
+    #[cfg(feature = "bevy_state")]
+    pub fn pause_in_state<S: States>(self, state: S) -> Self {
+        self.run_if(not(in_state(state)))
+    }
 }
 
 impl Default for ObservableTimerPlugin {
@@ -53,19 +706,416 @@ impl Default for ObservableTimerPlugin {
 
 impl Plugin for ObservableTimerPlugin {
     fn build(&self, app: &mut App) {
+        app.register_type::<ObservableTimer>()
+            .register_type::<TimerFinishBehavior>()
+            .register_type::<TimerBackoff>()
+            .register_type::<TimerDirection>()
+            .register_type::<TimerTag>()
+            .register_type::<TimerFraction>()
+            .register_type::<TimerIsPaused>()
+            .register_type::<TimerDisabled>()
+            .register_type::<TimerPaused>()
+            .register_type::<TimerResumed>()
+            .register_type::<TimerStarted>()
+            .register_type::<TimerReplaced>()
+            .register_type::<TimerInterval>()
+            .register_type::<TimerSecondElapsed>()
+            .register_type::<TimerProgress>()
+            .register_type::<TimerAdjusted>()
+            .register_type::<TimerRestarted>()
+            .register_type::<TimerFinished>()
+            .register_type::<StopReason>()
+            .register_type::<TimerTickMode>()
+            .register_type::<TimerCatchUp>()
+            .register_type::<TimerEventOrder>()
+            .register_type::<TimerTriggerMode>()
+            .register_type::<TimerStartMode>()
+            .register_type::<TimerHandle>()
+            .register_type::<TimerCollector>()
+            .register_type::<TimerCollected>()
+            .register_type::<FrameTimer>()
+            .register_type::<DespawnAfter>()
+            .register_type::<DismissAfter>()
+            .register_type::<Dismissing>()
+            .register_type::<Respawned>()
+            .register_type::<TimeDilation>()
+            .register_type::<DutyCycleTimer>()
+            .register_type::<TimerPhaseChanged>()
+            .register_type::<TimersRefocused>()
+            .register_type::<WallClockCatchUp>()
+            .register_type::<TimersResumed>()
+            .register_type::<SplitStopwatch>()
+            .register_type::<SplitRecorded>()
+            .register_type::<IdleInputs>()
+            .register_type::<IdleTimer>()
+            .register_type::<UserIdle>()
+            .register_type::<UserActive>()
+            .register_type::<HoldTimer>()
+            .register_type::<HoldProgress>()
+            .register_type::<HoldCompleted>()
+            .register_type::<HoldAborted>()
+            .register_type::<Watchdog>()
+            .register_type::<WatchdogExpired>()
+            .register_type::<TimerGroup>()
+            .register_type::<TimerGroupSettings>()
+            .register_type::<TaskTimeoutBehavior>()
+            .register_type::<TimerTimeout>()
+            .register_type::<SpawnerFinished>()
+            .register_type::<TimerSubscribers>()
+            .init_resource::<TimerRegistry>()
+            .init_resource::<TimerGroups>()
+            .init_resource::<FinishedTimers>()
+            .init_resource::<PhaseClock>()
+            .init_resource::<PendingTimerUpdates>()
+            .init_resource::<PendingTimerStarts>()
+            .init_resource::<RunningTimers>()
+            .init_resource::<TimerCommandChannel>()
+            .insert_resource(self.tick_mode)
+            .insert_resource(self.catch_up)
+            .insert_resource(self.event_order)
+            .insert_resource(self.trigger_mode)
+            .insert_resource(self.start_mode)
+            .insert_resource(TimerStagger::new(self.phase_stagger));
+
+        app.observe(trigger_finish_events)
+            .observe(record_finished_timers)
+            .observe(spawn_on_interval);
+
+        #[cfg(feature = "animation")]
         app.add_systems(
+            PostUpdate,
+            animation::update_animation_timelines.after(bevy::animation::advance_animations),
+        );
+
+        #[cfg(feature = "bevy_audio")]
+        app.register_type::<AudioSpeedSource>();
+
+        #[cfg(feature = "rollback")]
+        app.register_type::<RollbackFinishedEvents>();
+
+        #[cfg(feature = "bevy_text")]
+        app.register_type::<TimerCountdownText>().add_systems(
+            self.schedule,
+            text::update_timer_countdown_text.after(ObservableTimerSet),
+        );
+
+        #[cfg(feature = "ui")]
+        app.register_type::<TimerProgressBar>().add_systems(
             self.schedule,
-            update_observable_timers.in_set(ObservableTimerSet),
+            ui::update_timer_progress_bars.after(ObservableTimerSet),
         );
+
+        #[cfg(feature = "debug_log")]
+        app.observe(debug_log::log_timer_started)
+            .observe(debug_log::log_timer_replaced)
+            .observe(debug_log::log_timer_finished);
+
+        if self.buffered_events {
+            app.register_type::<TimerStartedEvent>()
+                .register_type::<TimerIntervalEvent>()
+                .register_type::<TimerFinishedEvent>()
+                .add_event::<TimerStartedEvent>()
+                .add_event::<TimerIntervalEvent>()
+                .add_event::<TimerFinishedEvent>()
+                .observe(send_started_events)
+                .observe(send_interval_events)
+                .observe(send_finished_events);
+        }
+
+        if let Some(collector) = self.collection {
+            app.insert_resource(collector);
+        }
+
+        if let Some(per_update) = self.despawn_budget {
+            app.insert_resource(TimerDespawnBudget::new(per_update));
+        }
+
+        if let Some(per_update) = self.event_budget {
+            app.insert_resource(TimerEventBudget::new(per_update));
+        }
+
+        if let Some(clock) = &self.game_clock {
+            app.register_type::<GameClock>()
+                .register_type::<Season>()
+                .register_type::<HourElapsed>()
+                .register_type::<DayElapsed>()
+                .register_type::<SeasonChanged>()
+                .insert_resource(clock.clone())
+                .init_resource::<GameCalendar>();
+        }
+
+        if let Some(autosave) = self.autosave.clone() {
+            app.register_type::<SaveRequested>()
+                .insert_resource(autosave);
+        }
+
+        if self.scheduling == TimerScheduling::DueQueue {
+            app.init_resource::<TimerQueue>()
+                .observe(stop_queueing::<(TimerFraction, TimerGroup, TimeDilation, Parent)>);
+            #[cfg(feature = "bevy_audio")]
+            app.observe(stop_queueing::<AudioSpeedSource>);
+        }
+
+        #[cfg(feature = "rollback")]
+        if let Some(max_rollback) = self.rollback {
+            app.insert_resource(TimerRollback::new(max_rollback));
+        }
+
+        if self.focus_pause {
+            app.init_resource::<TimerFocusPause>()
+                .add_systems(self.schedule, track_window_focus.before(ObservableTimerSet));
+        }
+
+        if self.exit_teardown {
+            app.insert_resource(TimerTeardown::on_exit())
+                .add_systems(Last, begin_teardown_on_exit);
+        }
+
+        for register in &self.disabling_components {
+            register(app);
+        }
+
+        if let Some(wall_clock) = self.suspend_pause {
+            app.add_event::<AppLifecycle>()
+                .insert_resource(TimerSuspendPause::new(wall_clock))
+                .add_systems(
+                    self.schedule,
+                    track_app_lifecycle.before(ObservableTimerSet),
+                );
+        }
+
+        let mut set_config = ObservableTimerSet.into_configs();
+        if self.focus_pause {
+            set_config = set_config.run_if(window_focused);
+        }
+        if self.suspend_pause.is_some() {
+            set_config = set_config.run_if(app_running);
+        }
+        for condition in &self.conditions {
+            set_config.run_if_dyn(condition());
+        }
+        app.configure_sets(self.schedule, set_config);
+        app.edit_schedule(ObservableTimerUpdate, add_timer_systems)
+            .add_systems(
+                self.schedule,
+                update_timers
+                    .in_set(ObservableTimerSet)
+                    .run_if(ticks_automatically),
+            );
     }
 }
 
+/// Adds the systems that update timers to the [`ObservableTimerUpdate`] schedule.
+///
+/// This is shared by [`ObservableTimerPlugin`] and [`ObservableTimerWorldExt::tick_observable_timers()`], which creates
+/// the schedule for worlds that don't have the plugin. The opt-in kinds of timers add their own systems with their
+/// plugins, such as [`CronTimerPlugin`].
+pub(crate) fn add_timer_systems(schedule: &mut Schedule) {
+    schedule.configure_sets(
+        (
+            ObservableTimerSystems::Tick,
+            ObservableTimerSystems::EmitEvents,
+            ObservableTimerSystems::ApplyFinishBehavior,
+        )
+            .chain(),
+    );
+
+    schedule.add_systems(
+        trigger_timer_starts
+            .in_set(ObservableTimerSystems::Tick)
+            .before(clear_finished_timers),
+    );
+    schedule.add_systems((
+        (
+            clear_finished_timers,
+            apply_timer_requests,
+            cancel_timer_groups,
+            sync_timer_groups,
+            stagger_timers,
+            check_timer_cancel_conditions,
+            timer_delta.pipe(update_observable_timers),
+        )
+            .chain()
+            .in_set(ObservableTimerSystems::Tick),
+        (
+            trigger_timer_events.run_if(not(triggers_immediately)),
+            trigger_timer_events_immediately.run_if(triggers_immediately),
+        )
+            .in_set(ObservableTimerSystems::EmitEvents),
+        apply_pending_finish_behaviors.in_set(ObservableTimerSystems::ApplyFinishBehavior),
+    ));
+    schedule.add_systems(
+        (
+            collect_finished_timers,
+            timer_delta.pipe(update_timer_registry),
+            timer_delta.pipe(update_despawn_after),
+            timer_delta.pipe(update_dismiss_after),
+            timer_delta.pipe(update_respawn_after),
+            timer_delta.pipe(update_delayed_components),
+            (
+                timer_delta.pipe(update_duty_cycle_timers),
+                timer_delta
+                    .pipe(update_game_clock)
+                    .run_if(resource_exists::<GameClock>),
+                timer_delta
+                    .pipe(update_autosave)
+                    .run_if(resource_exists::<AutosaveTimer>),
+                timer_delta.pipe(update_split_stopwatches),
+                timer_delta.pipe(update_idle_timers),
+                timer_delta.pipe(update_hold_timers),
+                timer_delta.pipe(update_watchdogs),
+            )
+                .chain(),
+            update_frame_timers,
+            update_timer_fractions,
+            apply_despawn_budget.run_if(resource_exists::<TimerDespawnBudget>),
+        )
+            .chain()
+            .after(ObservableTimerSystems::ApplyFinishBehavior),
+    );
+}
+
+/// A [`Resource`] that controls how much [`ObservableTimer`]s are advanced each time [`ObservableTimerSet`] runs.
+///
+/// This is inserted by [`ObservableTimerPlugin`] (see [`ObservableTimerPlugin::with_tick_mode()`]), and may be changed
+/// at runtime.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum TimerTickMode {
+    /// Advance timers by [`Time::delta()`].
+    ///
+    /// This is the default mode.
+    #[default]
+    Delta,
+    /// Advance timers by exactly the given step, once per update, regardless of how much time has actually passed.
+    ///
+    /// All timer bookkeeping is done with integer nanosecond [`Duration`]s and interval counts, so in this mode the
+    /// timer state and the events that are triggered depend only on the number of updates that have run. This makes
+    /// it suitable for lockstep and rollback netcode, where frames are resimulated and must produce bit-identical
+    /// results.
+    FixedStep(Duration),
+    /// Do not advance timers automatically.
+    ///
+    /// Timers will only be updated when calling [`ObservableTimerWorldExt::tick_observable_timers()`].
+    Manual,
+}
+
+/// Limits how many [`TimerInterval`] events a single [`ObservableTimer`] may trigger in one update.
+///
+/// When a frame takes a long time, or an interval is very short, a timer may complete many intervals in a single
+/// update. By default each of them triggers a `TimerInterval` event, which may flood observers.
+///
+/// As a [`Resource`] this is the default policy for timers that don't set [`ObservableTimer::catch_up`]. It is inserted
+/// by [`ObservableTimerPlugin`] (see [`ObservableTimerPlugin::with_catch_up()`]).
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum TimerCatchUp {
+    /// Trigger an event for every completed interval.
+    ///
+    /// This is the default policy.
+    #[default]
+    FireAll,
+    /// Trigger events for at most the given number of intervals per update (minimum `1`). Events for any further
+    /// intervals completed in the same update are skipped, though the intervals still count as elapsed.
+    DropExtra(u32),
+    /// Trigger events for at most the given number of intervals per update (minimum `1`). Events for any further
+    /// intervals are queued and triggered on following updates.
+    ///
+    /// If the timer finishes while events are still queued, its [`TimerFinished`] event and finish behavior are
+    /// delayed until the queue has been drained.
+    QueueForNextFrame(u32),
+}
+
+/// The order in which the events of different [`ObservableTimer`]s are triggered within a single update.
+///
+/// The events of each individual timer are always triggered in order. This only affects the order between timers,
+/// which otherwise depends on how timer updates were spread over threads.
+///
+/// As a [`Resource`] this is inserted by [`ObservableTimerPlugin`] (see [`ObservableTimerPlugin::with_event_order()`]),
+/// and may be changed at runtime.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum TimerEventOrder {
+    /// Timers are updated in parallel, and the order between the events of different timers may vary from run to run.
+    ///
+    /// This is the default order.
+    #[default]
+    Unordered,
+    /// Events are triggered in a stable order, which only depends on the events themselves and the timers' [`Entity`]
+    /// ids.
+    ///
+    /// Given the same entities and the same updates, observers then run in exactly the same order on every run, as
+    /// needed by lockstep simulations and replays. Combine this with [`TimerTickMode::FixedStep`] to make the events
+    /// themselves independent of frame timing.
+    ByEntity,
+}
+
+/// How the events of [`ObservableTimer`]s are triggered during [`ObservableTimerSystems::EmitEvents`].
+///
+/// As a [`Resource`] this is inserted by [`ObservableTimerPlugin`] (see [`ObservableTimerPlugin::with_trigger_mode()`]),
+/// and may be changed at runtime. Timers advanced with [`ObservableTimerWorldExt::tick_observable_timers()`] always
+/// have their events triggered before it returns, whichever mode is used.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum TimerTriggerMode {
+    /// Events are queued as [`Commands`], and triggered at the next sync point of the [`ObservableTimerUpdate`]
+    /// schedule.
+    ///
+    /// Systems ordered after [`ObservableTimerSystems::EmitEvents`] always see the effects of the events' observers,
+    /// since bevy applies commands between ordered systems. This is the default mode.
+    #[default]
+    Deferred,
+    /// Events are triggered directly from an exclusive system, so that their observers run, and their commands are
+    /// applied, before any other system continues.
+    ///
+    /// This makes the observers' effects visible to every system that runs later in the [`ObservableTimerUpdate`]
+    /// schedule, including ones that aren't ordered relative to the timer systems. The update can't run in parallel
+    /// with other timer systems while events are being triggered.
+    Immediate,
+}
+
+/// When the [`TimerStarted`] (and [`TimerReplaced`]) events of newly inserted timers are triggered.
+///
+/// This applies to every kind of timer that triggers `TimerStarted`, such as [`ObservableTimer`], [`CronTimer`] and
+/// [`FrameTimer`]. As a [`Resource`] this is inserted by [`ObservableTimerPlugin`] (see
+/// [`ObservableTimerPlugin::with_start_mode()`]), and may be changed at runtime.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub enum TimerStartMode {
+    /// Events are triggered at the start of the next timer update after the insertion, or just before the timer's
+    /// [`TimerFinished`] event if it is removed before then.
+    ///
+    /// Observers added along with the timer (such as with [`EntityCommands::observe()`]) exist by then, so they receive
+    /// the events. This is the default mode.
+    ///
+    /// [`EntityCommands::observe()`]: bevy::ecs::system::EntityCommands::observe
+    #[default]
+    Deferred,
+    /// Events are triggered from the timer's insert hook, and their observers run as soon as the insertion has been
+    /// applied.
+    ///
+    /// The events are never delayed until the next timer update, even if timers aren't updated in the meantime.
+    /// However, only observers that already exist when the timer is inserted receive them. This includes global
+    /// observers (see [`TimerObserverExt::observe_timers()`]), but not entity observers added in the same command
+    /// batch as the timer, which are only spawned afterwards.
+    Immediate,
+}
+
 /// Describes the behavior that should be taken by an [`ObservableTimer`] upon finishing.
 ///
+/// This is reflected as an opaque value, since [`TimerFinishBehavior::ReplaceWith`] holds a whole timer.
+///
 /// # See also
 /// - [`ObservableTimer::with_finish_behavior()`]
 /// - [`ObservableTimer::finish_behavior`]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Reflect, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect_value(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", reflect_value(Serialize, Deserialize))]
 pub enum TimerFinishBehavior {
     /// Do nothing.
     ///
@@ -82,14 +1132,123 @@ pub enum TimerFinishBehavior {
     DespawnEntity,
     /// Despawn the entity that the `ObservableTimer` is attached to, along with its children.
     DespawnRecursive,
+    /// Replace the `ObservableTimer` with the given timer, which then starts as usual.
+    ///
+    /// This chains timers together (e.g. a cast time followed by a cooldown) without needing an observer to insert the
+    /// next timer. Longer chains are made by giving the replacement a `ReplaceWith` behavior of its own. Timers in the
+    /// [`TimerRegistry`] keep their [handle](TimerHandle) when they are replaced.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut world = World::new();
+    /// let cooldown = ObservableTimer::once_from_seconds(10.0).with_finish_behavior(TimerFinishBehavior::RemoveComponent);
+    /// world.spawn(
+    ///     ObservableTimer::once_from_seconds(1.5).with_finish_behavior(TimerFinishBehavior::replace_with(cooldown)),
+    /// );
+    /// ```
+    ReplaceWith(Box<ObservableTimer>),
+}
+
+impl TimerFinishBehavior {
+    /// Creates a [`TimerFinishBehavior::ReplaceWith`] that replaces the finished timer with `timer`.
+    pub fn replace_with(timer: ObservableTimer) -> Self {
+        Self::ReplaceWith(Box::new(timer))
+    }
+}
+
+/// A small identifier that is included in every event triggered by a timer.
+///
+/// Entities can have more than one kind of timer-driven behavior (for example an [`ObservableTimer`] that gets
+/// replaced as an ability goes from casting to cooldown, or an `ObservableTimer` alongside a [`CronTimer`]). Tagging
+/// the timers allows a single observer to tell which one triggered an event.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands) {
+/// const CAST: TimerTag = TimerTag(0);
+/// const COOLDOWN: TimerTag = TimerTag(1);
+///
+/// commands
+///     .spawn(ObservableTimer::once_from_seconds(1.5).with_tag(CAST))
+///     .observe(|trigger: Trigger<TimerFinished>| match trigger.event().tag() {
+///         Some(CAST) => info!("Cast complete"),
+///         Some(COOLDOWN) => info!("Ready"),
+///         _ => {}
+///     });
+/// # }
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TimerTag(pub u32);
+
+/// Describes how the interval duration of an [`ObservableTimer`] grows after each completed interval.
+///
+/// # See also
+/// - [`ObservableTimer::with_backoff()`]
+/// - [`ObservableTimer::backoff`]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TimerBackoff {
+    /// The factor each interval's duration is multiplied by to get the next interval's duration.
+    pub factor: f32,
+    /// The maximum interval duration.
+    pub max: Duration,
+}
+
+/// The direction in which a [ping-pong](ObservableTimer::ping_pong) timer is moving through its current interval.
+///
+/// Timers that aren't ping-pong timers always move [`TimerDirection::Forward`].
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum TimerDirection {
+    /// The interval moves from start to end. This is the direction of the first interval.
+    #[default]
+    Forward,
+    /// The interval moves from end to start.
+    Backward,
+}
+
+impl TimerDirection {
+    /// Returns the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+        }
+    }
+}
+
+/// Computes the duration of an interval from its zero-based index. See [`ObservableTimer::with_interval_fn()`].
+#[derive(Clone)]
+struct IntervalFn(Arc<dyn Fn(u32) -> Duration + Send + Sync>);
+
+impl std::fmt::Debug for IntervalFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("IntervalFn(..)")
+    }
+}
+
+impl PartialEq for IntervalFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 /// A timer component that triggers observable lifecycle events on its [`Entity`].
 ///
 /// When an `ObservableTimer` is first added to an `Entity` (either by adding a new one, or replacing the current one)
-/// a [`TimerStarted`] event will be triggered. Then, each time an interval completes, a [`TimerInterval`] event will
-/// be triggered. Finally, when the timer is finished or its component is removed, a [`TimerFinished`] event will be
-/// triggered.
+/// a [`TimerStarted`] event will be triggered. Replacing a timer additionally triggers a [`TimerReplaced`] event just
+/// before, and if the old timer was still running, a [`TimerFinished`] event with [`StopReason::Replaced`] for it
+/// ahead of both. Then, each time an interval completes, a [`TimerInterval`] event will be triggered. Finally, when the
+/// timer is finished or its component is removed, a [`TimerFinished`] event will be triggered.
 ///
 /// By default the timer will despawn its `Entity` when it finishes. This behavior can be changed to removing only the
 /// `ObservableTimer` component, or recursively despawning children. See [`Self::with_finish_behavior`] for setting
@@ -98,14 +1257,105 @@ pub enum TimerFinishBehavior {
 ///
 /// To cancel a currently running timer simply remove the component. This will cause a [`TimerFinished`] event to be
 /// triggered with [`TimerFinished::cancelled()`] set to `true`.
-#[derive(Debug, Clone)]
+///
+/// # Scenes
+///
+/// `ObservableTimer` is reflectable and registered by [`ObservableTimerPlugin`], so it can be saved to and spawned from
+/// a `DynamicScene`. All of the timer's state is preserved, so a partially elapsed timer resumes where it left off.
+/// Spawning a scene inserts the component, so [`TimerStarted`] is triggered as usual. Writing a scene onto an entity
+/// that already has an `ObservableTimer` instead updates the existing timer in place, which triggers neither
+/// [`TimerStarted`] nor [`TimerFinished`].
+///
+/// With the `serialize` feature enabled the timer implements `Serialize` and `Deserialize`, including its elapsed
+/// state and finish behavior.
+///
+/// # Precision
+///
+/// All of the timer's state is kept as [`Duration`]s and integer counts, so repeating timers don't drift however long
+/// they run, and a timer that completes its intervals exactly on time keeps doing so after hours of play. Only the
+/// values reported as floats (such as [`Self::fraction()`] and [`TimerAdjusted::delta()`]) are approximate, and the
+/// `*_seconds` constructors are limited to the precision of the `f32` they are given. Prefer the constructors taking a
+/// [`Duration`] for long-lived timers with fractional intervals. Timers that should stay on a fixed schedule regardless
+/// of pausing, group speeds and dilation can be [phase-locked](Self::with_phase_lock()).
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Debug)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 pub struct ObservableTimer {
     timer: Timer,
     remaining_intervals: Option<u32>,
     elapsed_intervals: u32,
     intervals_this_tick: u32,
+    overshoot: Duration,
+    queued_intervals: u32,
+    /// The timer's [catch-up policy](TimerCatchUp), or `None` to use the global [`TimerCatchUp`] resource. Defaults to
+    /// `None`.
+    pub catch_up: Option<TimerCatchUp>,
+    /// Whether time left over after completing an interval counts towards the next interval. Defaults to `true`.
+    ///
+    /// When `true`, the next interval effectively starts "in the past", so a repeating timer stays in step with the
+    /// total elapsed time regardless of frame timing. When `false`, each interval starts fresh on the update after the
+    /// previous one completed, and any [overshoot](Self::overshoot) is discarded.
+    pub carry_overshoot: bool,
     /// The timer's [finish behavior](TimerFinishBehavior).
     pub finish_behavior: TimerFinishBehavior,
+    /// Whether the timer triggers a [`TimerSecondElapsed`] event each time its total remaining time crosses a whole
+    /// second. Defaults to `false`.
+    pub second_events: bool,
+    /// How often the timer triggers a [`TimerProgress`] event while running, or `None` to disable them. Defaults to
+    /// `None`.
+    pub progress_interval: Option<Duration>,
+    progress_elapsed: Duration,
+    finished_for: Duration,
+    pending_adjustment: Option<i128>,
+    pending_restart: bool,
+    /// The maximum random offset applied to each interval's duration. Defaults to zero.
+    ///
+    /// See [`Self::with_jitter()`].
+    pub jitter: Duration,
+    /// How the interval duration grows after each completed interval, or `None` to keep it constant. Defaults to
+    /// `None`.
+    ///
+    /// See [`Self::with_backoff()`].
+    pub backoff: Option<TimerBackoff>,
+    /// The timer's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    /// Whether the timer alternates [direction](TimerDirection) with each interval. Defaults to `false`.
+    ///
+    /// See [`Self::with_ping_pong()`].
+    pub ping_pong: bool,
+    /// Whether the timer is anchored to a fixed schedule rather than accumulating its own updates. Defaults to
+    /// `false`.
+    ///
+    /// See [`Self::with_phase_lock()`].
+    pub phase_locked: bool,
+    /// The schedule time at which the timer's phase-locked time started, once it has been anchored.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    phase_anchor: Option<Duration>,
+    /// The total time that has been fed to the timer since it was anchored.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    phase_elapsed: Duration,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    interval_fn: Option<IntervalFn>,
+    base_interval_duration: Duration,
+    initial_interval_duration: Duration,
+    /// The rollback tick the timer was first inserted on, if it was inserted while a `TimerRollback` existed.
+    ///
+    /// Together with the number of restarts and the tag this identifies the timer instance, so that rollbacks only
+    /// deduplicate the events of the same instance.
+    #[reflect(default)]
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub(crate) start_tick: Option<u32>,
+    /// The number of times the timer has been [restarted](Self::restart()).
+    #[reflect(default)]
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub(crate) restarts: u32,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    just_added: bool,
 }
 
 impl ObservableTimer {
@@ -123,7 +1373,30 @@ impl ObservableTimer {
             remaining_intervals,
             elapsed_intervals: 0,
             intervals_this_tick: 0,
+            overshoot: Duration::ZERO,
+            queued_intervals: 0,
+            catch_up: None,
+            carry_overshoot: true,
             finish_behavior: TimerFinishBehavior::default(),
+            second_events: false,
+            progress_interval: None,
+            progress_elapsed: Duration::ZERO,
+            finished_for: Duration::ZERO,
+            pending_adjustment: None,
+            pending_restart: false,
+            jitter: Duration::ZERO,
+            backoff: None,
+            tag: None,
+            ping_pong: false,
+            phase_locked: false,
+            phase_anchor: None,
+            phase_elapsed: Duration::ZERO,
+            interval_fn: None,
+            base_interval_duration: interval_duration,
+            initial_interval_duration: interval_duration,
+            start_tick: None,
+            restarts: 0,
+            just_added: false,
         }
     }
 
@@ -134,57 +1407,376 @@ impl ObservableTimer {
         Self::new(interval_count, Duration::from_secs_f32(interval_seconds))
     }
 
-    /// Create a new timer that will run for at most `total_duration` in intervals of length `interval_duration`.
-    pub fn from_total(total_duration: Duration, interval_duration: Duration) -> Self {
-        let interval_count =
-            (total_duration.as_secs_f32() / interval_duration.as_secs_f32()).floor() as _;
-        Self::new(interval_count, interval_duration)
+    /// Create a new timer that will run for `interval_count` intervals of length `interval_millis` milliseconds.
+    ///
+    /// An `interval_count` of `0` will result in a timer that runs indefinitely.
+    pub fn from_millis(interval_count: u32, interval_millis: u64) -> Self {
+        Self::new(interval_count, Duration::from_millis(interval_millis))
+    }
+
+    /// Create a new timer that will run for `interval_count` intervals at a rate of `hz` intervals per second.
+    ///
+    /// An `interval_count` of `0` will result in a timer that runs indefinitely. A `hz` that isn't positive and finite
+    /// results in intervals of [`Duration::MAX`], which never complete in practice.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// // Triggers a `TimerInterval` 20 times a second, forever
+    /// ObservableTimer::from_hz(0, 20.0);
+    /// ```
+    pub fn from_hz(interval_count: u32, hz: f32) -> Self {
+        let interval_duration = match hz {
+            hz if hz.is_finite() && hz > 0.0 => Duration::try_from_secs_f32(hz.recip()).ok(),
+            _ => None,
+        };
+        Self::new(interval_count, interval_duration.unwrap_or(Duration::MAX))
+    }
+
+    /// Create a new timer that will run for at most `total_duration` in intervals of length `interval_duration`.
+    pub fn from_total(total_duration: Duration, interval_duration: Duration) -> Self {
+        let interval_count = total_duration
+            .as_nanos()
+            .checked_div(interval_duration.as_nanos())
+            .unwrap_or(0)
+            .min(u32::MAX as u128) as u32;
+        Self::new(interval_count, interval_duration)
+    }
+
+    /// Create a new timer that will run for at most `total_seconds` in intervals of length `interval_seconds`.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// // Emits a `TimerInterval` every 5 seconds for 60 seconds
+    /// ObservableTimer::from_total_seconds(60.0, 5.0);
+    /// ```
+    pub fn from_total_seconds(total_seconds: f32, interval_seconds: f32) -> Self {
+        Self::from_total(
+            Duration::from_secs_f32(total_seconds),
+            Duration::from_secs_f32(interval_seconds),
+        )
+    }
+
+    /// Creates a new timer with a single interval.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// ObservableTimer::once(Duration::from_secs_f32(5.0));
+    /// ```
+    pub fn once(duration: Duration) -> Self {
+        Self::new(1, duration)
+    }
+
+    /// Creates a new timer with a single interval.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// ObservableTimer::once_from_seconds(5.0);
+    /// ```
+    pub fn once_from_seconds(seconds: f32) -> Self {
+        Self::from_seconds(1, seconds)
+    }
+
+    /// Creates a new timer that runs indefinitely with intervals of the given length.
+    pub fn indefinite(interval_duration: Duration) -> Self {
+        Self::new(0, interval_duration)
+    }
+
+    /// Creates a new timer that runs indefinitely with intervals of the given length.
+    ///
+    /// This is the same as [`Self::indefinite()`].
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// ObservableTimer::repeating(Duration::from_secs(1));
+    /// ```
+    pub fn repeating(interval_duration: Duration) -> Self {
+        Self::indefinite(interval_duration)
+    }
+
+    /// Creates a new timer that runs indefinitely with intervals of the given length.
+    pub fn indefinite_from_seconds(interval_seconds: f32) -> Self {
+        Self::from_seconds(0, interval_seconds)
+    }
+
+    /// Sets whether the timer starts out paused.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// // Waits for something else to call `unpause()` before it starts counting down
+    /// ObservableTimer::once_from_seconds(5.0).with_paused(true);
+    /// ```
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        if paused {
+            self.timer.pause();
+        } else {
+            self.timer.unpause();
+        }
+        self
+    }
+
+    /// Sets the total elapsed time, for creating timers that are already partially complete.
+    ///
+    /// Any intervals that this completes count as elapsed, but don't trigger [`TimerInterval`] events. The elapsed
+    /// time is limited so that the final interval always completes (and triggers its events) on the next update.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Finishes 3 seconds after it is inserted, having completed 2 of its 5 intervals
+    /// ObservableTimer::from_seconds(5, 1.0).with_elapsed(Duration::from_secs(2));
+    /// ```
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.set_total_elapsed(elapsed);
+        self
+    }
+
+    /// Sets the total elapsed time, for creating timers that are already partially complete.
+    fn set_total_elapsed(&mut self, elapsed: Duration) {
+        let interval_duration = self.timer.duration();
+        let mut intervals = elapsed
+            .as_nanos()
+            .checked_div(interval_duration.as_nanos())
+            .unwrap_or(0)
+            .min(u32::MAX as u128) as u32;
+        let mut interval_elapsed = elapsed.saturating_sub(interval_duration * intervals);
+
+        if let Some(remaining_intervals) = self.remaining_intervals {
+            if intervals >= remaining_intervals {
+                intervals = remaining_intervals.saturating_sub(1);
+                interval_elapsed = interval_duration;
+            }
+            self.remaining_intervals = Some(remaining_intervals - intervals);
+        }
+        self.elapsed_intervals += intervals;
+        self.timer.set_elapsed(interval_elapsed);
+    }
+
+    /// Sets a different duration for the first interval, after which the timer continues with its normal interval
+    /// duration. This is useful for spawners that need a warm-up time that differs from their steady-state cadence.
+    ///
+    /// This replaces any duration picked by [`Self::with_jitter()`], so it should be called afterwards.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Triggers a `TimerInterval` after 5 seconds, and then every second
+    /// ObservableTimer::indefinite_from_seconds(1.0).with_initial_delay(Duration::from_secs(5));
+    /// ```
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.timer.set_duration(initial_delay);
+        self
+    }
+
+    /// Sets the timer's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the timer's [finish behavior](TimerFinishBehavior).
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+
+    /// Sets the timer's [catch-up policy](TimerCatchUp), overriding the global default.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Never trigger more than one `TimerInterval` per update, even if the interval is shorter than a frame
+    /// ObservableTimer::indefinite(Duration::from_millis(1)).with_catch_up(TimerCatchUp::QueueForNextFrame(1));
+    /// ```
+    pub fn with_catch_up(mut self, catch_up: TimerCatchUp) -> Self {
+        self.catch_up = Some(catch_up);
+        self
+    }
+
+    /// Sets whether time left over after completing an interval counts towards the next interval.
+    ///
+    /// See [`Self::carry_overshoot`].
+    pub fn with_carry_overshoot(mut self, carry_overshoot: bool) -> Self {
+        self.carry_overshoot = carry_overshoot;
+        self
+    }
+
+    /// Sets whether the timer triggers [`TimerSecondElapsed`] events.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// // Triggers `TimerSecondElapsed` with `remaining_secs` of 2 and then 1, followed by `TimerFinished`
+    /// ObservableTimer::once_from_seconds(3.0).with_second_events(true);
+    /// ```
+    pub fn with_second_events(mut self, second_events: bool) -> Self {
+        self.second_events = second_events;
+        self
+    }
+
+    /// Sets the timer to trigger a [`TimerProgress`] event every `progress_interval` while it is running.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Reports progress roughly ten times a second over the course of 5 seconds
+    /// ObservableTimer::once_from_seconds(5.0).with_progress_events(Duration::from_millis(100));
+    /// ```
+    pub fn with_progress_events(mut self, progress_interval: Duration) -> Self {
+        self.progress_interval = Some(progress_interval);
+        self
     }
 
-    /// Create a new timer that will run for at most `total_seconds` in intervals of length `interval_seconds`.
+    /// Randomizes the duration of each interval, picking it uniformly from `base ± jitter` where `base` is the
+    /// duration the timer was created with. This is useful for desynchronizing many otherwise identical timers.
+    ///
+    /// The first interval's duration is picked immediately, and each following interval's when the previous one
+    /// completes. Intervals that complete during the same tick share a duration. Because future intervals haven't been
+    /// picked yet, [`Self::duration()`] and [`Self::remaining()`] assume they will have the base duration.
     ///
-    /// ```ignore
-    /// // Emits a `TimerInterval` every 5 seconds for 60 seconds
-    /// ObservableTimer::from_total_seconds(60.0, 5.0);
     /// ```
-    pub fn from_total_seconds(total_seconds: f32, interval_seconds: f32) -> Self {
-        Self::from_total(
-            Duration::from_secs_f32(total_seconds),
-            Duration::from_secs_f32(interval_seconds),
-        )
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Triggers a `TimerInterval` every 1.5 to 2.5 seconds
+    /// ObservableTimer::indefinite_from_seconds(2.0).with_jitter(Duration::from_millis(500));
+    /// ```
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self.pick_interval_duration();
+        self
     }
 
-    /// Creates a new timer with a single interval.
+    /// Grows the duration of each interval geometrically, multiplying it by `factor` after each completed interval
+    /// until it reaches `max`. This is useful for retry loops and escalating waves.
+    ///
+    /// [`Self::duration()`] and [`Self::remaining()`] assume that future intervals won't grow any further. The
+    /// duration of the final interval is reported by
+    /// [`TimerFinished::interval_duration()`].
     ///
-    /// ```ignore
-    /// ObservableTimer::once(Duration::from_secs_f32(5.0));
     /// ```
-    pub fn once(duration: Duration) -> Self {
-        Self::new(1, duration)
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Retries after 1, 2, 4, 8, 10, 10, ... seconds
+    /// ObservableTimer::indefinite_from_seconds(1.0).with_backoff(2.0, Duration::from_secs(10));
+    /// ```
+    pub fn with_backoff(mut self, factor: f32, max: Duration) -> Self {
+        self.backoff = Some(TimerBackoff { factor, max });
+        self
     }
 
-    /// Creates a new timer with a single interval.
+    /// Computes the duration of each interval with `interval_fn`, which is passed the zero-based index of the interval.
+    /// This covers ramping difficulty and irregular schedules without changing the timer from an observer every cycle.
+    ///
+    /// The first interval's duration is computed immediately, and each following interval's when the previous one
+    /// completes. Intervals that complete during the same tick share a duration, and zero durations are ignored. This
+    /// replaces [jitter](Self::with_jitter()) and [backoff](Self::with_backoff()). Because future intervals haven't been
+    /// computed yet, [`Self::duration()`] and [`Self::remaining()`] assume they will have the duration the timer was
+    /// created with. The function isn't reflected or serialized, so it is lost when the timer is saved to a scene.
     ///
-    /// ```ignore
-    /// ObservableTimer::once_from_seconds(5.0);
     /// ```
-    pub fn once_from_seconds(seconds: f32) -> Self {
-        Self::from_seconds(1, seconds)
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Spawns a wave after 10 seconds, then 9.5, 9, ... down to a minimum of 3 seconds between waves
+    /// ObservableTimer::indefinite_from_seconds(10.0).with_interval_fn(|index| {
+    ///     Duration::from_secs_f32((10.0 - 0.5 * index as f32).max(3.0))
+    /// });
+    /// ```
+    pub fn with_interval_fn(
+        mut self,
+        interval_fn: impl Fn(u32) -> Duration + Send + Sync + 'static,
+    ) -> Self {
+        self.interval_fn = Some(IntervalFn(Arc::new(interval_fn)));
+        self.pick_interval_duration();
+        self
     }
 
-    /// Creates a new timer that runs indefinitely with intervals of the given length.
-    pub fn indefinite(interval_duration: Duration) -> Self {
-        Self::new(1, interval_duration)
+    /// Samples a designer-authored [`CubicCurve`] for the duration of each interval, in seconds.
+    ///
+    /// The curve's whole domain is spread evenly across the first `span` intervals, after which its final value is
+    /// held. For example with a `span` of `10`, the first interval samples the start of the curve and the tenth samples
+    /// its end. Negative samples are treated as zero, and so are ignored. This is built on
+    /// [`Self::with_interval_fn()`], and has the same caveats.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// // Eases the time between waves from 10 seconds down to 3 over the first 20 waves
+    /// let pacing = CubicBezier::new([[10.0, 9.5, 3.5, 3.0]]).to_curve();
+    /// ObservableTimer::indefinite_from_seconds(10.0).with_duration_curve(pacing, 20);
+    /// ```
+    pub fn with_duration_curve(self, curve: CubicCurve<f32>, span: u32) -> Self {
+        let segments = curve.segments().len() as f32;
+        let last_index = span.saturating_sub(1).max(1) as f32;
+        self.with_interval_fn(move |index| {
+            let t = (index as f32 / last_index).min(1.0) * segments;
+            Duration::from_secs_f32(curve.position(t).max(0.0))
+        })
     }
 
-    /// Creates a new timer that runs indefinitely with intervals of the given length.
-    pub fn indefinite_from_seconds(interval_seconds: f32) -> Self {
-        Self::from_seconds(0, interval_seconds)
+    /// Makes the timer alternate [direction](TimerDirection) with each interval, so that
+    /// [`Self::directed_interval_fraction()`] goes from `0.0` to `1.0` and back again. This is useful for oscillating
+    /// platforms, pulsing UI and patrols.
+    ///
+    /// The first interval moves forward. The direction of the final interval is reported by
+    /// [`TimerFinished::direction()`].
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Component)]
+    /// # struct Platform {
+    /// #     start: Vec3,
+    /// #     end: Vec3,
+    /// # }
+    /// # fn setup(mut commands: Commands) {
+    /// # let platform = Platform { start: Vec3::ZERO, end: Vec3::X };
+    /// // Moves back and forth between two points every 2 seconds
+    /// fn move_platforms(mut platforms: Query<(&ObservableTimer, &Platform, &mut Transform)>) {
+    ///     for (timer, platform, mut transform) in platforms.iter_mut() {
+    ///         let t = timer.directed_interval_fraction();
+    ///         transform.translation = platform.start.lerp(platform.end, t);
+    ///     }
+    /// }
+    ///
+    /// commands.spawn((ObservableTimer::indefinite_from_seconds(2.0).with_ping_pong(true), platform));
+    /// # }
+    /// ```
+    pub fn with_ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
     }
 
-    /// Sets the timer's [finish behavior](TimerFinishBehavior).
-    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
-        self.finish_behavior = finish_behavior;
+    /// Anchors the timer to a fixed schedule, so that its intervals complete at exactly `t0 + n * interval`, where `t0`
+    /// is the time of its first update.
+    ///
+    /// Each update, a phase-locked timer is advanced to the total time that has passed on the timer schedule since
+    /// `t0`, rather than by the time passed in that update alone. It therefore ignores [`TimerGroup`] speeds and
+    /// [`TimeDilation`], always [carries overshoot](Self::carry_overshoot), and catches up on the intervals it missed
+    /// while [paused](Self::pause()) once it is resumed (subject to its [catch-up policy](TimerCatchUp)). After an hour
+    /// of play, a phase-locked 10 second heartbeat has completed exactly 360 intervals, each on the update where its
+    /// moment in the schedule passed. [Restarting](Self::restart()) or [seeking](Self::seek()) the timer anchors it
+    /// again.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// let mut world = World::new();
+    /// let heartbeat = world
+    ///     .spawn(ObservableTimer::indefinite_from_seconds(10.0).with_phase_lock(true))
+    ///     .id();
+    ///
+    /// world.tick_observable_timers(Duration::from_secs(15));
+    /// world.get_mut::<ObservableTimer>(heartbeat).unwrap().pause();
+    /// world.tick_observable_timers(Duration::from_secs(30));
+    /// world.get_mut::<ObservableTimer>(heartbeat).unwrap().unpause();
+    /// world.tick_observable_timers(Duration::from_secs(15));
+    ///
+    /// // The time spent paused still counts
+    /// assert_eq!(world.get::<ObservableTimer>(heartbeat).unwrap().elapsed_intervals(), 6);
+    /// ```
+    pub fn with_phase_lock(mut self, phase_locked: bool) -> Self {
+        self.phase_locked = phase_locked;
         self
     }
 }
@@ -205,6 +1797,103 @@ impl ObservableTimer {
         self.timer.unpause();
     }
 
+    /// Restarts the timer from the beginning, without removing and re-inserting the component.
+    ///
+    /// All intervals become remaining again, including for a timer that has already finished (which is only possible
+    /// with [`TimerFinishBehavior::None`]), and the interval duration returns to the one the timer was created with. An
+    /// [initial delay](Self::with_initial_delay()) isn't repeated. A [`TimerRestarted`] event is triggered on the next
+    /// timer update.
+    ///
+    /// ```
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut idle_timer = ObservableTimer::once_from_seconds(30.0);
+    /// // Reset the idle timer whenever there's input
+    /// idle_timer.restart();
+    /// ```
+    pub fn restart(&mut self) {
+        if let Some(remaining_intervals) = self.remaining_intervals {
+            self.remaining_intervals = Some(remaining_intervals + self.elapsed_intervals);
+        }
+        self.elapsed_intervals = 0;
+        self.intervals_this_tick = 0;
+        self.overshoot = Duration::ZERO;
+        self.queued_intervals = 0;
+        self.progress_elapsed = Duration::ZERO;
+        self.finished_for = Duration::ZERO;
+        self.pending_adjustment = None;
+        self.pending_restart = true;
+        self.restarts = self.restarts.wrapping_add(1);
+        self.phase_anchor = None;
+        self.base_interval_duration = self.initial_interval_duration;
+        self.timer.set_duration(self.base_interval_duration);
+        self.timer.reset();
+        self.pick_interval_duration();
+    }
+
+    /// Jumps to the given total elapsed time, either forwards or backwards, for replay scrubbers and editor previews.
+    ///
+    /// No events are triggered for the intervals that are skipped over or rewound, and every interval is assumed to
+    /// have the timer's base duration. Like [`Self::with_elapsed()`], the elapsed time is limited so that the final
+    /// interval completes on the next update. Seeking a finished timer (which is only possible with
+    /// [`TimerFinishBehavior::None`]) to before its end makes it run again.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut fuse_timer = ObservableTimer::once_from_seconds(3.0);
+    /// // Show the state of the fuse 1.5 seconds in
+    /// fuse_timer.seek(Duration::from_secs_f32(1.5));
+    /// ```
+    pub fn seek(&mut self, elapsed: Duration) {
+        if let Some(remaining_intervals) = self.remaining_intervals {
+            self.remaining_intervals = Some(remaining_intervals + self.elapsed_intervals);
+        }
+        self.elapsed_intervals = 0;
+        self.intervals_this_tick = 0;
+        self.overshoot = Duration::ZERO;
+        self.queued_intervals = 0;
+        self.progress_elapsed = Duration::ZERO;
+        self.finished_for = Duration::ZERO;
+        self.phase_anchor = None;
+        self.timer.set_duration(self.base_interval_duration);
+        self.timer.reset();
+        self.set_total_elapsed(elapsed);
+    }
+
+    /// Adds `delta` to the timer's remaining time by lengthening its current interval.
+    ///
+    /// A [`TimerAdjusted`] event is triggered on the next timer update. Following intervals keep their usual duration.
+    /// This does nothing if the timer is already done.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut bomb_timer = ObservableTimer::once_from_seconds(30.0);
+    /// // The bomb just got 10 more seconds
+    /// bomb_timer.extend(Duration::from_secs(10));
+    /// ```
+    pub fn extend(&mut self, delta: Duration) {
+        if self.is_done() {
+            return;
+        }
+        self.timer.set_duration(self.timer.duration() + delta);
+        *self.pending_adjustment.get_or_insert(0) += delta.as_nanos() as i128;
+    }
+
+    /// Removes `delta` from the timer's remaining time by shortening its current interval.
+    ///
+    /// A [`TimerAdjusted`] event is triggered on the next timer update. The current interval can at most be shortened
+    /// to end immediately, in which case it completes on the next timer update. Following intervals keep their usual
+    /// duration. This does nothing if the timer is already done.
+    pub fn shorten(&mut self, delta: Duration) {
+        if self.is_done() {
+            return;
+        }
+        let delta = delta.min(self.timer.remaining());
+        self.timer.set_duration(self.timer.duration() - delta);
+        *self.pending_adjustment.get_or_insert(0) -= delta.as_nanos() as i128;
+    }
+
     /// Whether the timer is finished running.
     pub fn is_done(&self) -> bool {
         self.remaining_intervals == Some(0)
@@ -220,6 +1909,20 @@ impl ObservableTimer {
         self.intervals_this_tick
     }
 
+    /// The number of completed intervals whose [`TimerInterval`] events are queued for following updates.
+    ///
+    /// This is only ever non-zero with [`TimerCatchUp::QueueForNextFrame`].
+    pub fn queued_intervals(&self) -> u32 {
+        self.queued_intervals
+    }
+
+    /// The amount of time by which the last interval completed in the last tick was overshot.
+    ///
+    /// This is zero if no interval completed in the last tick.
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+
     /// The length of a single interval.
     pub fn interval_duration(&self) -> Duration {
         self.timer.duration()
@@ -261,6 +1964,35 @@ impl ObservableTimer {
         }
     }
 
+    /// The direction of the current interval, or of the final interval once the timer is done.
+    ///
+    /// This is always [`TimerDirection::Forward`] unless the timer is a [ping-pong](Self::ping_pong) timer.
+    pub fn direction(&self) -> TimerDirection {
+        let interval = if self.is_done() {
+            self.elapsed_intervals.saturating_sub(1)
+        } else {
+            self.elapsed_intervals
+        };
+        if self.ping_pong && interval % 2 == 1 {
+            TimerDirection::Backward
+        } else {
+            TimerDirection::Forward
+        }
+    }
+
+    /// The fraction of the current interval's elapsed time in the timer's [direction](Self::direction()).
+    ///
+    /// This is the same as [`Self::interval_fraction()`] while moving forward, and goes from `1.0` to `0.0` while
+    /// moving backward. Once the timer is done, this is where its final interval ended.
+    pub fn directed_interval_fraction(&self) -> f32 {
+        match (self.is_done(), self.direction()) {
+            (false, TimerDirection::Forward) => self.timer.fraction(),
+            (false, TimerDirection::Backward) => self.timer.fraction_remaining(),
+            (true, TimerDirection::Forward) => 1.0,
+            (true, TimerDirection::Backward) => 0.0,
+        }
+    }
+
     /// The number of full intervals elapsed.
     pub fn elapsed_intervals(&self) -> u32 {
         self.elapsed_intervals
@@ -290,13 +2022,18 @@ impl ObservableTimer {
     ///
     /// This will be `None` for timers that repeat indefinitely.
     pub fn duration(&self) -> Option<Duration> {
-        self.remaining_intervals
-            .map(|n| self.timer.duration() * (n + self.elapsed_intervals))
+        self.remaining_intervals.map(|n| match n {
+            0 => self.base_interval_duration * self.elapsed_intervals,
+            n => {
+                self.base_interval_duration * (n - 1 + self.elapsed_intervals)
+                    + self.timer.duration()
+            }
+        })
     }
 
     /// The total elapsed duration on the timer.
     pub fn elapsed(&self) -> Duration {
-        let full_elapsed = self.timer.duration() * self.elapsed_intervals;
+        let full_elapsed = self.base_interval_duration * self.elapsed_intervals;
         if self.remaining_intervals == Some(0) {
             full_elapsed
         } else {
@@ -321,7 +2058,7 @@ impl ObservableTimer {
         match self.remaining_intervals {
             None => None,
             Some(0) => Some(Duration::ZERO),
-            Some(n) => Some(self.timer.duration() * (n - 1) + self.timer.remaining()),
+            Some(n) => Some(self.base_interval_duration * (n - 1) + self.timer.remaining()),
         }
     }
 
@@ -339,9 +2076,12 @@ impl ObservableTimer {
 
     /// Advance the timer by `delta`.
     fn tick(&mut self, delta: Duration) {
+        let interval_remaining = self.timer.remaining();
+
         match self.remaining_intervals {
             Some(0) => {
                 self.intervals_this_tick = 0;
+                self.finished_for += delta;
             }
             Some(remaining_intervals) => {
                 let intervals_this_tick = self
@@ -361,6 +2101,108 @@ impl ObservableTimer {
                 self.elapsed_intervals += intervals_this_tick;
             }
         }
+
+        self.overshoot = match self.intervals_this_tick {
+            0 => Duration::ZERO,
+            n => delta.saturating_sub(interval_remaining + self.timer.duration() * (n - 1)),
+        };
+        if self.intervals_this_tick > 0 && !self.carry_overshoot && !self.phase_locked {
+            self.timer.set_elapsed(Duration::ZERO);
+        }
+        if self.intervals_this_tick > 0 && !self.is_done() {
+            // Following intervals return to the base duration after an initial delay
+            self.timer.set_duration(self.base_interval_duration);
+            if let Some(backoff) = self.backoff {
+                let factor = f64::from(backoff.factor).powi(self.intervals_this_tick as i32);
+                self.base_interval_duration =
+                    Duration::try_from_secs_f64(self.base_interval_duration.as_secs_f64() * factor)
+                        .unwrap_or(backoff.max)
+                        .min(backoff.max);
+                self.timer.set_duration(self.base_interval_duration);
+            }
+            self.pick_interval_duration();
+        }
+    }
+
+    /// Picks a new duration for the current interval according to the interval function or [`Self::jitter`].
+    fn pick_interval_duration(&mut self) {
+        if let Some(interval_fn) = &self.interval_fn {
+            let duration = (interval_fn.0)(self.elapsed_intervals);
+            if !duration.is_zero() {
+                self.timer.set_duration(duration);
+            }
+            return;
+        }
+        if self.jitter.is_zero() {
+            return;
+        }
+
+        let base = self.base_interval_duration.as_secs_f64();
+        let offset = (fastrand::f64() * 2.0 - 1.0) * self.jitter.as_secs_f64();
+        let duration = Duration::from_secs_f64((base + offset).max(0.0));
+        if !duration.is_zero() {
+            self.timer.set_duration(duration);
+        }
+    }
+}
+
+impl ObservableTimer {
+    /// The amount a phase-locked timer should advance at the schedule time `now`, given that `delta` has passed since
+    /// the last update.
+    ///
+    /// A timer that hasn't been anchored yet is anchored as though it had started at the last update, like the regular
+    /// update of an unlocked timer.
+    fn phase_locked_delta(&mut self, now: Duration, delta: Duration) -> Duration {
+        let anchor = *self.phase_anchor.get_or_insert_with(|| {
+            self.phase_elapsed = Duration::ZERO;
+            now.saturating_sub(delta)
+        });
+        if self.paused() {
+            // Paused timers don't advance, so the time is made up once they resume
+            return Duration::ZERO;
+        }
+        let target = now.saturating_sub(anchor);
+        let delta = target.saturating_sub(self.phase_elapsed);
+        self.phase_elapsed = target;
+        delta
+    }
+
+    /// Takes whether the timer was restarted since the last update.
+    fn take_restart(&mut self) -> bool {
+        std::mem::take(&mut self.pending_restart)
+    }
+
+    /// Takes the net adjustment made since the last update, returning the [`TimerAdjusted`] event to trigger, if any.
+    fn take_adjustment(&mut self) -> Option<TimerAdjusted> {
+        let nanos = self.pending_adjustment.take()?;
+        Some(TimerAdjusted {
+            delta: (nanos as f64 / 1e9) as f32,
+            remaining: self.remaining(),
+            tag: self.tag,
+        })
+    }
+
+    /// Advance the progress event accumulator by `delta`, returning `true` if a progress event is due.
+    fn tick_progress(&mut self, delta: Duration) -> bool {
+        let Some(progress_interval) = self.progress_interval else {
+            return false;
+        };
+        if self.paused() || self.is_done() {
+            return false;
+        }
+
+        self.progress_elapsed += delta;
+        if self.progress_elapsed < progress_interval {
+            return false;
+        }
+
+        let leftover_nanos = self
+            .progress_elapsed
+            .as_nanos()
+            .checked_rem(progress_interval.as_nanos())
+            .unwrap_or(0);
+        self.progress_elapsed = Duration::from_nanos(leftover_nanos as u64);
+        true
     }
 }
 
@@ -368,21 +2210,87 @@ impl Component for ObservableTimer {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_remove(on_timer_removed);
+        hooks
+            .on_add(on_timer_added)
+            .on_insert(on_timer_inserted)
+            .on_remove(on_timer_removed);
     }
 }
 
+/// A marker component that is automatically added to entities whose [`ObservableTimer`] is paused, and removed again
+/// when it is unpaused or removed.
+///
+/// Paused timers are excluded from the regular timer update by this marker, so large numbers of paused timers cost
+/// (almost) nothing. The marker is added on the update after a timer is paused, and unpaused timers rejoin the regular
+/// update on the update after they are unpaused. It may also be used to query for paused timers.
+#[derive(Component, Reflect, Debug, Default, Clone, Copy)]
+#[reflect(Component, Debug, Default)]
+pub struct TimerIsPaused;
+
+/// A component that mirrors the [fraction](ObservableTimer::fraction) of an [`ObservableTimer`] on the same entity.
+///
+/// This is not added automatically. When present it is kept in sync during [`ObservableTimerSet`], and is only marked
+/// as changed when the fraction actually changes, so UI can react to it using ordinary change detection.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((ObservableTimer::once_from_seconds(5.0), TimerFraction::default()));
+/// # }
+/// ```
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq, Deref)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TimerFraction(pub f32);
+
 /// A timer [`Event`] that is triggered when an [`ObservableTimer`] is added or inserted.
-#[derive(Event, Debug)]
+///
+/// This is triggered at the start of the next timer update after the insertion, before any other events for the timer,
+/// so that observers added along with the timer (such as with [`EntityCommands::observe()`]) receive it. If the timer
+/// is removed before then, it is instead triggered just before the timer's [`TimerFinished`] event. This includes
+/// timers spawned from scenes, which may already be partially elapsed. With [`TimerStartMode::Immediate`] it is
+/// instead triggered as soon as the timer is inserted.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Resource, Default)]
+/// struct Started(bool);
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, ObservableTimerPlugin::default()))
+///     .init_resource::<Started>();
+///
+/// app.world_mut()
+///     .commands()
+///     .spawn(ObservableTimer::once_from_seconds(1.0))
+///     .observe(|_: Trigger<TimerStarted>, mut started: ResMut<Started>| started.0 = true);
+/// app.update();
+/// assert!(app.world().resource::<Started>().0);
+/// ```
+///
+/// [`EntityCommands::observe()`]: bevy::ecs::system::EntityCommands::observe
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
 pub struct TimerStarted {
-    // This prevents the ZST from being instantiated outside this crate.
-    _inner: (),
+    tag: Option<TimerTag>,
+}
+
+impl TimerStarted {
+    /// The [tag](TimerTag) of the timer that started.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
 }
 
 /// A timer [`Event`] that is triggered when an [`ObservableTimer`] interval has passed.
-#[derive(Event, Debug)]
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
 pub struct TimerInterval {
     count: u32,
+    tag: Option<TimerTag>,
 }
 
 impl TimerInterval {
@@ -390,66 +2298,349 @@ impl TimerInterval {
     pub fn count(&self) -> u32 {
         self.count
     }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered each time the total remaining time of an [`ObservableTimer`] crosses a whole
+/// second.
+///
+/// This is only triggered for timers with [`ObservableTimer::second_events`] enabled, and never for timers that run
+/// indefinitely. The final boundary (zero seconds remaining) is not reported, as it is covered by [`TimerFinished`].
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerSecondElapsed {
+    remaining_secs: u32,
+    tag: Option<TimerTag>,
+}
+
+impl TimerSecondElapsed {
+    /// The number of whole seconds remaining on the timer.
+    pub fn remaining_secs(&self) -> u32 {
+        self.remaining_secs
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered periodically while an [`ObservableTimer`] is running.
+///
+/// This is only triggered for timers with a [`ObservableTimer::progress_interval`]. At most one progress event is
+/// triggered per update, and none are triggered on the update that the timer finishes.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerProgress {
+    fraction: f32,
+    remaining: Option<Duration>,
+    tag: Option<TimerTag>,
+}
+
+impl TimerProgress {
+    /// The fraction of the timer's total elapsed time (goes from `0.0` to `1.0`).
+    ///
+    /// See [`ObservableTimer::fraction()`].
+    pub fn fraction(&self) -> f32 {
+        self.fraction
+    }
+
+    /// The total remaining duration on the timer, or `None` if it runs indefinitely.
+    ///
+    /// See [`ObservableTimer::remaining()`].
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered when an [`ObservableTimer`] is overwritten by inserting a new one.
+///
+/// This is triggered immediately before the new timer's [`TimerStarted`] event. If the old timer was still running, a
+/// [`TimerFinished`] event with [`StopReason::Replaced`] is triggered for it as soon as it is overwritten. This
+/// includes timers replaced by [`TimerFinishBehavior::ReplaceWith`], whose old timer has already finished.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerReplaced {
+    tag: Option<TimerTag>,
+}
+
+impl TimerReplaced {
+    /// The [tag](TimerTag) of the new timer.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered after an [`ObservableTimer`] is restarted with [`ObservableTimer::restart()`].
+///
+/// This is triggered on the timer update following the restart, before any other events for that update.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerRestarted {
+    tag: Option<TimerTag>,
+}
+
+impl TimerRestarted {
+    /// The [tag](TimerTag) of the timer that restarted.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+/// A timer [`Event`] that is triggered after an [`ObservableTimer`]'s remaining time is adjusted.
+///
+/// This is triggered on the timer update following a call to [`ObservableTimer::extend()`] or
+/// [`ObservableTimer::shorten()`], before any other events for that update. Multiple adjustments made between updates
+/// are combined into a single event.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimerAdjusted {
+    delta: f32,
+    remaining: Option<Duration>,
+    tag: Option<TimerTag>,
+}
+
+impl TimerAdjusted {
+    /// The net change in remaining time in seconds, which is positive when the timer was extended and negative when it
+    /// was shortened.
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// The total remaining duration on the timer after the adjustment, or `None` if it runs indefinitely.
+    ///
+    /// See [`ObservableTimer::remaining()`].
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
 }
 
 /// A timer [`Event`] that is triggered when an [`ObservableTimer`] finishes, or is cancelled.
-#[derive(Event, Debug)]
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
 pub struct TimerFinished {
-    cancelled: bool,
+    reason: StopReason,
+    condition_met: bool,
+    overshoot: Duration,
+    interval_duration: Duration,
+    direction: TimerDirection,
+    tag: Option<TimerTag>,
 }
 
 impl TimerFinished {
-    /// `true` when the timer was manually cancelled before finishing.
+    /// `true` when the timer stopped before finishing, by being removed or replaced, or by its entity being despawned.
     pub fn cancelled(&self) -> bool {
-        self.cancelled
+        self.reason != StopReason::Finished
+    }
+
+    /// Why the timer stopped.
+    pub fn reason(&self) -> StopReason {
+        self.reason
+    }
+
+    /// `true` when the timer was cancelled because its [`TimerCancelCondition`] was met.
+    pub fn cancelled_by_condition(&self) -> bool {
+        self.condition_met
+    }
+
+    /// The amount of time by which the timer's final interval was overshot on the update that it finished.
+    ///
+    /// This is always zero for cancelled timers.
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+
+    /// The duration of the timer's final interval, or of its current interval if it was cancelled.
+    ///
+    /// This is mostly useful for timers whose interval duration changes, such as those with
+    /// [backoff](ObservableTimer::with_backoff()). It is zero for [`CronTimer`], [`FireAt`], [`Deadline`] and
+    /// [`FrameTimer`], which don't measure intervals in time.
+    pub fn interval_duration(&self) -> Duration {
+        self.interval_duration
+    }
+
+    /// The [direction](TimerDirection) of the timer's final interval, or of its current interval if it was cancelled.
+    ///
+    /// This is only ever [`TimerDirection::Backward`] for [ping-pong](ObservableTimer::ping_pong) timers.
+    pub fn direction(&self) -> TimerDirection {
+        self.direction
+    }
+
+    /// The [tag](TimerTag) of the timer that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
     }
 }
 
-fn on_timer_removed(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
-    let timer = world.get::<ObservableTimer>(entity).unwrap();
-    if !timer.is_done() {
-        world
-            .commands()
-            .trigger_targets(TimerFinished { cancelled: true }, entity);
-    }
-}
-
-fn update_observable_timers(
-    time: Res<Time>,
-    mut timers: Query<(Entity, &mut ObservableTimer)>,
-    mut commands: Commands,
-) {
-    let delta = time.delta();
-    for (entity, mut timer) in timers.iter_mut() {
-        if timer.is_added() {
-            commands.trigger_targets(TimerStarted { _inner: () }, entity)
-        }
-
-        // The current interval number
-        let interval_num = timer.elapsed_intervals + 1;
-
-        // Tick the timer forward
-        timer.tick(delta);
-
-        // Trigger an interval event for every interval we finished this `tick()`
-        for count in interval_num..(interval_num + timer.intervals_this_tick) {
-            commands.trigger_targets(TimerInterval { count }, entity)
-        }
-
-        if timer.just_finished() {
-            commands.trigger_targets(TimerFinished { cancelled: false }, entity);
-            match timer.finish_behavior {
-                TimerFinishBehavior::None => {}
-                TimerFinishBehavior::RemoveComponent => {
-                    commands.entity(entity).remove::<ObservableTimer>();
-                }
-                TimerFinishBehavior::DespawnEntity => {
-                    commands.entity(entity).despawn();
-                }
-                TimerFinishBehavior::DespawnRecursive => {
-                    commands.entity(entity).despawn_recursive();
-                }
-            }
+/// Why an [`ObservableTimer`] stopped, as reported by [`TimerFinished::reason()`].
+///
+/// This lets observers tell apart, for example, a cast that completed from one that was interrupted because the caster
+/// died.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Resource, Default)]
+/// struct Reasons(Vec<StopReason>);
+///
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+/// ))
+/// .init_resource::<Reasons>()
+/// .observe(|trigger: Trigger<TimerFinished>, mut reasons: ResMut<Reasons>| {
+///     reasons.0.push(trigger.event().reason());
+/// });
+///
+/// let world = app.world_mut();
+/// world.spawn(ObservableTimer::once_from_seconds(1.0));
+/// let removed = world.spawn(ObservableTimer::once_from_seconds(1.0)).id();
+/// let despawned = world.spawn(ObservableTimer::once_from_seconds(1.0)).id();
+/// let replaced = world.spawn(ObservableTimer::once_from_seconds(1.0)).id();
+/// world.tick_observable_timers(Duration::ZERO);
+///
+/// world.entity_mut(removed).remove::<ObservableTimer>();
+/// world.despawn(despawned);
+/// world.entity_mut(replaced).insert(ObservableTimer::once_from_seconds(5.0));
+/// world.tick_observable_timers(Duration::from_secs(1));
+///
+/// assert_eq!(
+///     world.resource::<Reasons>().0,
+///     [
+///         StopReason::Cancelled,
+///         StopReason::EntityDespawned,
+///         StopReason::Replaced,
+///         StopReason::Finished,
+///     ]
+/// );
+/// ```
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum StopReason {
+    /// The timer ran to completion.
+    #[default]
+    Finished,
+    /// The timer was removed from its entity before finishing.
+    Cancelled,
+    /// The timer's entity was despawned before the timer finished.
+    EntityDespawned,
+    /// The timer was overwritten by inserting a new [`ObservableTimer`] before it finished.
+    ///
+    /// This is triggered as soon as the timer is overwritten, ahead of the new timer's [`TimerReplaced`] event. Only a
+    /// timer that was still running stops this way, so nothing is reported for a timer that had already finished,
+    /// such as one replaced by [`TimerFinishBehavior::ReplaceWith`]. The old timer's
+    /// [interval duration](TimerFinished::interval_duration) and [direction](TimerFinished::direction) are those it had
+    /// as of its last update, or as inserted if it hadn't been updated yet.
+    Replaced,
+}
+
+/// Triggers a cancelled [`TimerFinished`] event for a timer that has just been removed from `entity`.
+///
+/// The reason is only decided once the command runs, since the remove hook can't tell whether the entity is being
+/// despawned. Nothing is triggered while a [`TimerTeardown`] is in progress.
+pub(crate) fn trigger_cancelled(world: &mut DeferredWorld, event: TimerFinished, entity: Entity) {
+    world.commands().add(move |world: &mut World| {
+        if is_tearing_down(world) {
+            return;
         }
+        let reason = if world.get_entity(entity).is_some() {
+            StopReason::Cancelled
+        } else {
+            StopReason::EntityDespawned
+        };
+        world.trigger_targets(TimerFinished { reason, ..event }, entity);
+    });
+}
+
+fn on_timer_added(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    // The insert hook runs next, and uses this to tell a new timer apart from one replacing an existing timer
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    timer.bypass_change_detection().just_added = true;
+}
+
+fn on_timer_inserted(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    #[cfg(feature = "rollback")]
+    if rollback::restore_timer_state(&mut world, entity) {
+        pause_if_disabled(&mut world, entity);
+        queue::schedule_timer(&mut world, entity);
+        return;
+    }
+    #[cfg(feature = "rollback")]
+    rollback::stamp_start_tick(&mut world, entity);
+
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    let replaced = !std::mem::take(&mut timer.bypass_change_detection().just_added);
+    let current = RunningTimer::of(&timer);
+    let tag = timer.tag;
+    let replaced_unstarted = queue_timer_start(&mut world, entity, component, tag, replaced);
+    let previous = world
+        .get_resource_mut::<RunningTimers>()
+        .and_then(|mut running| running.0.insert(entity, current));
+    // Only a timer that had started and was still running stops by being replaced
+    if let (true, false, Some(previous)) = (replaced, replaced_unstarted, previous) {
+        world.commands().trigger_targets(
+            TimerFinished {
+                reason: StopReason::Replaced,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: previous.interval_duration,
+                direction: previous.direction,
+                tag: previous.tag,
+            },
+            entity,
+        );
+    }
+    pause_if_disabled(&mut world, entity);
+    queue::schedule_timer(&mut world, entity);
+    join_synchronized_group(&mut world, entity);
+    queue_staggered_timer(&mut world, entity);
+}
+
+fn on_timer_removed(mut world: DeferredWorld, entity: Entity, component: ComponentId) {
+    flush_timer_start(&mut world, entity, component);
+    if let Some(mut running) = world.get_resource_mut::<RunningTimers>() {
+        running.0.remove(&entity);
+    }
+    queue::unschedule_timer(&mut world, entity);
+    forget_disabled_timer(&mut world, entity);
+    if world.get::<TimerIsPaused>(entity).is_some() {
+        world.commands().entity(entity).remove::<TimerIsPaused>();
+    }
+
+    let timer = world.get::<ObservableTimer>(entity).unwrap();
+    if !timer.is_done() && world.get::<TimerCancelledSilently>(entity).is_none() {
+        let interval_duration = timer.interval_duration();
+        let (direction, tag) = (timer.direction(), timer.tag);
+        let condition_met = world.get::<TimerConditionMet>(entity).is_some();
+        trigger_cancelled(
+            &mut world,
+            TimerFinished {
+                reason: StopReason::Cancelled,
+                condition_met,
+                overshoot: Duration::ZERO,
+                interval_duration,
+                direction,
+                tag,
+            },
+            entity,
+        );
     }
 }
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::Instant, window::AppLifecycle};
+
+use crate::{CronTimer, FireAt};
+
+/// A [`Resource`] that freezes timers while a mobile app is suspended in the background.
+///
+/// When bevy reports that the app is suspending (with [`AppLifecycle`] events, sent on Android and iOS),
+/// [`ObservableTimerSet`](crate::ObservableTimerSet) stops running, in the same way as with
+/// [`ObservableTimerPlugin::run_if()`](crate::ObservableTimerPlugin::run_if). Once the app resumes, timers continue
+/// from where they were, and a global [`TimersResumed`] event is triggered with how long the app was suspended for.
+///
+/// [`FireAt`] and [`CronTimer`] follow the system's wall clock, which keeps going while the app is suspended. What
+/// happens to them on resuming is set by [`Self::wall_clock`].
+///
+/// This is inserted by [`ObservableTimerPlugin::with_suspend_pause()`].
+///
+/// ```
+/// # use bevy::{prelude::*, window::AppLifecycle};
+/// # use bevy_mod_observable_timer::*;
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     ObservableTimerPlugin::default().with_suspend_pause(WallClockCatchUp::Fire),
+/// ));
+///
+/// app.world_mut().send_event(AppLifecycle::Suspended);
+/// app.update();
+/// assert!(app.world().resource::<TimerSuspendPause>().is_suspended());
+///
+/// app.world_mut().send_event(AppLifecycle::Running);
+/// app.update();
+/// assert!(!app.world().resource::<TimerSuspendPause>().is_suspended());
+/// ```
+///
+/// [`ObservableTimerPlugin::with_suspend_pause()`]: crate::ObservableTimerPlugin::with_suspend_pause
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TimerSuspendPause {
+    /// What happens to wall-clock timers that came due while the app was suspended.
+    pub wall_clock: WallClockCatchUp,
+    /// The instant at which the app was suspended, while it is suspended.
+    suspended_since: Option<Instant>,
+}
+
+impl TimerSuspendPause {
+    /// Creates a `TimerSuspendPause` with the given policy for wall-clock timers.
+    pub fn new(wall_clock: WallClockCatchUp) -> Self {
+        Self {
+            wall_clock,
+            suspended_since: None,
+        }
+    }
+
+    /// Returns `true` while timers are frozen because the app is suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_since.is_some()
+    }
+}
+
+/// What happens to wall-clock timers ([`FireAt`] and [`CronTimer`]) when the app resumes after being suspended.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum WallClockCatchUp {
+    /// Timers that came due while the app was suspended finish (or trigger, for [`CronTimer`]s) as soon as it
+    /// resumes, once each.
+    ///
+    /// This is the default.
+    #[default]
+    Fire,
+    /// Wall-clock timers are frozen along with every other timer. [`FireAt`] targets are pushed back by the time spent
+    /// suspended, and [`CronTimer`]s skip any occurrences that passed while suspended.
+    Freeze,
+}
+
+/// An [`Event`] that is triggered globally when the app resumes, after a [`TimerSuspendPause`] froze timers.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimersResumed {
+    suspended_for: Duration,
+}
+
+impl TimersResumed {
+    /// The time that the app was suspended, and timers were frozen, for.
+    pub fn suspended_for(&self) -> Duration {
+        self.suspended_for
+    }
+}
+
+pub(crate) fn track_app_lifecycle(
+    mut lifecycle: EventReader<AppLifecycle>,
+    mut suspend: ResMut<TimerSuspendPause>,
+    mut fire_at: Query<&mut FireAt>,
+    mut cron_timers: Query<&mut CronTimer>,
+    mut commands: Commands,
+) {
+    let Some(&latest) = lifecycle.read().last() else {
+        return;
+    };
+
+    match (suspend.suspended_since, latest) {
+        (None, AppLifecycle::WillSuspend | AppLifecycle::Suspended) => {
+            suspend.suspended_since = Some(Instant::now());
+        }
+        (Some(since), AppLifecycle::WillResume | AppLifecycle::Running) => {
+            suspend.suspended_since = None;
+            let suspended_for = since.elapsed();
+            if suspend.wall_clock == WallClockCatchUp::Freeze {
+                for mut timer in fire_at.iter_mut() {
+                    timer.postpone(suspended_for);
+                }
+                for mut timer in cron_timers.iter_mut() {
+                    timer.skip_missed();
+                }
+            }
+            commands.trigger(TimersResumed { suspended_for });
+        }
+        _ => {}
+    }
+}
+
+/// A run condition that is `true` unless timers are frozen by a [`TimerSuspendPause`].
+pub(crate) fn app_running(suspend: Option<Res<TimerSuspendPause>>) -> bool {
+    !suspend.is_some_and(|suspend| suspend.is_suspended())
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    add_timer_systems,
+    start::PendingTimerStarts,
+    update::{run_timer_update, PendingTimerUpdates, PhaseClock, RunningTimers},
+    ObservableTimerUpdate,
+};
+
+/// An extension trait for manually advancing [`ObservableTimer`]s.
+///
+/// This runs the [`ObservableTimerUpdate`] schedule that [`ObservableTimerPlugin`] runs within
+/// [`ObservableTimerSet`], including triggering timer events and applying finish behaviors, but with an explicit
+/// `delta`. It is intended for apps that drive timers from their own loop (see [`TimerTickMode::Manual`]), and for
+/// tests that need precise control over time. It differs from the plugin's update in that:
+/// - The plugin's [run conditions](crate::ObservableTimerPlugin::run_if) are ignored, and the timers are advanced even if
+///   [`TimerTickMode`] isn't [`Manual`](crate::TimerTickMode::Manual).
+/// - [`CronTimer`], [`FireAt`] and [`Deadline`] are checked against their own clocks rather than `delta`, and are
+///   skipped if their clock's resource doesn't exist.
+///
+/// Worlds without an [`ObservableTimerPlugin`] have the schedule created on their first update. It only contains the
+/// core timer systems, so opt-in timer kinds such as [`CronTimer`] need an `App` with their plugins.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let timer_id = world.spawn(ObservableTimer::from_seconds(2, 1.0)).id();
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.get::<ObservableTimer>(timer_id).unwrap().elapsed_intervals(), 1);
+///
+/// // The timer finishes and despawns its entity
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert!(world.get_entity(timer_id).is_none());
+/// ```
+///
+/// [`ObservableTimer`]: crate::ObservableTimer
+/// [`ObservableTimerPlugin`]: crate::ObservableTimerPlugin
+/// [`ObservableTimerSet`]: crate::ObservableTimerSet
+/// [`TimerTickMode`]: crate::TimerTickMode
+/// [`TimerTickMode::Manual`]: crate::TimerTickMode::Manual
+/// [`CronTimer`]: crate::CronTimer
+/// [`FireAt`]: crate::FireAt
+/// [`Deadline`]: crate::Deadline
+pub trait ObservableTimerWorldExt {
+    /// Advances every [`ObservableTimer`](crate::ObservableTimer) by `delta`.
+    fn tick_observable_timers(&mut self, delta: Duration);
+}
+
+impl ObservableTimerWorldExt for World {
+    fn tick_observable_timers(&mut self, delta: Duration) {
+        let mut schedules = self.get_resource_or_insert_with(Schedules::default);
+        if !schedules.contains(ObservableTimerUpdate) {
+            let mut schedule = Schedule::new(ObservableTimerUpdate);
+            add_timer_systems(&mut schedule);
+            schedules.insert(schedule);
+        }
+        self.init_resource::<PhaseClock>();
+        self.init_resource::<PendingTimerUpdates>();
+        self.init_resource::<PendingTimerStarts>();
+        self.init_resource::<RunningTimers>();
+        run_timer_update(self, delta);
+    }
+}
+
+impl ObservableTimerWorldExt for App {
+    fn tick_observable_timers(&mut self, delta: Duration) {
+        self.world_mut().tick_observable_timers(delta);
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::{ecs::system::IntoObserverSystem, prelude::*};
+
+use crate::{
+    TimerAdjusted, TimerFinished, TimerInterval, TimerProgress, TimerReplaced, TimerRestarted,
+    TimerSecondElapsed, TimerStarted,
+};
+
+/// A marker trait for the [`Event`]s that are triggered on the entities of [`ObservableTimer`]s.
+///
+/// Each of these is triggered with the timer's entity as its target, so a global observer can read it from
+/// [`Trigger::entity()`]. See [`TimerObserverExt`].
+///
+/// [`ObservableTimer`]: crate::ObservableTimer
+pub trait TimerEvent: Event + Clone {}
+
+impl TimerEvent for TimerStarted {}
+impl TimerEvent for TimerReplaced {}
+impl TimerEvent for TimerRestarted {}
+impl TimerEvent for TimerAdjusted {}
+impl TimerEvent for TimerInterval {}
+impl TimerEvent for TimerSecondElapsed {}
+impl TimerEvent for TimerProgress {}
+impl TimerEvent for TimerFinished {}
+
+/// An extension trait for adding global observers for [`TimerEvent`]s.
+///
+/// A global observer runs for the event on every timer, which allows centralized handling (e.g. a single spawner
+/// observer) without adding an observer to each timer's entity. This is the same as [`App::observe()`], but only
+/// accepts timer events. Timers in the [`TimerRegistry`](crate::TimerRegistry) aren't attached to entities, so their
+/// events are instead triggered as [`RegisteredTimerEvent`](crate::RegisteredTimerEvent)s.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins(ObservableTimerPlugin::default())
+///     .observe_timers(|trigger: Trigger<TimerFinished>| {
+///         info!("The timer on {:?} finished", trigger.entity());
+///     });
+/// ```
+pub trait TimerObserverExt {
+    /// Adds a global observer for the timer event `E`.
+    fn observe_timers<E: TimerEvent, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self;
+}
+
+impl TimerObserverExt for App {
+    fn observe_timers<E: TimerEvent, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.observe(observer)
+    }
+}
+
+impl TimerObserverExt for World {
+    fn observe_timers<E: TimerEvent, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.observe(observer);
+        self
+    }
+}
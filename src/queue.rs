@@ -0,0 +1,237 @@
+use std::{cmp::Reverse, collections::BinaryHeap, time::Duration};
+
+use bevy::{
+    ecs::{component::Tick, world::DeferredWorld},
+    prelude::*,
+};
+
+use crate::{
+    update::{update_timer, RunningTimers, TimerUpdates},
+    ObservableTimer, TimeDilation, TimerCatchUp, TimerFraction, TimerGroup,
+};
+
+/// Describes how [`ObservableTimer`]s are scheduled for updates. See [`ObservableTimerPlugin::with_scheduling()`].
+///
+/// [`ObservableTimerPlugin::with_scheduling()`]: crate::ObservableTimerPlugin::with_scheduling
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimerScheduling {
+    /// Every timer is updated on every update.
+    ///
+    /// This is the default scheduling.
+    #[default]
+    EveryUpdate,
+    /// Timers are kept in a queue ordered by when their next interval completes, and are only updated on the update
+    /// that it does. This avoids touching every timer on every update, which matters when running tens of thousands of
+    /// timers.
+    ///
+    /// Because queued timers aren't updated in between intervals, the state reported by their [`ObservableTimer`]
+    /// (such as [`ObservableTimer::elapsed()`]) is only brought up to date when an interval completes. A queued timer
+    /// that is changed, such as by pausing, unpausing, restarting or adjusting it, is rescheduled on the next update
+    /// as if the state it reported was up to date, so the time that passed since it was last brought up to date is
+    /// dropped. Time spent paused is never counted.
+    ///
+    /// Timers that need to be updated continuously are never queued. This includes timers with
+    /// [`ObservableTimer::second_events`] or a [`ObservableTimer::progress_interval`], and timers on entities with a
+    /// [`TimerFraction`], a [`TimerGroup`], a [`TimeDilation`] or a [`Parent`]. Queued timers that gain any of these
+    /// are brought up to date on the next update, and rejoin the regular update from then on.
+    DueQueue,
+}
+
+/// The due queue used by [`TimerScheduling::DueQueue`].
+#[derive(Resource, Default)]
+pub(crate) struct TimerQueue {
+    /// The total time that has been advanced since the queue was created.
+    clock: Duration,
+    /// When each queued timer is next due, earliest first.
+    due: BinaryHeap<Reverse<(Duration, Entity)>>,
+    /// The change tick of the last update, to find the timers that have been changed since.
+    updated_at: Tick,
+    /// Timers that can no longer be queued since the last update.
+    unqueue: Vec<Entity>,
+}
+
+/// Marks a timer that is scheduled by the [`TimerQueue`].
+#[derive(Component)]
+pub(crate) struct QueuedTimer {
+    /// The queue clock time at which the timer is next due.
+    due: Duration,
+    /// The queue clock time at which the timer was last updated.
+    synced_at: Duration,
+}
+
+impl TimerQueue {
+    /// Advance the queue clock by `delta` and update every timer that is due, as of the change tick `this_run`.
+    pub(crate) fn update(
+        &mut self,
+        delta: Duration,
+        this_run: Tick,
+        mut timers: Query<(Entity, &mut ObservableTimer, &mut QueuedTimer)>,
+        default_catch_up: TimerCatchUp,
+        running: Option<&RunningTimers>,
+        updates: &mut TimerUpdates,
+    ) {
+        // Timers changed since the last update carry on from the state they reported, and their old entries are
+        // skipped once they come up
+        // This doesn't use the system's own change ticks, as manually ticked timers are updated by a new system each time
+        for (entity, timer, mut queued) in timers.iter_mut() {
+            if !timer
+                .last_changed()
+                .is_newer_than(self.updated_at, this_run)
+            {
+                continue;
+            }
+            queued.synced_at = self.clock;
+            if !can_queue(&timer) {
+                self.unqueue.push(entity);
+            } else if let Some(next_due) = next_due_in(&timer) {
+                queued.due = self.clock + next_due;
+                self.due.push(Reverse((queued.due, entity)));
+            } else {
+                queued.due = Duration::MAX;
+                updates.unqueued.push(entity);
+            }
+        }
+
+        self.clock += delta;
+
+        for entity in std::mem::take(&mut self.unqueue) {
+            let Ok((_, mut timer, mut queued)) = timers.get_mut(entity) else {
+                continue;
+            };
+            if queued.due == Duration::MAX {
+                continue;
+            }
+
+            let delta = self.clock - queued.synced_at;
+            update_timer(
+                entity,
+                &mut timer,
+                delta,
+                default_catch_up,
+                running,
+                updates,
+            );
+            queued.due = Duration::MAX;
+            updates.unqueued.push(entity);
+        }
+
+        let mut due_now = Vec::new();
+        while let Some(&Reverse((due, entity))) = self.due.peek() {
+            if due > self.clock {
+                break;
+            }
+            self.due.pop();
+            due_now.push((due, entity));
+        }
+
+        for (due, entity) in due_now {
+            // Skip entries for timers that have since been removed or rescheduled
+            let Ok((_, mut timer, mut queued)) = timers.get_mut(entity) else {
+                continue;
+            };
+            if queued.due != due {
+                continue;
+            }
+
+            let delta = self.clock - queued.synced_at;
+            update_timer(
+                entity,
+                &mut timer,
+                delta,
+                default_catch_up,
+                running,
+                updates,
+            );
+            queued.synced_at = self.clock;
+
+            if let Some(next_due) = next_due_in(&timer) {
+                queued.due = self.clock + next_due;
+                self.due.push(Reverse((queued.due, entity)));
+            } else {
+                // Finished timers that stay around rejoin the regular update, so that they can be restarted
+                updates.unqueued.push(entity);
+            }
+        }
+        self.updated_at = this_run;
+    }
+}
+
+/// Schedules a newly inserted timer in the [`TimerQueue`], if there is one and the timer can be queued.
+pub(crate) fn schedule_timer(world: &mut DeferredWorld, entity: Entity) {
+    let Some(clock) = world.get_resource::<TimerQueue>().map(|queue| queue.clock) else {
+        return;
+    };
+
+    let entity_ref = world.entity(entity);
+    let timer = entity_ref.get::<ObservableTimer>().unwrap();
+    let next_due = if !can_queue(timer)
+        || entity_ref.contains::<TimerFraction>()
+        || entity_ref.contains::<TimerGroup>()
+        || entity_ref.contains::<TimeDilation>()
+        || entity_ref.contains::<Parent>()
+        || audio_driven(&entity_ref)
+    {
+        None
+    } else {
+        next_due_in(timer)
+    };
+
+    let Some(next_due) = next_due else {
+        unschedule_timer(world, entity);
+        return;
+    };
+
+    let due = clock + next_due;
+    world
+        .resource_mut::<TimerQueue>()
+        .due
+        .push(Reverse((due, entity)));
+    world.commands().entity(entity).try_insert(QueuedTimer {
+        due,
+        synced_at: clock,
+    });
+}
+
+/// Takes a timer out of the [`TimerQueue`] when any of the components in `B` are added to its entity.
+pub(crate) fn stop_queueing<B: Bundle>(trigger: Trigger<OnAdd, B>, mut queue: ResMut<TimerQueue>) {
+    // The timer's queue marker may not have been inserted yet, so this is checked on the next update
+    queue.unqueue.push(trigger.entity());
+}
+
+/// Removes the queue marker from a timer's entity.
+pub(crate) fn unschedule_timer(world: &mut DeferredWorld, entity: Entity) {
+    if world.get::<QueuedTimer>(entity).is_some() {
+        world.commands().entity(entity).remove::<QueuedTimer>();
+    }
+}
+
+/// Returns `false` if `timer` has settings that need it to be updated continuously.
+fn can_queue(timer: &ObservableTimer) -> bool {
+    !timer.second_events && !timer.phase_locked && timer.progress_interval.is_none()
+}
+
+/// How long until `timer` next needs to be updated, or `None` if it never does.
+fn next_due_in(timer: &ObservableTimer) -> Option<Duration> {
+    if timer.queued_intervals > 0 || timer.pending_restart || timer.pending_adjustment.is_some() {
+        // Pending events are reported on the next update
+        Some(Duration::ZERO)
+    } else if timer.is_done() {
+        None
+    } else if timer.paused() {
+        // Check back in on paused timers periodically
+        Some(timer.interval_duration())
+    } else {
+        Some(timer.interval_remaining())
+    }
+}
+
+/// Returns `true` if the entity's timers follow an [`AudioSpeedSource`](crate::AudioSpeedSource).
+#[cfg(feature = "bevy_audio")]
+fn audio_driven(entity: &EntityRef) -> bool {
+    entity.contains::<crate::AudioSpeedSource>()
+}
+
+#[cfg(not(feature = "bevy_audio"))]
+fn audio_driven(_: &EntityRef) -> bool {
+    false
+}
@@ -0,0 +1,254 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::{
+    update::tick_timer, ObservableTimer, StopReason, TimerCatchUp, TimerFinishBehavior,
+    TimerFinished, TimerInterval, TimerRestarted, TimerSecondElapsed, TimerStarted,
+};
+
+/// A handle to a timer in the [`TimerRegistry`].
+///
+/// Handles are cheap to copy and store in gameplay data. They are never reused, so a handle to a timer that has
+/// finished or been cancelled is simply inert: every [`TimerRegistry`] method taking it does nothing and returns
+/// `false` or `None`.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct TimerHandle(u64);
+
+/// A [`Resource`] holding [`ObservableTimer`]s that aren't attached to any entity.
+///
+/// This is useful for app-level timers (autosaves, periodic polling, etc.) where spawning an entity just to host the
+/// timer would be awkward. Registered timers are updated alongside regular timers, and trigger the usual timer events
+/// globally, wrapped in a [`RegisteredTimerEvent`] that identifies the timer by its [`TimerHandle`].
+///
+/// Registered timers that finish are removed from the registry, unless their [finish behavior](TimerFinishBehavior) is
+/// [`TimerFinishBehavior::None`]. With [`TimerFinishBehavior::ReplaceWith`] the replacement timer takes over the same
+/// handle.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut app = App::new();
+/// app.add_plugins(ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual))
+///     .observe(|trigger: Trigger<RegisteredTimerEvent<TimerInterval>>| {
+///         info!("Autosave #{} ({:?})", trigger.event().event().count(), trigger.event().handle());
+///     });
+///
+/// let autosave = app
+///     .world_mut()
+///     .resource_mut::<TimerRegistry>()
+///     .start(ObservableTimer::indefinite_from_seconds(300.0));
+///
+/// app.tick_observable_timers(Duration::from_secs(300));
+/// assert_eq!(app.world().resource::<TimerRegistry>().get(autosave).unwrap().elapsed_intervals(), 1);
+/// ```
+#[derive(Resource, Default)]
+pub struct TimerRegistry {
+    timers: BTreeMap<TimerHandle, ObservableTimer>,
+    next_handle: u64,
+    started: Vec<(TimerHandle, TimerStarted)>,
+    cancelled: Vec<(TimerHandle, TimerFinished)>,
+}
+
+impl TimerRegistry {
+    /// Adds a timer to the registry, returning its handle.
+    ///
+    /// [`TimerStarted`] is triggered for the timer on the next timer update.
+    pub fn start(&mut self, timer: ObservableTimer) -> TimerHandle {
+        let handle = TimerHandle(self.next_handle);
+        self.next_handle += 1;
+        self.started.push((handle, TimerStarted { tag: timer.tag }));
+        self.timers.insert(handle, timer);
+        handle
+    }
+
+    /// Removes a timer from the registry, returning it if it existed.
+    ///
+    /// If the timer hadn't finished, [`TimerFinished`] is triggered for it on the next timer update with
+    /// [`TimerFinished::cancelled()`] set to `true`.
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<ObservableTimer> {
+        let timer = self.timers.remove(&handle)?;
+        if !timer.is_done() {
+            let event = TimerFinished {
+                reason: StopReason::Cancelled,
+                condition_met: false,
+                overshoot: Duration::ZERO,
+                interval_duration: timer.interval_duration(),
+                direction: timer.direction(),
+                tag: timer.tag,
+            };
+            self.cancelled.push((handle, event));
+        }
+        Some(timer)
+    }
+
+    /// Returns `true` if the registry contains a timer for `handle`.
+    pub fn contains(&self, handle: TimerHandle) -> bool {
+        self.timers.contains_key(&handle)
+    }
+
+    /// Returns a reference to the timer for `handle`, if it is still registered.
+    pub fn get(&self, handle: TimerHandle) -> Option<&ObservableTimer> {
+        self.timers.get(&handle)
+    }
+
+    /// Returns a mutable reference to the timer for `handle`, if it is still registered.
+    pub fn get_mut(&mut self, handle: TimerHandle) -> Option<&mut ObservableTimer> {
+        self.timers.get_mut(&handle)
+    }
+
+    /// Returns whether the timer for `handle` is paused.
+    pub fn is_paused(&self, handle: TimerHandle) -> Option<bool> {
+        self.get(handle).map(ObservableTimer::paused)
+    }
+
+    /// Pauses the timer for `handle`. Returns `true` if the timer was running.
+    pub fn pause(&mut self, handle: TimerHandle) -> bool {
+        match self.get_mut(handle) {
+            Some(timer) if !timer.paused() => {
+                timer.pause();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Resumes the timer for `handle`. Returns `true` if the timer was paused.
+    pub fn resume(&mut self, handle: TimerHandle) -> bool {
+        match self.get_mut(handle) {
+            Some(timer) if timer.paused() => {
+                timer.unpause();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Restarts the timer for `handle`. See [`ObservableTimer::restart()`]. Returns `true` if the timer is still
+    /// registered.
+    pub fn restart(&mut self, handle: TimerHandle) -> bool {
+        let Some(timer) = self.get_mut(handle) else {
+            return false;
+        };
+        timer.restart();
+        true
+    }
+
+    /// The time remaining until the timer for `handle` finishes. See [`ObservableTimer::remaining()`].
+    ///
+    /// This is `None` if the timer is no longer registered, or if it runs indefinitely.
+    pub fn remaining(&self, handle: TimerHandle) -> Option<Duration> {
+        self.get(handle)?.remaining()
+    }
+
+    /// Iterates over every registered timer and its handle.
+    pub fn iter(&self) -> impl Iterator<Item = (TimerHandle, &ObservableTimer)> {
+        self.timers.iter().map(|(handle, timer)| (*handle, timer))
+    }
+
+    /// The number of registered timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if there are no registered timers.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+}
+
+/// A timer [`Event`] triggered globally for a timer in the [`TimerRegistry`].
+///
+/// This wraps the event that would have been triggered on an entity's timer, e.g.
+/// `RegisteredTimerEvent<TimerFinished>`.
+#[derive(Event, Debug)]
+pub struct RegisteredTimerEvent<E: Event> {
+    handle: TimerHandle,
+    event: E,
+}
+
+impl<E: Event> RegisteredTimerEvent<E> {
+    /// The handle of the timer that triggered this event.
+    pub fn handle(&self) -> TimerHandle {
+        self.handle
+    }
+
+    /// The underlying timer event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+}
+
+pub(crate) fn update_timer_registry(
+    In(delta): In<Duration>,
+    default_catch_up: Option<Res<TimerCatchUp>>,
+    registry: Option<ResMut<TimerRegistry>>,
+    mut commands: Commands,
+) {
+    let Some(mut registry) = registry else {
+        return;
+    };
+    let default_catch_up = default_catch_up.as_deref().copied().unwrap_or_default();
+    let registry = &mut *registry;
+
+    for (handle, event) in registry.started.drain(..) {
+        commands.trigger(RegisteredTimerEvent { handle, event });
+    }
+
+    for (handle, event) in registry.cancelled.drain(..) {
+        commands.trigger(RegisteredTimerEvent { handle, event });
+    }
+
+    registry.timers.retain(|&handle, timer| {
+        let tick = tick_timer(timer, delta, default_catch_up);
+        let tag = timer.tag;
+
+        if tick.restarted {
+            let event = TimerRestarted { tag };
+            commands.trigger(RegisteredTimerEvent { handle, event });
+        }
+        if let Some(event) = tick.adjusted {
+            commands.trigger(RegisteredTimerEvent { handle, event });
+        }
+        for count in tick.intervals {
+            let event = TimerInterval { count, tag };
+            commands.trigger(RegisteredTimerEvent { handle, event });
+        }
+        for remaining_secs in tick.seconds.rev() {
+            let event = TimerSecondElapsed {
+                remaining_secs,
+                tag,
+            };
+            commands.trigger(RegisteredTimerEvent { handle, event });
+        }
+        if let Some(event) = tick.progress {
+            commands.trigger(RegisteredTimerEvent { handle, event });
+        }
+
+        let Some(overshoot) = tick.finished else {
+            return true;
+        };
+        let event = TimerFinished {
+            reason: StopReason::Finished,
+            condition_met: false,
+            overshoot,
+            interval_duration: timer.interval_duration(),
+            direction: timer.direction(),
+            tag,
+        };
+        commands.trigger(RegisteredTimerEvent { handle, event });
+
+        match &timer.finish_behavior {
+            TimerFinishBehavior::None => true,
+            TimerFinishBehavior::ReplaceWith(next) => {
+                *timer = next.as_ref().clone();
+                let event = TimerStarted { tag: timer.tag };
+                commands.trigger(RegisteredTimerEvent { handle, event });
+                true
+            }
+            _ => false,
+        }
+    });
+}
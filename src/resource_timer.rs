@@ -0,0 +1,96 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::delayed::register_delayed_system_in_world;
+
+/// A [`Resource`] that inserts or removes the resource `R` once the given time has passed, then removes itself.
+///
+/// This is for app-level state changes that don't involve any entity, such as inserting a `SuddenDeath` resource after
+/// five minutes of match time. Create one with [`TimerCommandsExt::insert_resource_after()`] or
+/// [`TimerCommandsExt::remove_resource_after()`]. When it elapses a [`ResourceTimerElapsed<R>`] event is triggered
+/// globally, after `R` has been inserted or removed.
+///
+/// There can only be one `ResourceTimer<R>` for each resource type, so scheduling another replaces it. Remove the
+/// `ResourceTimer<R>` resource to cancel it.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Resource)]
+/// # struct SuddenDeath;
+/// # fn setup(mut commands: Commands) {
+/// commands.insert_resource_after(Duration::from_secs(5 * 60), SuddenDeath);
+/// # }
+/// ```
+///
+/// [`TimerCommandsExt::insert_resource_after()`]: crate::TimerCommandsExt::insert_resource_after
+/// [`TimerCommandsExt::remove_resource_after()`]: crate::TimerCommandsExt::remove_resource_after
+#[derive(Resource, Debug)]
+pub struct ResourceTimer<R: Resource> {
+    /// The time remaining until the resource is inserted or removed.
+    pub remaining: Duration,
+    insert: Option<R>,
+}
+
+impl<R: Resource> ResourceTimer<R> {
+    /// Returns `true` if the resource will be inserted, and `false` if it will be removed.
+    pub fn inserts(&self) -> bool {
+        self.insert.is_some()
+    }
+}
+
+/// An [`Event`] triggered globally when a [`ResourceTimer<R>`] elapses.
+#[derive(Event, Debug)]
+pub struct ResourceTimerElapsed<R: Resource> {
+    inserted: bool,
+    marker: PhantomData<R>,
+}
+
+impl<R: Resource> ResourceTimerElapsed<R> {
+    /// `true` if the resource was inserted, and `false` if it was removed.
+    pub fn inserted(&self) -> bool {
+        self.inserted
+    }
+}
+
+/// Inserts a [`ResourceTimer<R>`], making sure its update system is registered.
+pub(crate) fn start_resource_timer<R: Resource>(
+    world: &mut World,
+    duration: Duration,
+    insert: Option<R>,
+) {
+    register_delayed_system_in_world::<ResourceTimer<R>, _>(world, update_resource_timer::<R>);
+    world.insert_resource(ResourceTimer {
+        remaining: duration,
+        insert,
+    });
+}
+
+fn update_resource_timer<R: Resource>(
+    In(delta): In<Duration>,
+    timer: Option<ResMut<ResourceTimer<R>>>,
+    mut commands: Commands,
+) {
+    let Some(mut timer) = timer else {
+        return;
+    };
+    let timer = timer.bypass_change_detection();
+    timer.remaining = timer.remaining.saturating_sub(delta);
+    if !timer.remaining.is_zero() {
+        return;
+    }
+
+    let insert = timer.insert.take();
+    let inserted = insert.is_some();
+    commands.remove_resource::<ResourceTimer<R>>();
+    match insert {
+        Some(resource) => commands.insert_resource(resource),
+        None => commands.remove_resource::<R>(),
+    }
+    commands.trigger(ResourceTimerElapsed::<R> {
+        inserted,
+        marker: PhantomData,
+    });
+}
@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Spawns the replacement entity for a [`RespawnAfter`].
+type RespawnFn = Box<dyn FnOnce(&mut World) -> Entity + Send + Sync>;
+
+/// A component that spawns a replacement for a despawned entity once the given time has passed.
+///
+/// A `RespawnAfter` holds either a factory function or a snapshot [`Bundle`] to spawn the replacement from, and is
+/// usually spawned with [`TimerCommandsExt::respawn_after()`], which also despawns the entity being replaced. When the
+/// time is up the replacement is spawned, a [`Respawned`] event is triggered on the `RespawnAfter`'s entity, and that
+/// entity is then despawned. Despawning it before then cancels the respawn.
+///
+/// To respawn in a loop, have the factory's bundle (or an observer of [`Respawned`]) set up the next respawn when the
+/// new entity dies.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// let mut world = World::new();
+/// let enemy = world.spawn(Enemy).id();
+///
+/// world.despawn(enemy);
+/// world.spawn(RespawnAfter::from_bundle(Duration::from_secs(5), Enemy).with_previous(enemy));
+///
+/// world.tick_observable_timers(Duration::from_secs(5));
+/// assert_eq!(world.query::<&Enemy>().iter(&world).count(), 1);
+/// assert_eq!(world.query::<&RespawnAfter>().iter(&world).count(), 0);
+/// ```
+///
+/// [`TimerCommandsExt::respawn_after()`]: crate::TimerCommandsExt::respawn_after
+#[derive(Component)]
+pub struct RespawnAfter {
+    /// The time remaining until the replacement is spawned.
+    pub remaining: Duration,
+    previous: Option<Entity>,
+    factory: RespawnFn,
+}
+
+impl RespawnAfter {
+    /// Create a new `RespawnAfter` that spawns the entity returned by `factory` once `duration` has passed.
+    pub fn new(
+        duration: Duration,
+        factory: impl FnOnce(&mut World) -> Entity + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            remaining: duration,
+            previous: None,
+            factory: Box::new(factory),
+        }
+    }
+
+    /// Create a new `RespawnAfter` that spawns a new entity with `bundle` once `duration` has passed.
+    pub fn from_bundle<B: Bundle>(duration: Duration, bundle: B) -> Self {
+        Self::new(duration, move |world| world.spawn(bundle).id())
+    }
+
+    /// Sets the entity being replaced, which is reported by [`Respawned::previous()`].
+    pub fn with_previous(mut self, previous: Entity) -> Self {
+        self.previous = Some(previous);
+        self
+    }
+
+    /// The entity being replaced, if known.
+    pub fn previous(&self) -> Option<Entity> {
+        self.previous
+    }
+}
+
+impl std::fmt::Debug for RespawnAfter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RespawnAfter")
+            .field("remaining", &self.remaining)
+            .field("previous", &self.previous)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An [`Event`] that is triggered on a [`RespawnAfter`]'s entity once it has spawned the replacement entity.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct Respawned {
+    previous: Option<Entity>,
+    new_entity: Entity,
+}
+
+impl Respawned {
+    /// The entity that was replaced, if known.
+    pub fn previous(&self) -> Option<Entity> {
+        self.previous
+    }
+
+    /// The newly spawned entity.
+    pub fn new_entity(&self) -> Entity {
+        self.new_entity
+    }
+}
+
+pub(crate) fn update_respawn_after(
+    In(delta): In<Duration>,
+    mut respawns: Query<(Entity, &mut RespawnAfter)>,
+    mut commands: Commands,
+) {
+    for (entity, mut respawn) in respawns.iter_mut() {
+        let respawn = respawn.bypass_change_detection();
+        respawn.remaining = respawn.remaining.saturating_sub(delta);
+        if respawn.remaining.is_zero() {
+            commands
+                .entity(entity)
+                .add(|entity: Entity, world: &mut World| {
+                    // The entity may have been despawned since, which cancels the respawn
+                    let Some(respawn) = world
+                        .get_entity_mut(entity)
+                        .and_then(|mut entity| entity.take::<RespawnAfter>())
+                    else {
+                        return;
+                    };
+                    let new_entity = (respawn.factory)(world);
+                    let previous = respawn.previous;
+                    world.trigger_targets(
+                        Respawned {
+                            previous,
+                            new_entity,
+                        },
+                        entity,
+                    );
+                    // Observers may have despawned the entity already
+                    if let Some(entity) = world.get_entity_mut(entity) {
+                        entity.despawn();
+                    }
+                });
+        }
+    }
+}
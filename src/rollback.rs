@@ -0,0 +1,337 @@
+use bevy::{
+    ecs::{system::EntityCommands, world::DeferredWorld},
+    prelude::*,
+};
+
+use crate::{
+    update::{RunningTimer, RunningTimers},
+    ObservableTimer, TimerFinished, TimerTag,
+};
+
+/// A [`Resource`] that deduplicates [`TimerFinished`] events when a rollback netcode resimulates frames.
+///
+/// With prediction and rollback (such as with `lightyear`), frames are simulated ahead of confirmed input, then rolled
+/// back and resimulated when the prediction turns out to be wrong. Timers themselves roll back like any other
+/// component, by registering [`ObservableTimer`](crate::ObservableTimer) with the netcode's rollback or prediction
+/// and updating timers in the schedule it resimulates, using [`TimerTickMode::FixedStep`](crate::TimerTickMode).
+/// Snapshots should be loaded with [`RestoreTimerExt::restore_timer()`] or by overwriting the component in place, since
+/// inserting them triggers the events of a new timer. The events triggered while resimulating, however, would run every
+/// observer's side effects a second time.
+///
+/// This crate doesn't depend on any netcode, so registering the component and running the timer update in the
+/// netcode's schedule (`FixedMain` for `lightyear`) is left to the app, as shown in
+/// [`ObservableTimerPlugin::with_rollback()`].
+///
+/// A `TimerRollback` keeps track of the simulation tick, which must be set with [`Self::set_tick()`] before timers
+/// update on every tick, including resimulated ones. Going back to a tick that was already simulated starts a
+/// resimulation, which lasts until simulation moves past the furthest tick that was simulated before. What happens to
+/// `TimerFinished` events while resimulating is set by [`Self::finished_events`], and by default a timer that already
+/// triggered a `TimerFinished` event since the start of the rollback doesn't trigger it again, even if it now finishes
+/// on a different tick. Finish behaviors are always performed.
+///
+/// Timers are told apart by their entity, their [tag](crate::TimerTag), the tick they were first inserted on and the
+/// number of times they have been [restarted](ObservableTimer::restart()). The start tick is recorded in the timer
+/// component itself, so it is restored along with the rest of the timer's state when rolling back. A new timer that
+/// is inserted on a later tick is a different timer, and triggers its own event even on an entity whose previous timer
+/// already finished.
+///
+/// With GGRS, timers are updated in `GgrsSchedule` and their components are included in rollback snapshots. GGRS
+/// loads snapshots by overwriting components in place, which triggers no timer events, but reinserts components that
+/// were removed since, which triggers [`TimerStarted`](crate::TimerStarted) again. Timers that may be removed during
+/// a rollback window should use [`TimerFinishBehavior::None`](crate::TimerFinishBehavior::None) instead.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// # struct GgrsSchedule;
+/// # #[derive(Resource)]
+/// # struct RollbackFrameCount(i32);
+/// # let mut app = App::new();
+/// app.add_plugins(
+///     ObservableTimerPlugin::in_schedule(GgrsSchedule)
+///         .with_tick_mode(TimerTickMode::FixedStep(Duration::from_nanos(16_666_667)))
+///         .with_rollback(8),
+/// )
+/// // With `bevy_ggrs`, timers are also included in rollback snapshots:
+/// // .rollback_component_with_clone::<ObservableTimer>()
+/// .add_systems(GgrsSchedule, set_timer_tick.before(ObservableTimerSet));
+///
+/// fn set_timer_tick(frame: Res<RollbackFrameCount>, mut rollback: ResMut<TimerRollback>) {
+///     rollback.set_tick(frame.0 as u32);
+/// }
+/// ```
+///
+/// This is inserted by [`ObservableTimerPlugin::with_rollback()`], and may also be inserted or removed at runtime.
+///
+/// Requires the `rollback` feature.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.insert_resource(TimerRollback::new(8));
+/// let timer = ObservableTimer::once_from_seconds(1.0).with_finish_behavior(TimerFinishBehavior::None);
+/// let predicted = world.spawn(timer).id();
+/// let confirmed = world.get::<ObservableTimer>(predicted).unwrap().clone();
+///
+/// world.resource_mut::<TimerRollback>().set_tick(10);
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.resource::<TimerRollback>().finished_since(10), 1);
+///
+/// // The prediction was wrong, so tick 10 is resimulated from the confirmed state
+/// world.entity_mut(predicted).restore_timer(confirmed);
+/// world.resource_mut::<TimerRollback>().set_tick(10);
+/// assert!(world.resource::<TimerRollback>().is_resimulating());
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert_eq!(world.resource::<TimerRollback>().finished_since(10), 1);
+/// ```
+///
+/// [`ObservableTimerPlugin::with_rollback()`]: crate::ObservableTimerPlugin::with_rollback
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct TimerRollback {
+    /// The furthest number of ticks that can be rolled back, after which finished timers are forgotten.
+    pub max_rollback: u32,
+    /// What happens to [`TimerFinished`] events while resimulating. Defaults to
+    /// [`RollbackFinishedEvents::Deduplicate`].
+    pub finished_events: RollbackFinishedEvents,
+    tick: u32,
+    latest: Option<u32>,
+    rollback_start: Option<u32>,
+    /// The ticks that timers triggered [`TimerFinished`] on, along with the timers.
+    finished: Vec<(u32, TimerInstance)>,
+}
+
+/// Identifies a single run of an [`ObservableTimer`], which triggers at most one [`TimerFinished`] event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimerInstance {
+    entity: Entity,
+    start_tick: Option<u32>,
+    restarts: u32,
+    tag: Option<TimerTag>,
+}
+
+impl TimerRollback {
+    /// Creates a `TimerRollback` that can deduplicate events across rollbacks of up to `max_rollback` ticks.
+    pub fn new(max_rollback: u32) -> Self {
+        Self {
+            max_rollback,
+            finished_events: RollbackFinishedEvents::default(),
+            tick: 0,
+            latest: None,
+            rollback_start: None,
+            finished: Vec::new(),
+        }
+    }
+
+    /// Sets what happens to [`TimerFinished`] events while resimulating.
+    pub fn with_finished_events(mut self, finished_events: RollbackFinishedEvents) -> Self {
+        self.finished_events = finished_events;
+        self
+    }
+
+    /// Sets the tick that is being simulated. Setting a tick that was already simulated starts a resimulation.
+    pub fn set_tick(&mut self, tick: u32) {
+        match self.latest {
+            Some(latest) if tick <= latest => {
+                if self.rollback_start.is_none() || tick <= self.tick {
+                    self.rollback_start = Some(tick);
+                }
+            }
+            _ => {
+                self.latest = Some(tick);
+                self.rollback_start = None;
+                let max_rollback = self.max_rollback;
+                self.finished
+                    .retain(|&(finished, _)| finished.saturating_add(max_rollback) >= tick);
+            }
+        }
+        self.tick = tick;
+    }
+
+    /// The tick that is being simulated.
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    /// Returns `true` while resimulating ticks that were already simulated.
+    pub fn is_resimulating(&self) -> bool {
+        self.rollback_start.is_some()
+    }
+
+    /// The number of [`TimerFinished`] events that have been triggered since `tick`, and are still remembered.
+    pub fn finished_since(&self, tick: u32) -> usize {
+        self.finished
+            .iter()
+            .filter(|&&(finished, _)| finished >= tick)
+            .count()
+    }
+
+    /// Records that the timer `instance` finished, returning `false` if its event was already triggered before being
+    /// rolled back.
+    fn record_finish(&mut self, instance: TimerInstance) -> bool {
+        if let Some(start) = self.rollback_start {
+            match self.finished_events {
+                RollbackFinishedEvents::Replay => return true,
+                RollbackFinishedEvents::Suppress
+                    if instance.start_tick.is_none_or(|started| started < start) =>
+                {
+                    return false
+                }
+                _ => {}
+            }
+            let triggered = self.finished.iter().any(|&(finished, finished_instance)| {
+                finished_instance == instance && finished >= start
+            });
+            if triggered {
+                return false;
+            }
+        }
+        self.finished.push((self.tick, instance));
+        true
+    }
+}
+
+/// What happens to [`TimerFinished`] events that are triggered while a [`TimerRollback`] is resimulating.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum RollbackFinishedEvents {
+    /// Events are only triggered for timers that haven't already triggered one since the start of the rollback.
+    ///
+    /// This is the default.
+    #[default]
+    Deduplicate,
+    /// No events are triggered while resimulating for timers that were already running when the first resimulated tick
+    /// started.
+    ///
+    /// Timers that were inserted during the resimulated ticks trigger their events as with [`Self::Deduplicate`], so a
+    /// timer that was only inserted because of the corrected prediction still triggers its event once.
+    Suppress,
+    /// Every event is triggered again, so observers can replay their effects. Observers can tell replayed events
+    /// apart with [`TimerRollback::is_resimulating()`].
+    Replay,
+}
+
+/// An extension trait for restoring an entity's [`ObservableTimer`] to the state it had on an earlier tick, when a
+/// rollback netcode loads a snapshot.
+///
+/// Inserting the snapshot with `insert()` would treat it as a new timer, triggering a [`TimerFinished`] with
+/// [`StopReason::Replaced`](crate::StopReason::Replaced) for the mispredicted timer, followed by
+/// [`TimerReplaced`](crate::TimerReplaced) and [`TimerStarted`](crate::TimerStarted) for the restored one. Restoring
+/// it triggers none of these, and also works on entities that lost their timer after the snapshot was taken. The
+/// restored timer is paused if its entity is disabled, but isn't staggered or synchronized with its group again, since
+/// the snapshot already was.
+///
+/// Netcodes that overwrite the component in place when loading a snapshot, such as GGRS with
+/// `rollback_component_with_clone`, don't trigger any events either, and don't need this.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.insert_resource(TimerRollback::new(8));
+/// let timer = ObservableTimer::once_from_seconds(1.0).with_finish_behavior(TimerFinishBehavior::RemoveComponent);
+/// let predicted = world.spawn(timer).id();
+/// let confirmed = world.get::<ObservableTimer>(predicted).unwrap().clone();
+///
+/// world.resource_mut::<TimerRollback>().set_tick(10);
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert!(world.get::<ObservableTimer>(predicted).is_none());
+///
+/// world.entity_mut(predicted).restore_timer(confirmed);
+/// assert!(!world.get::<ObservableTimer>(predicted).unwrap().is_done());
+/// ```
+///
+/// Requires the `rollback` feature.
+pub trait RestoreTimerExt {
+    /// Restores the entity's [`ObservableTimer`] to `timer`, without triggering any timer events.
+    fn restore_timer(&mut self, timer: ObservableTimer) -> &mut Self;
+}
+
+impl RestoreTimerExt for EntityWorldMut<'_> {
+    fn restore_timer(&mut self, timer: ObservableTimer) -> &mut Self {
+        self.insert(TimerRestored)
+            .insert(timer)
+            .remove::<TimerRestored>()
+    }
+}
+
+impl RestoreTimerExt for EntityCommands<'_> {
+    fn restore_timer(&mut self, timer: ObservableTimer) -> &mut Self {
+        self.add(move |mut entity: EntityWorldMut| {
+            entity.restore_timer(timer);
+        })
+    }
+}
+
+/// Marks a timer that is being restored, so that the insert hook doesn't treat it as a new timer.
+#[derive(Component)]
+struct TimerRestored;
+
+/// Keeps track of a timer that has just been inserted on `entity` if it is being restored, returning `false` if it
+/// is a new timer instead.
+pub(crate) fn restore_timer_state(world: &mut DeferredWorld, entity: Entity) -> bool {
+    if world.get::<TimerRestored>(entity).is_none() {
+        return false;
+    }
+
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    let timer = timer.bypass_change_detection();
+    timer.just_added = false;
+    let (done, current) = (timer.is_done(), RunningTimer::of(timer));
+    if let Some(mut running) = world.get_resource_mut::<RunningTimers>() {
+        if done {
+            running.0.remove(&entity);
+        } else {
+            running.0.insert(entity, current);
+        }
+    }
+    true
+}
+
+/// Records the current tick on a timer that has just been inserted on `entity`, unless it was restored from a snapshot
+/// that already has one.
+pub(crate) fn stamp_start_tick(world: &mut DeferredWorld, entity: Entity) {
+    let Some(tick) = world
+        .get_resource::<TimerRollback>()
+        .map(TimerRollback::tick)
+    else {
+        return;
+    };
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    let timer = timer.bypass_change_detection();
+    timer.start_tick.get_or_insert(tick);
+}
+
+/// Triggers `finished` for each of `targets` that hasn't already triggered it before a rollback.
+pub(crate) fn trigger_finished(world: &mut World, finished: TimerFinished, targets: Vec<Entity>) {
+    if !world.contains_resource::<TimerRollback>() {
+        world.trigger_targets(finished, targets);
+        return;
+    }
+
+    let instances: Vec<_> = targets
+        .iter()
+        .map(|&entity| {
+            let timer = world.get::<ObservableTimer>(entity);
+            TimerInstance {
+                entity,
+                start_tick: timer.and_then(|timer| timer.start_tick),
+                restarts: timer.map_or(0, |timer| timer.restarts),
+                tag: finished.tag,
+            }
+        })
+        .collect();
+    let mut rollback = world.resource_mut::<TimerRollback>();
+    let targets: Vec<_> = instances
+        .into_iter()
+        .filter(|&instance| rollback.record_finish(instance))
+        .map(|instance| instance.entity)
+        .collect();
+    if !targets.is_empty() {
+        world.trigger_targets(finished, targets);
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::{ecs::query::QueryFilter, prelude::*};
+
+use crate::TimerFinished;
+
+/// A [`Resource`] recording the entities whose timers finished during the last timer update.
+///
+/// This is cleared at the start of every update of [`ObservableTimerSet`](crate::ObservableTimerSet), and holds every
+/// entity on which [`TimerFinished`] was triggered since then, excluding cancelled timers. It backs the
+/// [`on_timer_finished()`] and [`any_timer_finished()`] run conditions, which bridge timer events to ordinary systems.
+#[derive(Resource, Debug, Default)]
+pub struct FinishedTimers {
+    entities: Vec<Entity>,
+}
+
+impl FinishedTimers {
+    /// Returns `true` if the timer on `entity` finished during the last timer update.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(&entity)
+    }
+
+    /// Iterates over the entities whose timers finished during the last timer update.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter().copied()
+    }
+
+    /// Returns `true` if no timers finished during the last timer update.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+/// A run condition that is `true` if the timer on `entity` finished during the last timer update.
+///
+/// See [`FinishedTimers`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn open_gate() {}
+/// # let mut app = App::new();
+/// # let gate_timer = app.world_mut().spawn(ObservableTimer::once_from_seconds(5.0)).id();
+/// app.add_systems(Update, open_gate.run_if(on_timer_finished(gate_timer)).after(ObservableTimerSet));
+/// ```
+pub fn on_timer_finished(entity: Entity) -> impl FnMut(Res<FinishedTimers>) -> bool + Clone {
+    move |finished: Res<FinishedTimers>| finished.contains(entity)
+}
+
+/// A run condition that is `true` if any timer on an entity matching the filter `F` finished during the last timer
+/// update. Use `()` to match every timer.
+///
+/// The filter is checked when the condition runs, so it only matches entities that still exist at that point. Timers
+/// with the default [`TimerFinishBehavior::DespawnEntity`](crate::TimerFinishBehavior::DespawnEntity) never match a
+/// filter other than `()`.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Component)]
+/// # struct WaveTimer;
+/// # fn play_victory_fanfare() {}
+/// # let mut app = App::new();
+/// app.add_systems(Update, play_victory_fanfare.run_if(any_timer_finished::<With<WaveTimer>>()));
+/// ```
+pub fn any_timer_finished<F: QueryFilter>(
+) -> impl FnMut(Res<FinishedTimers>, Query<(), F>) -> bool + Clone {
+    |finished: Res<FinishedTimers>, filter: Query<(), F>| {
+        finished.iter().any(|entity| filter.contains(entity))
+    }
+}
+
+pub(crate) fn clear_finished_timers(finished: Option<ResMut<FinishedTimers>>) {
+    if let Some(mut finished) = finished {
+        finished.entities.clear();
+    }
+}
+
+pub(crate) fn record_finished_timers(
+    trigger: Trigger<TimerFinished>,
+    finished: Option<ResMut<FinishedTimers>>,
+) {
+    if let (false, Some(mut finished)) = (trigger.event().cancelled(), finished) {
+        finished.entities.push(trigger.entity());
+    }
+}
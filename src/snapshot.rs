@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::ObservableTimer;
+
+/// A copy of the state of every [`ObservableTimer`] in a [`World`], for saving and restoring timers.
+///
+/// Restoring a snapshot with [`TimerSnapshot::apply()`] overwrites existing timers in place, so no [`TimerFinished`]
+/// is triggered for the timers being replaced. With the `serialize` feature enabled the snapshot implements
+/// `Serialize` and `Deserialize`.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// let snapshot = TimerSnapshot::capture(app.world());
+/// // ...
+/// snapshot.apply(app.world_mut());
+/// ```
+///
+/// [`TimerFinished`]: crate::TimerFinished
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerSnapshot {
+    timers: Vec<(Entity, ObservableTimer)>,
+}
+
+impl TimerSnapshot {
+    /// Captures the state of every [`ObservableTimer`] in `world`.
+    pub fn capture(world: &World) -> Self {
+        let timers = world
+            .iter_entities()
+            .filter_map(|entity| Some((entity.id(), entity.get::<ObservableTimer>()?.clone())))
+            .collect();
+        Self { timers }
+    }
+
+    /// Restores every captured timer onto its entity in `world`.
+    ///
+    /// Entities that already have an [`ObservableTimer`] are updated in place, which triggers neither
+    /// [`TimerStarted`] nor [`TimerFinished`]. Entities without one have the timer inserted, which triggers
+    /// [`TimerStarted`] as usual. Entities that no longer exist are skipped.
+    ///
+    /// Timers in `world` that are not part of the snapshot are left untouched.
+    ///
+    /// [`TimerStarted`]: crate::TimerStarted
+    /// [`TimerFinished`]: crate::TimerFinished
+    pub fn apply(&self, world: &mut World) {
+        for (entity, timer) in &self.timers {
+            let Some(mut entity) = world.get_entity_mut(*entity) else {
+                continue;
+            };
+            match entity.get_mut::<ObservableTimer>() {
+                Some(mut existing) => *existing = timer.clone(),
+                None => {
+                    entity.insert(timer.clone());
+                }
+            }
+        }
+    }
+
+    /// The captured timers and the entities they belong to.
+    pub fn timers(&self) -> impl Iterator<Item = (Entity, &ObservableTimer)> {
+        self.timers.iter().map(|(entity, timer)| (*entity, timer))
+    }
+
+    /// The number of captured timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if no timers were captured.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{ecs::system::SystemId, prelude::*};
+
+use crate::TimerInterval;
+
+/// Spawns something on behalf of a spawner entity.
+type SpawnFn = Box<dyn Fn(Entity, &mut Commands) + Send + Sync>;
+
+/// A component that spawns something every time its entity's timer completes an interval.
+///
+/// `SpawnInterval` is paired with an [`ObservableTimer`](crate::ObservableTimer) on the same entity, which sets the
+/// cadence. What is spawned is either a clone of a bundle, a factory closure, or a registered system that takes the
+/// spawner's entity as input.
+///
+/// With a [max count](Self::with_max_count()), a [`SpawnerFinished`] event is triggered on the spawner once that many
+/// spawns have happened, and the `SpawnInterval` is removed. The timer itself is left alone, so give it a matching
+/// interval count (or a finish behavior) if it should stop at the same time.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Component, Clone)]
+/// # struct Enemy;
+/// # #[derive(Component)]
+/// # struct SpawnedBy(Entity);
+/// # fn setup(mut commands: Commands) {
+/// // Spawn a wave of 10 enemies, one every 2 seconds
+/// commands
+///     .spawn((
+///         ObservableTimer::from_seconds(10, 2.0),
+///         SpawnInterval::from_fn(|spawner, commands| {
+///             commands.spawn((Enemy, SpawnedBy(spawner)));
+///         })
+///         .with_max_count(10),
+///     ))
+///     .observe(|_: Trigger<SpawnerFinished>| info!("Wave complete"));
+/// # }
+/// ```
+#[derive(Component)]
+pub struct SpawnInterval {
+    spawn: SpawnFn,
+    spawned: u32,
+    /// The number of spawns after which the spawner finishes, or `None` to spawn for as long as the timer runs.
+    pub max_count: Option<u32>,
+}
+
+impl SpawnInterval {
+    /// Creates a `SpawnInterval` that spawns a clone of `bundle` every interval.
+    pub fn new<B: Bundle + Clone>(bundle: B) -> Self {
+        Self::from_fn(move |_, commands| {
+            commands.spawn(bundle.clone());
+        })
+    }
+
+    /// Creates a `SpawnInterval` that calls `factory` every interval, with the spawner's entity.
+    pub fn from_fn(factory: impl Fn(Entity, &mut Commands) + Send + Sync + 'static) -> Self {
+        Self {
+            spawn: Box::new(factory),
+            spawned: 0,
+            max_count: None,
+        }
+    }
+
+    /// Creates a `SpawnInterval` that runs a registered system every interval, with the spawner's entity as input.
+    pub fn from_system(system: SystemId<Entity>) -> Self {
+        Self::from_fn(move |spawner, commands| {
+            commands.run_system_with_input(system, spawner);
+        })
+    }
+
+    /// Sets the maximum number of spawns.
+    pub fn with_max_count(mut self, max_count: u32) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// The number of spawns that have happened so far.
+    pub fn spawned(&self) -> u32 {
+        self.spawned
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`SpawnInterval`] entity once it has spawned its maximum count.
+#[derive(Event, Reflect, Debug)]
+#[reflect(Debug)]
+pub struct SpawnerFinished {
+    spawned: u32,
+}
+
+impl SpawnerFinished {
+    /// The total number of spawns.
+    pub fn spawned(&self) -> u32 {
+        self.spawned
+    }
+}
+
+pub(crate) fn spawn_on_interval(
+    trigger: Trigger<TimerInterval>,
+    mut spawners: Query<&mut SpawnInterval>,
+    mut commands: Commands,
+) {
+    let spawner = trigger.entity();
+    let Ok(mut spawn_interval) = spawners.get_mut(spawner) else {
+        return;
+    };
+    if spawn_interval
+        .max_count
+        .is_some_and(|max_count| spawn_interval.spawned >= max_count)
+    {
+        return;
+    }
+
+    (spawn_interval.spawn)(spawner, &mut commands);
+    spawn_interval.spawned += 1;
+
+    if spawn_interval.max_count == Some(spawn_interval.spawned) {
+        let spawned = spawn_interval.spawned;
+        commands.trigger_targets(SpawnerFinished { spawned }, spawner);
+        commands.entity(spawner).remove::<SpawnInterval>();
+    }
+}
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use bevy::{ecs::world::DeferredWorld, prelude::*};
+
+use crate::{ObservableTimer, TimerGroup, TimerGroupSettings, TimerGroups};
+
+/// The fractional part of the golden ratio, which spreads successive offsets evenly over an interval.
+const GOLDEN_RATIO_FRACTION: f64 = 0.618_033_988_749_895;
+
+/// A [`Resource`] that offsets the initial phase of newly added repeating [`ObservableTimer`]s, so that many timers
+/// with the same interval duration don't all complete on the same update.
+///
+/// When [`Self::all`] is set, or the timer's [`TimerGroup`] is [staggered](crate::TimerGroupSettings::staggered), the
+/// first interval of each repeating timer is shortened by a different fraction of its duration. The fractions follow
+/// a low-discrepancy sequence rather than random numbers, so any number of timers are spread evenly over the interval,
+/// and the same timers are always staggered the same way. Only the first interval is shortened, and no events are
+/// triggered by doing so.
+///
+/// Timers that are inserted already partway through their first interval (such as with
+/// [`ObservableTimer::with_elapsed()`]), timers that complete only once, and timers in
+/// [synchronized](crate::TimerGroupSettings::synchronized) groups are not staggered.
+///
+/// This is inserted by [`ObservableTimerPlugin`](crate::ObservableTimerPlugin), and staggering every timer can be
+/// enabled with [`ObservableTimerPlugin::with_phase_stagger()`](crate::ObservableTimerPlugin::with_phase_stagger).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// world.insert_resource(TimerStagger::new(true));
+///
+/// let timers: Vec<_> = (0..4)
+///     .map(|_| world.spawn(ObservableTimer::indefinite_from_seconds(1.0)).id())
+///     .collect();
+/// world.tick_observable_timers(Duration::ZERO);
+///
+/// let mut remaining: Vec<_> = timers
+///     .iter()
+///     .map(|&timer| world.get::<ObservableTimer>(timer).unwrap().interval_remaining())
+///     .collect();
+/// remaining.dedup();
+/// assert_eq!(remaining.len(), 4);
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TimerStagger {
+    /// Whether every repeating timer is staggered, rather than only those in staggered groups. Defaults to `false`.
+    pub all: bool,
+    /// The number of timers staggered so far, which picks the next offset.
+    staggered: u32,
+    /// Timers that have been added since the last update.
+    pending: Vec<Entity>,
+}
+
+impl TimerStagger {
+    /// Creates a `TimerStagger` that staggers every repeating timer if `all` is `true`, or otherwise only timers in
+    /// staggered groups.
+    pub fn new(all: bool) -> Self {
+        Self { all, ..default() }
+    }
+
+    /// The fraction of its first interval that the next staggered timer skips.
+    fn next_offset(&mut self) -> f64 {
+        let offset = (f64::from(self.staggered) * GOLDEN_RATIO_FRACTION).fract();
+        self.staggered = self.staggered.wrapping_add(1);
+        offset
+    }
+}
+
+/// Records that the timer on `entity` has been added, if it may need to be staggered.
+pub(crate) fn queue_staggered_timer(world: &mut DeferredWorld, entity: Entity) {
+    let Some(stagger) = world.get_resource::<TimerStagger>() else {
+        return;
+    };
+    if !stagger.all {
+        let (Some(group), Some(groups)) = (
+            world.get::<TimerGroup>(entity),
+            world.get_resource::<TimerGroups>(),
+        ) else {
+            return;
+        };
+        if !groups.settings(&group.0).staggered {
+            return;
+        }
+    }
+    world.resource_mut::<TimerStagger>().pending.push(entity);
+}
+
+pub(crate) fn stagger_timers(
+    stagger: Option<ResMut<TimerStagger>>,
+    groups: Option<Res<TimerGroups>>,
+    mut timers: Query<(&mut ObservableTimer, Option<&TimerGroup>)>,
+) {
+    let Some(mut stagger) = stagger else {
+        return;
+    };
+    if stagger.pending.is_empty() {
+        return;
+    }
+
+    let mut pending = std::mem::take(&mut stagger.pending);
+    pending.sort_unstable();
+    pending.dedup();
+    for entity in pending {
+        let Ok((mut timer, group)) = timers.get_mut(entity) else {
+            continue;
+        };
+        let settings = match (groups.as_deref(), group) {
+            (Some(groups), Some(group)) => groups.settings(&group.0),
+            _ => TimerGroupSettings::default(),
+        };
+        if settings.synchronized || !(stagger.all || settings.staggered) {
+            continue;
+        }
+        if timer.is_done()
+            || timer.remaining_intervals() == Some(1)
+            || timer.timer.elapsed() != Duration::ZERO
+        {
+            continue;
+        }
+
+        let offset = stagger.next_offset();
+        let duration = timer.timer.duration();
+        timer
+            .timer
+            .set_duration(duration.mul_f64(1.0 - offset).max(Duration::from_nanos(1)));
+    }
+}
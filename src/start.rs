@@ -0,0 +1,113 @@
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    teardown::is_tearing_down, update::TimerEventObservers, TimerEventOrder, TimerReplaced,
+    TimerStartMode, TimerStarted, TimerTag,
+};
+
+/// A [`Resource`] holding the timers that have been inserted since the last timer update, whose [`TimerStarted`]
+/// events haven't been triggered yet.
+///
+/// Triggering these from the insert hooks would run observers as soon as the insertion is applied, before any
+/// observers added along with the timer (such as with [`EntityCommands::observe()`]) exist.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct PendingTimerStarts {
+    /// The timers in the order they were inserted.
+    queued: Vec<(Entity, ComponentId)>,
+    /// The tag of each timer, and whether it replaced a timer that had already started.
+    pending: HashMap<(Entity, ComponentId), (Option<TimerTag>, bool)>,
+}
+
+/// Records that the timer `component` has been inserted on `entity`, so that [`TimerStarted`] (preceded by
+/// [`TimerReplaced`] if `replaced` is `true`) is triggered for it on the next timer update. With
+/// [`TimerStartMode::Immediate`], or without [`PendingTimerStarts`], the events are triggered right away instead.
+///
+/// Returns `true` if the timer replaced one whose [`TimerStarted`] was never triggered.
+pub(crate) fn queue_timer_start(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    component: ComponentId,
+    tag: Option<TimerTag>,
+    replaced: bool,
+) -> bool {
+    let immediate = world.get_resource::<TimerStartMode>() == Some(&TimerStartMode::Immediate);
+    let starts = world
+        .get_resource_mut::<PendingTimerStarts>()
+        .filter(|_| !immediate);
+    let Some(mut starts) = starts else {
+        trigger_start(&mut world.commands(), entity, tag, replaced);
+        return false;
+    };
+
+    // A timer that never started doesn't count as replaced, though the one it replaced might have
+    if let Some((pending_tag, _)) = starts.pending.get_mut(&(entity, component)) {
+        *pending_tag = tag;
+        return true;
+    }
+    starts.queued.push((entity, component));
+    starts.pending.insert((entity, component), (tag, replaced));
+    false
+}
+
+/// Triggers [`TimerStarted`] right away for the timer `component` on `entity` if it is still pending, as it is being
+/// removed. Nothing is triggered while a [`TimerTeardown`](crate::TimerTeardown) is in progress.
+pub(crate) fn flush_timer_start(world: &mut DeferredWorld, entity: Entity, component: ComponentId) {
+    let Some(mut starts) = world.get_resource_mut::<PendingTimerStarts>() else {
+        return;
+    };
+    let Some((tag, replaced)) = starts.pending.remove(&(entity, component)) else {
+        return;
+    };
+    if !is_tearing_down(world) {
+        trigger_start(&mut world.commands(), entity, tag, replaced);
+    }
+}
+
+pub(crate) fn trigger_timer_starts(
+    starts: Option<ResMut<PendingTimerStarts>>,
+    event_order: Option<Res<TimerEventOrder>>,
+    observers: TimerEventObservers,
+    mut commands: Commands,
+) {
+    let Some(mut starts) = starts else {
+        return;
+    };
+    if starts.queued.is_empty() {
+        return;
+    }
+
+    let mut queued = std::mem::take(&mut starts.queued);
+    let trigger_replaced = !observers.replaced.is_empty();
+    let trigger_started = !observers.started.is_empty();
+    if !trigger_replaced && !trigger_started {
+        starts.pending.clear();
+        return;
+    }
+
+    if event_order.as_deref() == Some(&TimerEventOrder::ByEntity) {
+        queued.sort_by_key(|(entity, _)| *entity);
+    }
+    for key in queued {
+        // Timers that were removed in the meantime have already been started by their remove hook
+        let Some((tag, replaced)) = starts.pending.remove(&key) else {
+            continue;
+        };
+        if replaced && trigger_replaced {
+            commands.trigger_targets(TimerReplaced { tag }, key.0);
+        }
+        if trigger_started {
+            commands.trigger_targets(TimerStarted { tag }, key.0);
+        }
+    }
+}
+
+fn trigger_start(commands: &mut Commands, entity: Entity, tag: Option<TimerTag>, replaced: bool) {
+    if replaced {
+        commands.trigger_targets(TimerReplaced { tag }, entity);
+    }
+    commands.trigger_targets(TimerStarted { tag }, entity);
+}
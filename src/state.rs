@@ -0,0 +1,89 @@
+use bevy::{
+    prelude::*,
+    state::state::{ExitSchedules, StateTransitionEvent},
+};
+
+use crate::ObservableTimer;
+
+/// Cancels the [`ObservableTimer`] on this entity when the world's state of the matching type no longer matches the
+/// supplied value.
+///
+/// Cancelling removes both the `ObservableTimer` and this component, which triggers [`TimerFinished`] with
+/// [`TimerFinished::cancelled()`] set to `true`. Unlike bevy's `StateScoped`, the entity itself is not despawned.
+///
+/// To enable this feature call [`StateScopedTimersExt::enable_state_scoped_timers()`] for the state type. Requires
+/// the `bevy_state` feature.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum GameState {
+/// #     #[default]
+/// #     MainMenu,
+/// #     InGame,
+/// # }
+/// # fn setup(app: &mut App, mut commands: Commands) {
+/// app.init_state::<GameState>()
+///     .enable_state_scoped_timers::<GameState>();
+///
+/// commands.spawn((
+///     ObservableTimer::once_from_seconds(10.0),
+///     StateScopedTimer(GameState::InGame),
+/// ));
+/// # }
+/// ```
+///
+/// [`TimerFinished`]: crate::TimerFinished
+/// [`TimerFinished::cancelled()`]: crate::TimerFinished::cancelled
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct StateScopedTimer<S: States>(pub S);
+
+/// An extension trait for enabling [`StateScopedTimer`]s.
+pub trait StateScopedTimersExt {
+    /// Enables [`StateScopedTimer`]s for the state type `S`.
+    fn enable_state_scoped_timers<S: States>(&mut self) -> &mut Self;
+}
+
+impl StateScopedTimersExt for App {
+    fn enable_state_scoped_timers<S: States>(&mut self) -> &mut Self {
+        if !self
+            .world()
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            let name = std::any::type_name::<S>();
+            warn!("State scoped timers are enabled for state `{name}`, but the state isn't installed in the app!");
+        }
+        // Like bevy's state scoped entities, this runs in `StateTransition` rather than `OnExit`, because `OnExit`
+        // only runs for one specific variant of the state.
+        self.add_systems(
+            StateTransition,
+            cancel_state_scoped_timers::<S>.in_set(ExitSchedules::<S>::default()),
+        )
+    }
+}
+
+fn cancel_state_scoped_timers<S: States>(
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    timers: Query<(Entity, &StateScopedTimer<S>), With<ObservableTimer>>,
+    mut commands: Commands,
+) {
+    // State machine internals generate at most 1 transition event (per type) each frame
+    let Some(transition) = transitions.read().last() else {
+        return;
+    };
+    if transition.entered == transition.exited {
+        return;
+    }
+    let Some(exited) = &transition.exited else {
+        return;
+    };
+    for (entity, scope) in &timers {
+        if scope.0 == *exited {
+            commands
+                .entity(entity)
+                .remove::<(ObservableTimer, StateScopedTimer<S>)>();
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::TimerTag;
+
+/// A stopwatch component that records split times, for speedrun timers and time-trial modes.
+///
+/// The stopwatch counts up from zero while it isn't paused. Each call to [`Self::split()`] records the time elapsed so
+/// far, and a [`SplitRecorded`] event is triggered on the stopwatch's entity for it on the next update. Unlike
+/// [`ObservableTimer`](crate::ObservableTimer)s, stopwatches aren't affected by
+/// [`TimeDilation`](crate::TimeDilation), so that recorded times always reflect the time that was actually played.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let run = world.spawn(SplitStopwatch::new()).id();
+///
+/// world.tick_observable_timers(Duration::from_secs(70));
+/// world.get_mut::<SplitStopwatch>(run).unwrap().split();
+/// world.tick_observable_timers(Duration::from_secs(50));
+/// world.get_mut::<SplitStopwatch>(run).unwrap().split();
+///
+/// let stopwatch = world.get::<SplitStopwatch>(run).unwrap();
+/// assert_eq!(stopwatch.splits(), [Duration::from_secs(70), Duration::from_secs(120)]);
+/// assert_eq!(stopwatch.split_duration(1), Some(Duration::from_secs(50)));
+/// ```
+#[derive(Component, Reflect, Debug, Default, Clone, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct SplitStopwatch {
+    /// Whether the stopwatch is paused. Defaults to `false`.
+    pub paused: bool,
+    /// The stopwatch's [tag](TimerTag), which is included in all of its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    elapsed: Duration,
+    splits: Vec<Duration>,
+    reported: usize,
+}
+
+impl SplitStopwatch {
+    /// Creates a new running stopwatch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the stopwatch's [tag](TimerTag), which is included in all of its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The total time elapsed while the stopwatch wasn't paused.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Records a split at the current elapsed time, returning the time since the previous split.
+    pub fn split(&mut self) -> Duration {
+        let split = self.elapsed - self.splits.last().copied().unwrap_or_default();
+        self.splits.push(self.elapsed);
+        split
+    }
+
+    /// The total elapsed time at each recorded split.
+    pub fn splits(&self) -> &[Duration] {
+        &self.splits
+    }
+
+    /// The time between the given split and the one before it.
+    pub fn split_duration(&self, index: usize) -> Option<Duration> {
+        let total = *self.splits.get(index)?;
+        let previous = index
+            .checked_sub(1)
+            .map_or(Duration::ZERO, |previous| self.splits[previous]);
+        Some(total - previous)
+    }
+
+    /// The time elapsed since the last split, or since the start if there are none.
+    pub fn current_split(&self) -> Duration {
+        self.elapsed - self.splits.last().copied().unwrap_or_default()
+    }
+
+    /// Pauses the stopwatch.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the stopwatch.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Resets the elapsed time to zero and clears all recorded splits, including any that haven't been reported yet.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.splits.clear();
+        self.reported = 0;
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`SplitStopwatch`]'s entity for each recorded split.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct SplitRecorded {
+    index: usize,
+    split: Duration,
+    total: Duration,
+    tag: Option<TimerTag>,
+}
+
+impl SplitRecorded {
+    /// The index of the split, starting from 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The time between this split and the one before it.
+    pub fn split(&self) -> Duration {
+        self.split
+    }
+
+    /// The total elapsed time when the split was recorded.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// The [tag](TimerTag) of the stopwatch that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+pub(crate) fn update_split_stopwatches(
+    In(delta): In<Duration>,
+    mut stopwatches: Query<(Entity, &mut SplitStopwatch)>,
+    mut commands: Commands,
+) {
+    for (entity, mut stopwatch) in stopwatches.iter_mut() {
+        let stopwatch = &mut *stopwatch;
+        for index in stopwatch.reported..stopwatch.splits.len() {
+            commands.trigger_targets(
+                SplitRecorded {
+                    index,
+                    split: stopwatch.split_duration(index).unwrap(),
+                    total: stopwatch.splits[index],
+                    tag: stopwatch.tag,
+                },
+                entity,
+            );
+        }
+        stopwatch.reported = stopwatch.splits.len();
+
+        if !stopwatch.paused {
+            stopwatch.elapsed += delta;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        entity::Entities,
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::{
+    TimerAdjusted, TimerEvent, TimerFinished, TimerInterval, TimerProgress, TimerReplaced,
+    TimerRestarted, TimerSecondElapsed, TimerStarted,
+};
+
+/// A component that forwards every event of the timer on its entity to a list of subscriber entities.
+///
+/// This lets many entities share a single cadence (e.g. "every 10 seconds all crops grow") without each needing its
+/// own timer. Each [`TimerEvent`] triggered on the timer's entity is also triggered on every subscriber, wrapped in a
+/// [`SubscribedTimerEvent`] that identifies the timer. Subscribers that no longer exist are skipped.
+///
+/// The observers that forward events are only added once the first `TimerSubscribers` is, so that apps that don't use
+/// subscribers don't pay for them.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Component, Default)]
+/// # struct Crop;
+/// # impl Crop {
+/// #     fn grow(&mut self) {}
+/// # }
+/// # fn setup(mut commands: Commands, mut subscribers: Query<&mut TimerSubscribers>, crop: Entity) {
+/// let growth_timer = commands
+///     .spawn((ObservableTimer::indefinite_from_seconds(10.0), TimerSubscribers::default()))
+///     .id();
+///
+/// commands
+///     .spawn(Crop::default())
+///     .observe(|trigger: Trigger<SubscribedTimerEvent<TimerInterval>>, mut crops: Query<&mut Crop>| {
+///         crops.get_mut(trigger.entity()).unwrap().grow();
+///     });
+///
+/// // Later, once the crop has been spawned
+/// subscribers.get_mut(growth_timer).unwrap().push(crop);
+/// # }
+/// ```
+#[derive(Reflect, Debug, Default, Clone, PartialEq, Eq, Deref, DerefMut)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct TimerSubscribers(pub Vec<Entity>);
+
+impl Component for TimerSubscribers {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(on_subscribers_added);
+    }
+}
+
+/// Marks that the observers forwarding events to subscribers have been added.
+#[derive(Resource)]
+struct SubscriberForwarding;
+
+fn on_subscribers_added(mut world: DeferredWorld, _: Entity, _: ComponentId) {
+    if !world.contains_resource::<SubscriberForwarding>() {
+        world.commands().add(add_forwarding_observers);
+    }
+}
+
+fn add_forwarding_observers(world: &mut World) {
+    if world.contains_resource::<SubscriberForwarding>() {
+        return;
+    }
+    world.insert_resource(SubscriberForwarding);
+    world.observe(forward_to_subscribers::<TimerStarted>);
+    world.observe(forward_to_subscribers::<TimerReplaced>);
+    world.observe(forward_to_subscribers::<TimerRestarted>);
+    world.observe(forward_to_subscribers::<TimerAdjusted>);
+    world.observe(forward_to_subscribers::<TimerInterval>);
+    world.observe(forward_to_subscribers::<TimerSecondElapsed>);
+    world.observe(forward_to_subscribers::<TimerProgress>);
+    world.observe(forward_to_subscribers::<TimerFinished>);
+}
+
+/// A timer [`Event`] that is forwarded to the subscribers in a [`TimerSubscribers`] component.
+///
+/// This wraps the event that was triggered on the timer's entity, e.g. `SubscribedTimerEvent<TimerInterval>`.
+#[derive(Event, Debug)]
+pub struct SubscribedTimerEvent<E: TimerEvent> {
+    timer: Entity,
+    event: E,
+}
+
+impl<E: TimerEvent> SubscribedTimerEvent<E> {
+    /// The entity of the timer that triggered this event.
+    pub fn timer(&self) -> Entity {
+        self.timer
+    }
+
+    /// The underlying timer event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+}
+
+fn forward_to_subscribers<E: TimerEvent>(
+    trigger: Trigger<E>,
+    subscribers: Query<&TimerSubscribers>,
+    entities: &Entities,
+    mut commands: Commands,
+) {
+    let timer = trigger.entity();
+    let Ok(subscribers) = subscribers.get(timer) else {
+        return;
+    };
+    for &subscriber in subscribers.iter() {
+        if entities.contains(subscriber) {
+            let event = trigger.event().clone();
+            commands.trigger_targets(SubscribedTimerEvent { timer, event }, subscriber);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+};
+
+use crate::delayed::register_delayed_system;
+
+/// A component that times out an async task held in the component `T` on the same entity.
+///
+/// Tasks spawned on one of bevy's task pools are usually kept in a component (e.g. `LoadLevel(Task<Level>)`) that a
+/// polling system removes once the task completes. `TaskTimeout<T>` waits for that to happen: if `T` is removed before
+/// the timeout elapses, the `TaskTimeout` simply removes itself. Otherwise a [`TimerTimeout`] event is triggered on the
+/// entity, the [`TaskTimeoutBehavior`] is performed, and the `TaskTimeout` is removed.
+///
+/// Like [`InsertAfter`](crate::InsertAfter), each task component type is set up the first time a `TaskTimeout` for it
+/// is added.
+///
+/// ```no_run
+/// # use bevy::{prelude::*, tasks::Task};
+/// # use bevy_mod_observable_timer::*;
+/// # struct Level;
+/// # fn setup(mut commands: Commands, task: Task<Level>) {
+/// #[derive(Component)]
+/// struct LoadLevel(Task<Level>);
+///
+/// commands
+///     .spawn((
+///         LoadLevel(task),
+///         TaskTimeout::<LoadLevel>::from_seconds(10.0).with_behavior(TaskTimeoutBehavior::DropTask),
+///     ))
+///     .observe(|_: Trigger<TimerTimeout>| error!("Loading the level timed out"));
+/// # }
+/// ```
+pub struct TaskTimeout<T: Component> {
+    /// The time remaining until the task times out.
+    pub remaining: Duration,
+    /// What to do if the task times out.
+    pub behavior: TaskTimeoutBehavior,
+    timeout: Duration,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> TaskTimeout<T> {
+    /// Create a new `TaskTimeout` that times out once `timeout` has passed.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            remaining: timeout,
+            behavior: TaskTimeoutBehavior::default(),
+            timeout,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a new `TaskTimeout` that times out after the given number of seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// Sets the [`TaskTimeoutBehavior`].
+    pub fn with_behavior(mut self, behavior: TaskTimeoutBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// The total timeout this was created with.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<T: Component> Clone for TaskTimeout<T> {
+    fn clone(&self) -> Self {
+        Self {
+            remaining: self.remaining,
+            behavior: self.behavior,
+            timeout: self.timeout,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> std::fmt::Debug for TaskTimeout<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskTimeout")
+            .field("remaining", &self.remaining)
+            .field("behavior", &self.behavior)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl<T: Component> Component for TaskTimeout<T> {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_task_timeouts::<T>);
+        });
+    }
+}
+
+/// What a [`TaskTimeout`] does when its task times out, after triggering [`TimerTimeout`].
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum TaskTimeoutBehavior {
+    /// Leave the task running.
+    ///
+    /// This is the default behavior.
+    #[default]
+    None,
+    /// Remove the task component. Dropping a bevy `Task` cancels it.
+    DropTask,
+    /// Despawn the task's entity.
+    DespawnEntity,
+}
+
+/// A timer [`Event`] that is triggered on an entity when its [`TaskTimeout`] elapses before the task completes.
+#[derive(Event, Reflect, Debug)]
+#[reflect(Debug)]
+pub struct TimerTimeout {
+    timeout: Duration,
+}
+
+impl TimerTimeout {
+    /// The timeout that elapsed.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+fn update_task_timeouts<T: Component>(
+    In(delta): In<Duration>,
+    mut timeouts: Query<(Entity, &mut TaskTimeout<T>, Has<T>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut task_timeout, has_task) in timeouts.iter_mut() {
+        if !has_task {
+            // The task completed (or was otherwise removed) in time
+            commands.entity(entity).remove::<TaskTimeout<T>>();
+            continue;
+        }
+
+        let task_timeout = task_timeout.bypass_change_detection();
+        task_timeout.remaining = task_timeout.remaining.saturating_sub(delta);
+        if !task_timeout.remaining.is_zero() {
+            continue;
+        }
+
+        let timeout = task_timeout.timeout;
+        commands.trigger_targets(TimerTimeout { timeout }, entity);
+        match task_timeout.behavior {
+            TaskTimeoutBehavior::None => {
+                commands.entity(entity).remove::<TaskTimeout<T>>();
+            }
+            TaskTimeoutBehavior::DropTask => {
+                commands.entity(entity).remove::<(T, TaskTimeout<T>)>();
+            }
+            TaskTimeoutBehavior::DespawnEntity => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+/// A [`Resource`] that stops timers from triggering [`TimerFinished`](crate::TimerFinished) when they are removed or
+/// despawned while the app is being torn down.
+///
+/// When an app exits, cleanup code often despawns every entity, which cancels each unfinished timer and runs its
+/// observers at a point where they can misbehave, such as writing another [`AppExit`] or despawning entities that are
+/// already gone. While teardown is [active](Self::is_active), timers of every kind that are removed or despawned do so
+/// silently, as with [`cancel_timer_silently()`]. Timers that finish on their own are unaffected.
+///
+/// Teardown can be started by hand with [`Self::begin()`], such as before despawning a whole level, or automatically
+/// once an [`AppExit`] event is sent when [`Self::on_exit`] is set. The latter is enabled with
+/// [`ObservableTimerPlugin::with_exit_teardown()`](crate::ObservableTimerPlugin::with_exit_teardown).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// #[derive(Resource, Default)]
+/// struct Cancellations(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Cancellations>();
+/// world.init_resource::<TimerTeardown>();
+/// world.observe(|_: Trigger<TimerFinished>, mut cancellations: ResMut<Cancellations>| {
+///     cancellations.0 += 1;
+/// });
+///
+/// let entity = world.spawn(ObservableTimer::once_from_seconds(1.0)).id();
+/// world.resource_mut::<TimerTeardown>().begin();
+/// world.despawn(entity);
+/// world.flush();
+/// assert_eq!(world.resource::<Cancellations>().0, 0);
+/// ```
+///
+/// [`cancel_timer_silently()`]: crate::TimerEntityCommandsExt::cancel_timer_silently
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TimerTeardown {
+    /// Whether teardown begins as soon as an [`AppExit`] event is sent. Defaults to `false`.
+    pub on_exit: bool,
+    active: bool,
+}
+
+impl TimerTeardown {
+    /// Creates a `TimerTeardown` that begins once an [`AppExit`] event is sent.
+    pub fn on_exit() -> Self {
+        Self {
+            on_exit: true,
+            active: false,
+        }
+    }
+
+    /// Begins teardown, silencing timers that are removed from now on.
+    pub fn begin(&mut self) {
+        self.active = true;
+    }
+
+    /// Ends teardown, so that removed timers trigger [`TimerFinished`](crate::TimerFinished) again.
+    pub fn end(&mut self) {
+        self.active = false;
+    }
+
+    /// Returns `true` while teardown is in progress.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// Returns `true` if removed timers shouldn't trigger any events.
+///
+/// This also checks for [`AppExit`] events directly, so that timers despawned by the same system that sent the event
+/// are silenced too.
+pub(crate) fn is_tearing_down(world: &World) -> bool {
+    world
+        .get_resource::<TimerTeardown>()
+        .is_some_and(|teardown| {
+            teardown.active
+                || (teardown.on_exit
+                    && world
+                        .get_resource::<Events<AppExit>>()
+                        .is_some_and(|exits| !exits.is_empty()))
+        })
+}
+
+pub(crate) fn begin_teardown_on_exit(
+    teardown: Option<ResMut<TimerTeardown>>,
+    mut exits: EventReader<AppExit>,
+) {
+    let Some(mut teardown) = teardown else {
+        return;
+    };
+    if exits.read().next().is_some() && teardown.on_exit && !teardown.active {
+        teardown.begin();
+    }
+}
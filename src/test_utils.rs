@@ -0,0 +1,164 @@
+//! Helpers for testing timer-driven gameplay without depending on real time.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use bevy::prelude::*;
+//! # use bevy_mod_observable_timer::{test_utils::*, *};
+//! let mut app = timer_test_app();
+//! app.record_timer_events();
+//!
+//! let timer_id = app.world_mut().spawn(ObservableTimer::from_seconds(2, 1.0)).id();
+//! app.advance_timers_in_steps(Duration::from_secs(2), Duration::from_millis(100));
+//!
+//! assert_eq!(
+//!     app.world().resource::<TimerEventLog>().events_for(timer_id),
+//!     [
+//!         RecordedTimerEvent::Started,
+//!         RecordedTimerEvent::Interval(1),
+//!         RecordedTimerEvent::Interval(2),
+//!         RecordedTimerEvent::Finished { cancelled: false },
+//!     ]
+//! );
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    ObservableTimerPlugin, ObservableTimerWorldExt, TimerFinished, TimerInterval, TimerStarted,
+    TimerTickMode,
+};
+
+/// Creates an [`App`] with an [`ObservableTimerPlugin`] in [`TimerTickMode::Manual`], so that timers only advance when
+/// told to by [`TimerTestAppExt`].
+///
+/// The app also has the [`Time`], [`Time<Real>`] and [`Time<Virtual>`] clocks, which [`TimerTestAppExt`] advances
+/// along with the timers.
+pub fn timer_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual))
+        .init_resource::<Time>()
+        .init_resource::<Time<Real>>()
+        .init_resource::<Time<Virtual>>();
+    app
+}
+
+/// An extension trait for driving timers from tests.
+///
+/// Along with the timers, this advances whichever of the [`Time`], [`Time<Real>`] and [`Time<Virtual>`] clocks exist
+/// by the same amount, so that [`CronTimer`](crate::CronTimer) and [`Deadline`](crate::Deadline) fire as they would
+/// in a running app. Like the timers, the clocks ignore [`Time<Virtual>`]'s pausing, speed and maximum delta.
+/// [`FireAt`](crate::FireAt) compares against the system clock, which can't be advanced, so it only fires once its
+/// target has actually passed.
+pub trait TimerTestAppExt {
+    /// Advances every timer by `delta` in a single update.
+    fn advance_timers(&mut self, delta: Duration) -> &mut Self;
+
+    /// Advances every timer by `total`, in updates of at most `step`, as if `total` had passed over several frames.
+    fn advance_timers_in_steps(&mut self, total: Duration, step: Duration) -> &mut Self;
+
+    /// Starts recording timer events into a [`TimerEventLog`] resource.
+    fn record_timer_events(&mut self) -> &mut Self;
+}
+
+impl TimerTestAppExt for App {
+    fn advance_timers(&mut self, delta: Duration) -> &mut Self {
+        advance(self.world_mut(), delta);
+        self
+    }
+
+    fn advance_timers_in_steps(&mut self, total: Duration, step: Duration) -> &mut Self {
+        assert!(!step.is_zero(), "`step` must be greater than zero");
+        let mut remaining = total;
+        while !remaining.is_zero() {
+            let delta = remaining.min(step);
+            advance(self.world_mut(), delta);
+            remaining -= delta;
+        }
+        self
+    }
+
+    fn record_timer_events(&mut self) -> &mut Self {
+        self.init_resource::<TimerEventLog>()
+            .observe(
+                |trigger: Trigger<TimerStarted>, mut log: ResMut<TimerEventLog>| {
+                    log.events
+                        .push((trigger.entity(), RecordedTimerEvent::Started));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TimerInterval>, mut log: ResMut<TimerEventLog>| {
+                    let event = RecordedTimerEvent::Interval(trigger.event().count());
+                    log.events.push((trigger.entity(), event));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TimerFinished>, mut log: ResMut<TimerEventLog>| {
+                    let event = RecordedTimerEvent::Finished {
+                        cancelled: trigger.event().cancelled(),
+                    };
+                    log.events.push((trigger.entity(), event));
+                },
+            )
+    }
+}
+
+/// Advances the clocks that exist in `world` and then every timer by `delta`.
+fn advance(world: &mut World, delta: Duration) {
+    if let Some(mut real) = world.get_resource_mut::<Time<Real>>() {
+        real.advance_by(delta);
+    }
+    if let Some(mut virtual_time) = world.get_resource_mut::<Time<Virtual>>() {
+        virtual_time.advance_by(delta);
+        let generic = virtual_time.as_generic();
+        if let Some(mut time) = world.get_resource_mut::<Time>() {
+            *time = generic;
+        }
+    } else if let Some(mut time) = world.get_resource_mut::<Time>() {
+        time.advance_by(delta);
+    }
+    world.tick_observable_timers(delta);
+}
+
+/// A timer event recorded by [`TimerTestAppExt::record_timer_events()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordedTimerEvent {
+    /// A [`TimerStarted`] event.
+    Started,
+    /// A [`TimerInterval`] event with the given count.
+    Interval(u32),
+    /// A [`TimerFinished`] event.
+    Finished {
+        /// See [`TimerFinished::cancelled()`].
+        cancelled: bool,
+    },
+}
+
+/// A [`Resource`] holding the timer events recorded by [`TimerTestAppExt::record_timer_events()`], in the order they
+/// were triggered.
+#[derive(Resource, Debug, Default)]
+pub struct TimerEventLog {
+    events: Vec<(Entity, RecordedTimerEvent)>,
+}
+
+impl TimerEventLog {
+    /// Every recorded event along with its target entity.
+    pub fn events(&self) -> &[(Entity, RecordedTimerEvent)] {
+        &self.events
+    }
+
+    /// The recorded events that targeted `entity`.
+    pub fn events_for(&self, entity: Entity) -> Vec<RecordedTimerEvent> {
+        self.events
+            .iter()
+            .filter(|(target, _)| *target == entity)
+            .map(|(_, event)| *event)
+            .collect()
+    }
+
+    /// Clears the recorded events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
@@ -0,0 +1,122 @@
+use std::{borrow::Cow, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::ObservableTimer;
+
+/// A component that writes the remaining time of an [`ObservableTimer`] on the same entity into its [`Text`].
+///
+/// Every update after [`ObservableTimerSet`](crate::ObservableTimerSet), the value of the text's first section is
+/// replaced with the [`format`](Self::format) string, with the following placeholders filled in:
+///
+/// - `{h}`: whole hours.
+/// - `{m}` and `{mm}`: minutes, zero-padded to two digits for `{mm}`. These are the minutes within the hour when the
+///   format contains `{h}`, and the total minutes otherwise.
+/// - `{s}` and `{ss}`: seconds, zero-padded to two digits for `{ss}`. These are the seconds within the minute when the
+///   format contains `{m}` or `{mm}`, and the total seconds otherwise.
+/// - `{ms}`: milliseconds within the second, zero-padded to three digits.
+///
+/// Unless the format contains `{ms}`, the remaining time is rounded up to a whole second, so that a countdown shows
+/// `0:01` rather than `0:00` during its final second. Timers that run indefinitely show the time remaining in their
+/// current interval.
+///
+/// Requires the `bevy_text` feature.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     TextBundle::from_section("", TextStyle::default()),
+///     ObservableTimer::once_from_seconds(90.0),
+///     TimerCountdownText::new("{m}:{ss}"),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct TimerCountdownText {
+    /// The format string. Defaults to `"{m}:{ss}"`.
+    pub format: Cow<'static, str>,
+}
+
+impl TimerCountdownText {
+    /// Creates a `TimerCountdownText` with the given format string.
+    pub fn new(format: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            format: format.into(),
+        }
+    }
+
+    /// Formats `remaining` using this component's format string.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_mod_observable_timer::*;
+    /// let countdown = TimerCountdownText::new("{m}:{ss}");
+    /// assert_eq!(countdown.format_remaining(Duration::from_millis(89_500)), "1:30");
+    /// ```
+    pub fn format_remaining(&self, remaining: Duration) -> String {
+        let format = &*self.format;
+        let has_hours = format.contains("{h}");
+        let has_minutes = format.contains("{m}") || format.contains("{mm}");
+        let has_millis = format.contains("{ms}");
+
+        let total_secs = if has_millis || remaining.subsec_nanos() == 0 {
+            remaining.as_secs()
+        } else {
+            remaining.as_secs() + 1
+        };
+        let hours = total_secs / 3600;
+        let minutes = if has_hours {
+            total_secs / 60 % 60
+        } else {
+            total_secs / 60
+        };
+        let seconds = if has_minutes {
+            total_secs % 60
+        } else {
+            total_secs
+        };
+
+        format
+            .replace("{h}", &hours.to_string())
+            .replace("{mm}", &format!("{minutes:02}"))
+            .replace("{m}", &minutes.to_string())
+            .replace("{ss}", &format!("{seconds:02}"))
+            .replace("{s}", &seconds.to_string())
+            .replace("{ms}", &format!("{:03}", remaining.subsec_millis()))
+    }
+}
+
+impl Default for TimerCountdownText {
+    fn default() -> Self {
+        Self::new("{m}:{ss}")
+    }
+}
+
+pub(crate) fn update_timer_countdown_text(
+    mut texts: Query<(&TimerCountdownText, &ObservableTimer, &mut Text)>,
+) {
+    for (countdown, timer, mut text) in texts.iter_mut() {
+        let remaining = timer
+            .remaining()
+            .unwrap_or_else(|| timer.interval_remaining());
+        let value = countdown.format_remaining(remaining);
+
+        // Only touch the text when it actually changes, to avoid needlessly re-laying it out
+        if text
+            .sections
+            .first()
+            .is_some_and(|section| section.value == value)
+        {
+            continue;
+        }
+        match text.sections.first_mut() {
+            Some(section) => section.value = value,
+            None => text
+                .sections
+                .push(TextSection::new(value, TextStyle::default())),
+        }
+    }
+}
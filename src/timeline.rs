@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    dilation::{scale_delta, TimeDilations},
+    update::timer_delta,
+    ObservableTimerSystems, ObservableTimerUpdate, TimerFinishBehavior,
+};
+
+/// Triggers a keyframe's event on the given entity.
+pub(crate) type KeyframeFn = Box<dyn Fn(Entity, &mut Commands) + Send + Sync>;
+
+/// A keyframe in a [`Timeline`].
+struct Keyframe {
+    time: Duration,
+    trigger: KeyframeFn,
+}
+
+/// A component that triggers a sequence of events on its entity at set times.
+///
+/// Each keyframe pairs a time (relative to when the timeline was inserted) with an [`Event`], which is triggered on the
+/// timeline's entity on the update that its time is crossed. Keyframes at the same time are triggered in the order
+/// they were added. Once the timeline's [duration](Self::duration()) has elapsed a [`TimelineFinished`] event is
+/// triggered, and the [`TimerFinishBehavior`] is performed, where [`TimerFinishBehavior::RemoveComponent`] removes the
+/// `Timeline` component, and [`TimerFinishBehavior::ReplaceWith`] replaces it with the given
+/// [`ObservableTimer`](crate::ObservableTimer).
+///
+/// This is a simpler alternative to chaining many timers together for cutscenes and scripted encounters.
+///
+/// For replay scrubbers and editor previews, a timeline can [seek](Self::seek()) to any time, and can run backwards
+/// with a negative [speed](Self::speed). While running backwards, keyframes are triggered again as their times are
+/// crossed in reverse, unless [`Self::reverse_events`] is `false`. A timeline that reaches the start while running
+/// backwards stays there, and one that runs backwards from its end can finish again.
+///
+/// Requires the [`TimelinePlugin`].
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # #[derive(Event, Clone)]
+/// # struct ShowDialogue(&'static str);
+/// # #[derive(Event, Clone)]
+/// # struct SpawnGuards;
+/// # #[derive(Event, Clone)]
+/// # struct CloseGate;
+/// # fn setup(mut commands: Commands) {
+/// commands
+///     .spawn(
+///         Timeline::new()
+///             .at_seconds(0.0, ShowDialogue("Who goes there?"))
+///             .at_seconds(2.0, SpawnGuards)
+///             .at_seconds(5.0, CloseGate)
+///             .with_finish_behavior(TimerFinishBehavior::RemoveComponent),
+///     )
+///     .observe(|trigger: Trigger<ShowDialogue>| { /* ... */ })
+///     .observe(|_: Trigger<SpawnGuards>| { /* ... */ })
+///     .observe(|_: Trigger<CloseGate>| { /* ... */ })
+///     .observe(|_: Trigger<TimelineFinished>| info!("Encounter started"));
+/// # }
+/// ```
+#[derive(Component)]
+pub struct Timeline {
+    keyframes: Vec<Keyframe>,
+    elapsed: Duration,
+    duration: Option<Duration>,
+    next_keyframe: usize,
+    finished: bool,
+    /// The rate at which the timeline runs, where negative values run it backwards. Defaults to `1.0`. The timeline
+    /// stays still while this is infinite or NaN.
+    pub speed: f32,
+    /// Whether keyframes are triggered when their times are crossed while running backwards. Defaults to `true`.
+    pub reverse_events: bool,
+    /// The behavior to perform once the timeline has finished.
+    pub finish_behavior: TimerFinishBehavior,
+}
+
+impl Timeline {
+    /// Creates an empty timeline.
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            elapsed: Duration::ZERO,
+            duration: None,
+            next_keyframe: 0,
+            finished: false,
+            speed: 1.0,
+            reverse_events: true,
+            finish_behavior: TimerFinishBehavior::default(),
+        }
+    }
+
+    /// Adds a keyframe that triggers a clone of `event` once `time` has elapsed.
+    pub fn at<E: Event + Clone>(mut self, time: Duration, event: E) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        let trigger: KeyframeFn = Box::new(move |entity, commands| {
+            commands.trigger_targets(event.clone(), entity);
+        });
+        self.keyframes.insert(index, Keyframe { time, trigger });
+        self
+    }
+
+    /// Adds a keyframe that triggers a clone of `event` once the given number of seconds have elapsed.
+    pub fn at_seconds<E: Event + Clone>(self, seconds: f32, event: E) -> Self {
+        self.at(Duration::from_secs_f32(seconds), event)
+    }
+
+    /// Sets the timeline's duration, which otherwise defaults to the time of its last keyframe.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the rate at which the timeline runs, where negative values run it backwards.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets whether keyframes are triggered when their times are crossed while running backwards.
+    pub fn with_reverse_events(mut self, reverse_events: bool) -> Self {
+        self.reverse_events = reverse_events;
+        self
+    }
+
+    /// Sets the [`TimerFinishBehavior`].
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+
+    /// The total duration of the timeline.
+    pub fn duration(&self) -> Duration {
+        self.duration.unwrap_or_else(|| {
+            self.keyframes
+                .last()
+                .map_or(Duration::ZERO, |keyframe| keyframe.time)
+        })
+    }
+
+    /// The time elapsed since the timeline started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Jumps to the given elapsed time, either forwards or backwards, without triggering any keyframes in between.
+    ///
+    /// Keyframes at exactly `elapsed` are triggered on the next update if the timeline is running forwards. Seeking a
+    /// finished timeline to before its end makes it run again.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # let mut timeline = Timeline::new();
+    /// // Preview the cutscene from the 10 second mark
+    /// timeline.seek(Duration::from_secs(10));
+    /// ```
+    pub fn seek(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+        self.next_keyframe = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time < elapsed);
+        self.finished = self.finished && elapsed >= self.duration();
+    }
+
+    /// The number of keyframes.
+    pub fn len(&self) -> usize {
+        self.keyframes.len()
+    }
+
+    /// Returns `true` if the timeline has no keyframes.
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    /// `true` once the timeline's duration has elapsed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`Timeline`]'s entity once its duration has elapsed.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct TimelineFinished {
+    overshoot: Duration,
+}
+
+impl TimelineFinished {
+    /// The amount of time by which the timeline's duration was overshot on the update that it finished.
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+}
+
+/// A plugin that updates [`Timeline`]s. Requires [`ObservableTimerPlugin`](crate::ObservableTimerPlugin).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # let mut app = App::new();
+/// app.add_plugins((ObservableTimerPlugin::default(), TimelinePlugin));
+/// ```
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TimelineFinished>().add_systems(
+            ObservableTimerUpdate,
+            timer_delta
+                .pipe(update_timelines)
+                .after(ObservableTimerSystems::ApplyFinishBehavior),
+        );
+    }
+}
+
+pub(crate) fn update_timelines(
+    In(delta): In<Duration>,
+    mut timelines: Query<(Entity, &mut Timeline)>,
+    dilations: TimeDilations,
+    mut commands: Commands,
+) {
+    let dilated = dilations.any();
+    for (entity, mut timeline) in timelines.iter_mut() {
+        let delta = if dilated {
+            dilations.delta(entity, delta)
+        } else {
+            delta
+        };
+        if timeline.speed < 0.0 {
+            rewind_timeline(entity, &mut timeline, delta, &mut commands);
+            continue;
+        }
+        if timeline.finished {
+            continue;
+        }
+
+        let timeline = &mut *timeline;
+        timeline.elapsed = timeline
+            .elapsed
+            .saturating_add(scale_delta(delta, f64::from(timeline.speed)));
+        while let Some(keyframe) = timeline.keyframes.get(timeline.next_keyframe) {
+            if keyframe.time > timeline.elapsed {
+                break;
+            }
+            (keyframe.trigger)(entity, &mut commands);
+            timeline.next_keyframe += 1;
+        }
+
+        let Some(overshoot) = timeline.elapsed.checked_sub(timeline.duration()) else {
+            continue;
+        };
+        timeline.finished = true;
+        commands.trigger_targets(TimelineFinished { overshoot }, entity);
+
+        match &timeline.finish_behavior {
+            TimerFinishBehavior::None => {}
+            TimerFinishBehavior::RemoveComponent => {
+                commands.entity(entity).remove::<Timeline>();
+            }
+            TimerFinishBehavior::DespawnEntity => {
+                commands.entity(entity).despawn();
+            }
+            TimerFinishBehavior::DespawnRecursive => {
+                commands.entity(entity).despawn_recursive();
+            }
+            TimerFinishBehavior::ReplaceWith(timer) => {
+                commands
+                    .entity(entity)
+                    .remove::<Timeline>()
+                    .insert(timer.as_ref().clone());
+            }
+        }
+    }
+}
+
+/// Moves a timeline with a negative speed backwards, triggering the keyframes that it crosses in reverse order.
+fn rewind_timeline(
+    entity: Entity,
+    timeline: &mut Timeline,
+    delta: Duration,
+    commands: &mut Commands,
+) {
+    timeline.elapsed = timeline
+        .elapsed
+        .saturating_sub(scale_delta(delta, -f64::from(timeline.speed)));
+    while let Some(previous) = timeline.next_keyframe.checked_sub(1) {
+        let keyframe = &timeline.keyframes[previous];
+        if keyframe.time <= timeline.elapsed {
+            break;
+        }
+        if timeline.reverse_events {
+            (keyframe.trigger)(entity, commands);
+        }
+        timeline.next_keyframe = previous;
+    }
+    if timeline.elapsed < timeline.duration() {
+        timeline.finished = false;
+    }
+}
@@ -0,0 +1,296 @@
+use std::{hash::Hash, marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::{ComponentHooks, ComponentId, StorageType},
+        world::DeferredWorld,
+    },
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::delayed::{register_delayed_system, register_delayed_system_in_world};
+
+/// A value in a [`TtlMap`], along with the time it expires at.
+struct TtlEntry<V> {
+    value: V,
+    expires_at: Duration,
+}
+
+/// A map whose entries each expire after their own time-to-live, triggering an [`EntryExpired`] event.
+///
+/// This suits caches of remote data and temporary claims, such as reserving a tile for a few seconds. A `TtlMap` can
+/// be used either as a [`Component`], in which case `EntryExpired` is triggered on its entity, or as a [`Resource`],
+/// in which case it is triggered globally. As a component it is set up the first time it is added, while as a
+/// resource it must be added with [`TtlMapAppExt::init_ttl_map()`].
+///
+/// Entries expire as the map is advanced along with [`ObservableTimer`](crate::ObservableTimer)s, in the order of
+/// their expiry times. Removing an entry or re-inserting its key never triggers an event.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let mut reservations = TtlMap::new();
+/// reservations.insert(IVec2::new(3, 4), "worker 1", Duration::from_secs(5));
+/// let tiles = world.spawn(reservations).id();
+///
+/// world.tick_observable_timers(Duration::from_secs(4));
+/// let reservations = world.get::<TtlMap<IVec2, &str>>(tiles).unwrap();
+/// assert_eq!(reservations.get(&IVec2::new(3, 4)), Some(&"worker 1"));
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert!(world.get::<TtlMap<IVec2, &str>>(tiles).unwrap().is_empty());
+/// ```
+pub struct TtlMap<K, V> {
+    entries: HashMap<K, TtlEntry<V>>,
+    elapsed: Duration,
+    /// No entries expire before this time, though it may be earlier than the actual next expiry.
+    next_expiry: Duration,
+}
+
+impl<K: Eq + Hash, V> TtlMap<K, V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::default(),
+            elapsed: Duration::ZERO,
+            next_expiry: Duration::MAX,
+        }
+    }
+
+    /// Inserts `value` for `key`, expiring once `ttl` has passed. Returns the previous value for `key`, if any.
+    pub fn insert(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        let expires_at = self.elapsed.saturating_add(ttl);
+        self.next_expiry = self.next_expiry.min(expires_at);
+        self.entries
+            .insert(key, TtlEntry { value, expires_at })
+            .map(|entry| entry.value)
+    }
+
+    /// Sets the time-to-live of the entry for `key` to `ttl`. Returns `false` if there is no such entry.
+    pub fn refresh(&mut self, key: &K, ttl: Duration) -> bool {
+        let Some(entry) = self.entries.get_mut(key) else {
+            return false;
+        };
+        entry.expires_at = self.elapsed.saturating_add(ttl);
+        self.next_expiry = self.next_expiry.min(entry.expires_at);
+        true
+    }
+
+    /// Returns the value for `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Returns the value for `key` mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries.get_mut(key).map(|entry| &mut entry.value)
+    }
+
+    /// Returns the time remaining until the entry for `key` expires.
+    pub fn ttl(&self, key: &K) -> Option<Duration> {
+        self.entries
+            .get(key)
+            .map(|entry| entry.expires_at.saturating_sub(self.elapsed))
+    }
+
+    /// Returns `true` if the map has an entry for `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Removes and returns the value for `key`, without triggering [`EntryExpired`].
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key).map(|entry| entry.value)
+    }
+
+    /// Iterates over every entry in the map, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, entry)| (key, &entry.value))
+    }
+
+    /// The number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry, without triggering [`EntryExpired`].
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.next_expiry = Duration::MAX;
+    }
+
+    /// Advances the map by `delta`, returning the entries that expired in order of their expiry times.
+    fn advance(&mut self, delta: Duration) -> Vec<(K, V)> {
+        self.elapsed += delta;
+        if self.elapsed < self.next_expiry {
+            return Vec::new();
+        }
+
+        let elapsed = self.elapsed;
+        let mut expired: Vec<_> = self
+            .entries
+            .extract_if(|_, entry| entry.expires_at <= elapsed)
+            .collect();
+        expired.sort_by_key(|(_, entry)| entry.expires_at);
+        self.next_expiry = self
+            .entries
+            .values()
+            .map(|entry| entry.expires_at)
+            .min()
+            .unwrap_or(Duration::MAX);
+
+        expired
+            .into_iter()
+            .map(|(key, entry)| (key, entry.value))
+            .collect()
+    }
+}
+
+impl<K: Eq + Hash, V> Default for TtlMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: std::fmt::Debug, V: std::fmt::Debug> std::fmt::Debug for TtlMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(key, entry)| {
+                (
+                    key,
+                    (&entry.value, entry.expires_at.saturating_sub(self.elapsed)),
+                )
+            }))
+            .finish()
+    }
+}
+
+impl<K, V> Component for TtlMap<K, V>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world: DeferredWorld, _: Entity, _: ComponentId| {
+            register_delayed_system::<Self, _>(&mut world, update_ttl_map_components::<K, V>);
+        });
+    }
+}
+
+impl<K, V> Resource for TtlMap<K, V>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+}
+
+/// An [`Event`] triggered when an entry in a [`TtlMap`] expires.
+///
+/// For a `TtlMap` component this is triggered on its entity, and for a `TtlMap` resource it is triggered globally.
+#[derive(Event, Debug)]
+pub struct EntryExpired<K: Send + Sync + 'static, V: Send + Sync + 'static> {
+    key: K,
+    value: V,
+}
+
+impl<K: Send + Sync + 'static, V: Send + Sync + 'static> EntryExpired<K, V> {
+    /// The key of the entry that expired.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// The value of the entry that expired.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+}
+
+/// An extension trait for adding [`TtlMap`] resources.
+pub trait TtlMapAppExt {
+    /// Initializes an empty [`TtlMap<K, V>`] resource, and sets up its expiry.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_observable_timer::*;
+    /// # #[derive(Debug, PartialEq, Eq, Hash)]
+    /// # struct PlayerId(u64);
+    /// # struct ProfileData;
+    /// # let mut app = App::new();
+    /// app.init_ttl_map::<PlayerId, ProfileData>()
+    ///     .observe(|trigger: Trigger<EntryExpired<PlayerId, ProfileData>>| {
+    ///         info!("Profile for {:?} is stale", trigger.event().key());
+    ///     });
+    /// ```
+    fn init_ttl_map<K, V>(&mut self) -> &mut Self
+    where
+        K: Eq + Hash + Send + Sync + 'static,
+        V: Send + Sync + 'static;
+}
+
+impl TtlMapAppExt for App {
+    fn init_ttl_map<K, V>(&mut self) -> &mut Self
+    where
+        K: Eq + Hash + Send + Sync + 'static,
+        V: Send + Sync + 'static,
+    {
+        register_delayed_system_in_world::<TtlMapResource<K, V>, _>(
+            self.world_mut(),
+            update_ttl_map_resource::<K, V>,
+        );
+        self.init_resource::<TtlMap<K, V>>()
+    }
+}
+
+/// Identifies the update system for [`TtlMap`] resources, as opposed to components.
+struct TtlMapResource<K, V>(PhantomData<(K, V)>);
+
+fn update_ttl_map_components<K, V>(
+    In(delta): In<Duration>,
+    mut maps: Query<(Entity, &mut TtlMap<K, V>)>,
+    mut commands: Commands,
+) where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    for (entity, mut map) in maps.iter_mut() {
+        let expired = map.bypass_change_detection().advance(delta);
+        if expired.is_empty() {
+            continue;
+        }
+        map.set_changed();
+        for (key, value) in expired {
+            commands.trigger_targets(EntryExpired { key, value }, entity);
+        }
+    }
+}
+
+fn update_ttl_map_resource<K, V>(
+    In(delta): In<Duration>,
+    map: Option<ResMut<TtlMap<K, V>>>,
+    mut commands: Commands,
+) where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    let Some(mut map) = map else {
+        return;
+    };
+    let expired = map.bypass_change_detection().advance(delta);
+    if expired.is_empty() {
+        return;
+    }
+    map.set_changed();
+    for (key, value) in expired {
+        commands.trigger(EntryExpired { key, value });
+    }
+}
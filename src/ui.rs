@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use crate::ObservableTimer;
+
+/// A component that sizes its UI node to show the progress of a linked [`ObservableTimer`].
+///
+/// Every update after [`ObservableTimerSet`](crate::ObservableTimerSet), the node's width (or height, for
+/// [`ProgressBarAxis::Vertical`]) is set to a percentage of its parent matching the timer's
+/// [fraction](ObservableTimer::fraction()). Place the bar inside a fixed-size container node to get a cooldown or cast
+/// bar without any custom systems. The node is left as it is while the linked entity has no timer.
+///
+/// Requires the `ui` feature.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// # fn setup(mut commands: Commands, cast_timer: Entity) {
+/// commands
+///     .spawn(NodeBundle {
+///         style: Style { width: Val::Px(200.0), height: Val::Px(20.0), ..default() },
+///         ..default()
+///     })
+///     .with_children(|parent| {
+///         parent.spawn((
+///             NodeBundle {
+///                 style: Style { height: Val::Percent(100.0), ..default() },
+///                 background_color: Color::srgb(0.2, 0.6, 1.0).into(),
+///                 ..default()
+///             },
+///             TimerProgressBar::new(cast_timer).with_fill(ProgressBarFill::Elapsed),
+///         ));
+///     });
+/// # }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct TimerProgressBar {
+    /// The entity with the [`ObservableTimer`] to show.
+    pub timer: Entity,
+    /// Which dimension of the node is sized.
+    pub axis: ProgressBarAxis,
+    /// Whether the bar shows elapsed or remaining time.
+    pub fill: ProgressBarFill,
+}
+
+impl TimerProgressBar {
+    /// Creates a horizontal `TimerProgressBar` for the timer on the given entity, which fills up as time elapses.
+    pub fn new(timer: Entity) -> Self {
+        Self {
+            timer,
+            axis: ProgressBarAxis::default(),
+            fill: ProgressBarFill::default(),
+        }
+    }
+
+    /// Sets the [`ProgressBarAxis`].
+    pub fn with_axis(mut self, axis: ProgressBarAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the [`ProgressBarFill`].
+    pub fn with_fill(mut self, fill: ProgressBarFill) -> Self {
+        self.fill = fill;
+        self
+    }
+}
+
+/// The dimension of a [`TimerProgressBar`] node that is sized.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum ProgressBarAxis {
+    /// Size the node's width.
+    ///
+    /// This is the default axis.
+    #[default]
+    Horizontal,
+    /// Size the node's height.
+    Vertical,
+}
+
+/// What a [`TimerProgressBar`] shows.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum ProgressBarFill {
+    /// The bar grows from empty to full as time elapses, e.g. for a cast bar.
+    ///
+    /// This is the default fill.
+    #[default]
+    Elapsed,
+    /// The bar shrinks from full to empty as time elapses, e.g. for a cooldown.
+    Remaining,
+}
+
+pub(crate) fn update_timer_progress_bars(
+    mut bars: Query<(&TimerProgressBar, &mut Style)>,
+    timers: Query<&ObservableTimer>,
+) {
+    for (bar, mut style) in bars.iter_mut() {
+        let Ok(timer) = timers.get(bar.timer) else {
+            continue;
+        };
+        let fraction = match bar.fill {
+            ProgressBarFill::Elapsed => timer.fraction(),
+            ProgressBarFill::Remaining => timer.fraction_remaining(),
+        };
+        let size = Val::Percent(fraction * 100.0);
+
+        // Only touch the style when the size actually changes, to avoid needlessly triggering a layout
+        let current = match bar.axis {
+            ProgressBarAxis::Horizontal => style.width,
+            ProgressBarAxis::Vertical => style.height,
+        };
+        if current == size {
+            continue;
+        }
+        match bar.axis {
+            ProgressBarAxis::Horizontal => style.width = size,
+            ProgressBarAxis::Vertical => style.height = size,
+        }
+    }
+}
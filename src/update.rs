@@ -0,0 +1,878 @@
+use std::{ops::Range, time::Duration};
+
+use bevy::{
+    ecs::{
+        entity::EntityHashMap,
+        observer::TriggerTargets,
+        system::{SystemChangeTick, SystemParam, SystemState},
+    },
+    prelude::*,
+    utils::Parallel,
+};
+
+use crate::{
+    despawn_budget::despawn_finished,
+    dilation::TimeDilations,
+    event_budget::{BudgetedEvent, TimerEventBudget},
+    queue::{QueuedTimer, TimerQueue},
+    ObservableTimer, ObservableTimerUpdate, StopReason, TimerAdjusted, TimerCatchUp,
+    TimerDirection, TimerEventOrder, TimerFinishBehavior, TimerFinished, TimerFraction, TimerGroup,
+    TimerGroups, TimerInterval, TimerIsPaused, TimerProgress, TimerReplaced, TimerRestarted,
+    TimerSecondElapsed, TimerStarted, TimerTag, TimerTickMode, TimerTriggerMode,
+};
+
+pub(crate) fn ticks_automatically(tick_mode: Res<TimerTickMode>) -> bool {
+    *tick_mode != TimerTickMode::Manual
+}
+
+/// The amount that timers are advanced by in the current run of [`ObservableTimerUpdate`].
+#[derive(Resource, Default)]
+pub(crate) struct TimerDelta(Duration);
+
+pub(crate) fn timer_delta(delta: Res<TimerDelta>) -> Duration {
+    delta.0
+}
+
+/// Runs [`ObservableTimerUpdate`], advancing timers as set by the [`TimerTickMode`].
+pub(crate) fn update_timers(world: &mut World) {
+    let delta = match *world.resource::<TimerTickMode>() {
+        TimerTickMode::Delta => world.resource::<Time>().delta(),
+        TimerTickMode::FixedStep(step) => step,
+        TimerTickMode::Manual => return,
+    };
+    run_timer_update(world, delta);
+}
+
+/// Runs [`ObservableTimerUpdate`], advancing timers by `delta`.
+pub(crate) fn run_timer_update(world: &mut World, delta: Duration) {
+    world.insert_resource(TimerDelta(delta));
+    world.run_schedule(ObservableTimerUpdate);
+}
+
+/// The total time that has passed on the timer schedule, which [phase-locked](ObservableTimer::phase_locked) timers
+/// are anchored to.
+#[derive(Resource, Default)]
+pub(crate) struct PhaseClock {
+    elapsed: Duration,
+}
+
+/// Timers that are updated every update.
+type ActiveTimerFilter = (Without<QueuedTimer>, Without<TimerIsPaused>);
+
+/// Paused timers that have changed since the last update.
+type ChangedPausedTimerFilter = (
+    Without<QueuedTimer>,
+    With<TimerIsPaused>,
+    Changed<ObservableTimer>,
+);
+
+/// A timer along with its entity and group.
+type TimerItem = (
+    Entity,
+    &'static mut ObservableTimer,
+    Option<&'static TimerGroup>,
+);
+
+/// The timers updated by [`update_observable_timers`], split by how they are scheduled, along with their dilations,
+/// the clock that phase-locked timers are anchored to, the order for triggering their events and the current change
+/// tick.
+#[derive(SystemParam)]
+pub(crate) struct Timers<'w, 's> {
+    active: Query<'w, 's, TimerItem, ActiveTimerFilter>,
+    changed_paused: Query<'w, 's, TimerItem, ChangedPausedTimerFilter>,
+    queued: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut ObservableTimer,
+            &'static mut QueuedTimer,
+        ),
+    >,
+    dilations: TimeDilations<'w, 's>,
+    phase_clock: Option<ResMut<'w, PhaseClock>>,
+    running: Option<Res<'w, RunningTimers>>,
+    ticks: SystemChangeTick,
+}
+
+/// A [`Resource`] holding the updates of the last timer tick, until [`trigger_timer_events`] and
+/// [`apply_pending_finish_behaviors`] deal with them.
+#[derive(Resource, Default)]
+pub(crate) struct PendingTimerUpdates {
+    updates: TimerUpdates,
+    behaviors: Vec<(TimerFinishBehavior, Entity)>,
+}
+
+/// A [`Resource`] holding every [`ObservableTimer`] that hasn't finished, so that a running timer that is overwritten by
+/// inserting a new one can be reported as stopped with [`StopReason::Replaced`].
+#[derive(Resource, Debug, Default)]
+pub(crate) struct RunningTimers(pub(crate) EntityHashMap<RunningTimer>);
+
+/// What a [`StopReason::Replaced`] event reports about a running timer, as of its last update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RunningTimer {
+    pub(crate) tag: Option<TimerTag>,
+    pub(crate) interval_duration: Duration,
+    pub(crate) direction: TimerDirection,
+}
+
+impl RunningTimer {
+    pub(crate) fn of(timer: &ObservableTimer) -> Self {
+        Self {
+            tag: timer.tag,
+            interval_duration: timer.interval_duration(),
+            direction: timer.direction(),
+        }
+    }
+}
+
+/// The observers of the events that are only triggered for timers when something observes them.
+///
+/// Timer events are triggered for entities alone, so only observers without component targets (`Observer<E, ()>`) can
+/// ever run for them. When there are none for an event type, triggering it is skipped entirely. Entity observers are
+/// spawned by commands, so any that were added along with a timer exist by the time the timer is next updated.
+///
+/// This only tells whether such an observer exists at all, not which entities it watches, so a single entity observer
+/// of an event type has that event triggered for every timer. [`TimerFinished`] isn't included, since the plugin always
+/// observes it. [`TimerStarted`] and [`TimerReplaced`] are only skipped when triggered on the timer update after
+/// insertion, and not when a timer is removed before then.
+#[derive(SystemParam)]
+pub(crate) struct TimerEventObservers<'w, 's> {
+    pub(crate) started: Query<'w, 's, (), With<Observer<TimerStarted, ()>>>,
+    pub(crate) replaced: Query<'w, 's, (), With<Observer<TimerReplaced, ()>>>,
+    restarted: Query<'w, 's, (), With<Observer<TimerRestarted, ()>>>,
+    adjusted: Query<'w, 's, (), With<Observer<TimerAdjusted, ()>>>,
+    intervals: Query<'w, 's, (), With<Observer<TimerInterval, ()>>>,
+    seconds: Query<'w, 's, (), With<Observer<TimerSecondElapsed, ()>>>,
+    progress: Query<'w, 's, (), With<Observer<TimerProgress, ()>>>,
+}
+
+impl TimerEventObservers<'_, '_> {
+    /// Which of the events that are only triggered when observed currently have observers.
+    fn observed(&self) -> ObservedEvents {
+        ObservedEvents {
+            restarted: !self.restarted.is_empty(),
+            adjusted: !self.adjusted.is_empty(),
+            intervals: !self.intervals.is_empty(),
+            seconds: !self.seconds.is_empty(),
+            progress: !self.progress.is_empty(),
+        }
+    }
+}
+
+/// Whether each of the events that are only triggered when observed has any observers. See [`TimerEventObservers`].
+#[derive(Clone, Copy)]
+struct ObservedEvents {
+    restarted: bool,
+    adjusted: bool,
+    intervals: bool,
+    seconds: bool,
+    progress: bool,
+}
+
+pub(crate) fn update_observable_timers(
+    In(delta): In<Duration>,
+    default_catch_up: Option<Res<TimerCatchUp>>,
+    mut timers: Timers,
+    groups: Option<Res<TimerGroups>>,
+    queue: Option<ResMut<TimerQueue>>,
+    mut thread_updates: Local<Parallel<TimerUpdates>>,
+    mut pending: ResMut<PendingTimerUpdates>,
+) {
+    let default_catch_up = default_catch_up.as_deref().copied().unwrap_or_default();
+    let tick_span = info_span!("tick_observable_timers").entered();
+    let dilated = timers.dilations.any();
+    let running = timers.running.as_deref();
+    let now = timers.phase_clock.as_mut().map_or(Duration::ZERO, |clock| {
+        clock.elapsed += delta;
+        clock.elapsed
+    });
+
+    // The amount each timer advances, after its group's speed and dilation, unless it is phase-locked
+    let dilations = &timers.dilations;
+    let groups = groups.as_deref();
+    let timer_delta = |entity, timer: &mut ObservableTimer, group: Option<&TimerGroup>| {
+        if timer.phase_locked {
+            return timer.phase_locked_delta(now, delta);
+        }
+        let delta = TimerGroups::delta(groups, group, delta);
+        if dilated {
+            dilations.delta(entity, delta)
+        } else {
+            delta
+        }
+    };
+
+    // Timers are ticked in parallel, with their events and finish behaviors buffered per-thread
+    timers
+        .active
+        .par_iter_mut()
+        .for_each(|(entity, mut timer, group)| {
+            let delta = timer_delta(entity, &mut timer, group);
+            thread_updates.scope(|updates| {
+                update_timer(
+                    entity,
+                    &mut timer,
+                    delta,
+                    default_catch_up,
+                    running,
+                    updates,
+                );
+                if timer.paused() && timer.queued_intervals == 0 {
+                    updates.paused.push(entity);
+                }
+            });
+        });
+
+    let mut updates = TimerUpdates::default();
+    for thread_updates in thread_updates.iter_mut() {
+        updates.append(thread_updates);
+    }
+
+    // Timers that were unpaused since the last update rejoin the regular update
+    for (entity, mut timer, group) in timers.changed_paused.iter_mut() {
+        if !timer.paused() {
+            let delta = timer_delta(entity, &mut timer, group);
+            updates.unpaused.push(entity);
+            update_timer(
+                entity,
+                &mut timer,
+                delta,
+                default_catch_up,
+                running,
+                &mut updates,
+            );
+        } else {
+            // Paused timers still report restarts and adjustments
+            if timer.pending_restart {
+                timer.take_restart();
+                updates.restarted.push((timer.tag, entity));
+            }
+            if timer.pending_adjustment.is_some() {
+                let adjusted = timer.take_adjustment().unwrap();
+                updates.adjusted.push((adjusted, entity));
+            }
+            updates.record_running(running, entity, &timer);
+        }
+    }
+
+    if let Some(mut queue) = queue {
+        queue.update(
+            delta,
+            timers.ticks.this_run(),
+            timers.queued,
+            default_catch_up,
+            running,
+            &mut updates,
+        );
+    }
+
+    tick_span.exit();
+    pending.updates.append(&mut updates);
+}
+
+pub(crate) fn triggers_immediately(trigger_mode: Option<Res<TimerTriggerMode>>) -> bool {
+    trigger_mode.as_deref() == Some(&TimerTriggerMode::Immediate)
+}
+
+/// The updates recorded by [`update_observable_timers`], along with the observers of their events and the budget for
+/// triggering them.
+#[derive(SystemParam)]
+pub(crate) struct TimerEventTriggers<'w, 's> {
+    pending: ResMut<'w, PendingTimerUpdates>,
+    running: Option<ResMut<'w, RunningTimers>>,
+    observers: TimerEventObservers<'w, 's>,
+    event_budget: Option<ResMut<'w, TimerEventBudget>>,
+    event_order: Option<Res<'w, TimerEventOrder>>,
+    commands: Commands<'w, 's>,
+}
+
+/// Queues commands that trigger the events recorded by [`update_observable_timers`].
+pub(crate) fn trigger_timer_events(mut triggers: TimerEventTriggers) {
+    let _trigger_span = info_span!("queue_timer_events").entered();
+    let updates = std::mem::take(&mut triggers.pending.updates);
+    if let Some(running) = triggers.running.as_deref_mut() {
+        updates.track_running(running);
+    }
+    let by_entity = triggers.event_order.as_deref() == Some(&TimerEventOrder::ByEntity);
+    let behaviors = updates.apply(
+        triggers.observers.observed(),
+        by_entity,
+        triggers.event_budget.as_deref_mut(),
+        &mut triggers.commands,
+    );
+    triggers.pending.behaviors.extend(behaviors);
+}
+
+/// Triggers the events recorded by [`update_observable_timers`] directly on the world, for
+/// [`TimerTriggerMode::Immediate`], so that each observer runs (and its commands are applied) before the next event is
+/// triggered.
+pub(crate) fn trigger_timer_events_immediately(
+    world: &mut World,
+    observers: &mut SystemState<TimerEventObservers<'static, 'static>>,
+) {
+    let _trigger_span = info_span!("trigger_timer_events").entered();
+    let updates = std::mem::take(&mut world.resource_mut::<PendingTimerUpdates>().updates);
+    if let Some(mut running) = world.get_resource_mut::<RunningTimers>() {
+        updates.track_running(&mut running);
+    }
+    let observed = observers.get(world).observed();
+    let by_entity = world.get_resource::<TimerEventOrder>() == Some(&TimerEventOrder::ByEntity);
+    let behaviors = if world.contains_resource::<TimerEventBudget>() {
+        world.resource_scope(|world, mut budget: Mut<TimerEventBudget>| {
+            updates.apply(observed, by_entity, Some(&mut budget), world)
+        })
+    } else {
+        updates.apply(observed, by_entity, None, world)
+    };
+    world
+        .resource_mut::<PendingTimerUpdates>()
+        .behaviors
+        .extend(behaviors);
+}
+
+/// Queues commands that apply the finish behaviors of the timers whose [`TimerFinished`] events have been triggered.
+pub(crate) fn apply_pending_finish_behaviors(
+    mut pending: ResMut<PendingTimerUpdates>,
+    mut commands: Commands,
+) {
+    apply_finish_behaviors(std::mem::take(&mut pending.behaviors), &mut commands);
+}
+
+/// Advance a single timer by `delta` and record any resulting events.
+pub(crate) fn update_timer(
+    entity: Entity,
+    timer: &mut ObservableTimer,
+    delta: Duration,
+    default_catch_up: TimerCatchUp,
+    running: Option<&RunningTimers>,
+    updates: &mut TimerUpdates,
+) {
+    let tick = tick_timer(timer, delta, default_catch_up);
+    updates.record_running(running, entity, timer);
+    let tag = timer.tag;
+
+    if tick.restarted {
+        updates.restarted.push((tag, entity));
+    }
+    if let Some(adjusted) = tick.adjusted {
+        updates.adjusted.push((adjusted, entity));
+    }
+    for count in tick.intervals {
+        updates.intervals.push(((count, tag), entity));
+    }
+    for remaining_secs in tick.seconds.rev() {
+        updates.seconds.push(((remaining_secs, tag), entity));
+    }
+    if let Some(progress) = tick.progress {
+        updates.progress.push((progress, entity));
+    }
+    if let Some(overshoot) = tick.finished {
+        let interval_duration = timer.interval_duration();
+        let direction = timer.direction();
+        updates
+            .finished
+            .push(((overshoot, interval_duration, direction, tag), entity));
+        if timer.finish_behavior != TimerFinishBehavior::None {
+            updates
+                .behaviors
+                .push((timer.finish_behavior.clone(), entity));
+        }
+    }
+}
+
+/// The events that should be triggered after ticking a single timer.
+pub(crate) struct TimerTick {
+    /// Whether to trigger a [`TimerRestarted`] event.
+    pub restarted: bool,
+    /// The [`TimerAdjusted`] event to trigger, if any.
+    pub adjusted: Option<TimerAdjusted>,
+    /// The counts of the [`TimerInterval`] events to trigger.
+    pub intervals: Range<u32>,
+    /// The remaining seconds of the [`TimerSecondElapsed`] events to trigger, which should be triggered in reverse.
+    pub seconds: Range<u32>,
+    /// The [`TimerProgress`] event to trigger, if any.
+    pub progress: Option<TimerProgress>,
+    /// The overshoot of the [`TimerFinished`] event to trigger, if the timer finished.
+    pub finished: Option<Duration>,
+}
+
+/// Advance a single timer by `delta`, returning the events that should be triggered.
+pub(crate) fn tick_timer(
+    timer: &mut ObservableTimer,
+    delta: Duration,
+    default_catch_up: TimerCatchUp,
+) -> TimerTick {
+    // Restarts and adjustments are reported before the timer moves on
+    let restarted = timer.take_restart();
+    let adjusted = timer.take_adjustment();
+
+    // The number of the first interval that hasn't had an event triggered yet
+    let interval_num = timer.elapsed_intervals - timer.queued_intervals + 1;
+
+    // The total remaining time, for reporting second boundaries
+    let remaining_before = timer.remaining();
+
+    // Tick the timer forward
+    timer.tick(delta);
+
+    // Trigger an interval event for every interval we finished this `tick()`, subject to the catch-up policy
+    let pending_intervals = timer.queued_intervals + timer.intervals_this_tick;
+    let (fired_intervals, queued_intervals) = match timer.catch_up.unwrap_or(default_catch_up) {
+        TimerCatchUp::FireAll => (pending_intervals, 0),
+        TimerCatchUp::DropExtra(max) => (pending_intervals.min(max.max(1)), 0),
+        TimerCatchUp::QueueForNextFrame(max) => {
+            let fired_intervals = pending_intervals.min(max.max(1));
+            (fired_intervals, pending_intervals - fired_intervals)
+        }
+    };
+    timer.queued_intervals = queued_intervals;
+
+    // Trigger a second event for every whole second boundary crossed this `tick()`
+    let seconds = match (timer.second_events, remaining_before, timer.remaining()) {
+        (true, Some(before), Some(after)) => whole_seconds_between(after, before),
+        _ => 0..0,
+    };
+
+    let progress = timer.tick_progress(delta).then(|| TimerProgress {
+        fraction: timer.fraction(),
+        remaining: timer.remaining(),
+        tag: timer.tag,
+    });
+
+    // The timer finishes once the event for its final interval has been triggered
+    let finished = (timer.is_done() && timer.queued_intervals == 0 && fired_intervals > 0)
+        .then_some(timer.overshoot);
+
+    TimerTick {
+        restarted,
+        adjusted,
+        intervals: interval_num..interval_num.saturating_add(fired_intervals),
+        seconds,
+        progress,
+        finished,
+    }
+}
+
+/// The overshoot, interval duration, direction and tag of a [`TimerFinished`] event.
+type FinishedKey = (Duration, Duration, TimerDirection, Option<TimerTag>);
+
+/// The events and finish behaviors produced by updating timers.
+///
+/// These are buffered so that identical events for many timers can be triggered with a single call.
+#[derive(Default)]
+pub(crate) struct TimerUpdates {
+    /// Timers that should have [`TimerRestarted`] triggered, with their tags.
+    restarted: Vec<(Option<TimerTag>, Entity)>,
+    /// Running timers whose state differs from their [`RunningTimers`] entry, or that have none.
+    running: Vec<(RunningTimer, Entity)>,
+    /// [`TimerAdjusted`] events, at most one for each timer.
+    adjusted: Vec<(TimerAdjusted, Entity)>,
+    /// [`TimerInterval`] counts and tags, in order for each timer.
+    intervals: Vec<((u32, Option<TimerTag>), Entity)>,
+    /// [`TimerSecondElapsed`] remaining seconds and tags, in order for each timer.
+    seconds: Vec<((u32, Option<TimerTag>), Entity)>,
+    /// [`TimerProgress`] events, at most one for each timer.
+    progress: Vec<(TimerProgress, Entity)>,
+    /// [`TimerFinished`] overshoots, interval durations, directions and tags, at most one for each timer.
+    finished: Vec<(FinishedKey, Entity)>,
+    /// Finish behaviors to apply, at most one for each timer.
+    behaviors: Vec<(TimerFinishBehavior, Entity)>,
+    /// Queued timers that have finished, and should no longer be queued.
+    pub(crate) unqueued: Vec<Entity>,
+    /// Timers that have been paused and should be marked with [`TimerIsPaused`].
+    paused: Vec<Entity>,
+    /// Timers that have been unpaused and should no longer be marked with [`TimerIsPaused`].
+    unpaused: Vec<Entity>,
+}
+
+impl TimerUpdates {
+    /// Move all of the updates in `other` into `self`, leaving `other` empty.
+    fn append(&mut self, other: &mut Self) {
+        self.restarted.append(&mut other.restarted);
+        self.running.append(&mut other.running);
+        self.adjusted.append(&mut other.adjusted);
+        self.intervals.append(&mut other.intervals);
+        self.seconds.append(&mut other.seconds);
+        self.progress.append(&mut other.progress);
+        self.finished.append(&mut other.finished);
+        self.behaviors.append(&mut other.behaviors);
+        self.unqueued.append(&mut other.unqueued);
+        self.paused.append(&mut other.paused);
+        self.unpaused.append(&mut other.unpaused);
+    }
+
+    /// Record the state of a timer that is still running, if it differs from its entry in `running`.
+    pub(crate) fn record_running(
+        &mut self,
+        running: Option<&RunningTimers>,
+        entity: Entity,
+        timer: &ObservableTimer,
+    ) {
+        let Some(running) = running else {
+            return;
+        };
+        let current = RunningTimer::of(timer);
+        if !timer.is_done() && running.0.get(&entity) != Some(&current) {
+            self.running.push((current, entity));
+        }
+    }
+
+    /// Record the state of the timers that are running, including any that are running again after being restarted,
+    /// and which have finished.
+    fn track_running(&self, running: &mut RunningTimers) {
+        for &(current, entity) in &self.running {
+            running.0.insert(entity, current);
+        }
+        for (_, entity) in &self.finished {
+            running.0.remove(entity);
+        }
+    }
+
+    /// Drop the events that nothing observes, so that they are neither sorted nor counted against the budget.
+    fn drop_unobserved(&mut self, observed: ObservedEvents) {
+        if !observed.restarted {
+            self.restarted.clear();
+        }
+        if !observed.adjusted {
+            self.adjusted.clear();
+        }
+        if !observed.intervals {
+            self.intervals.clear();
+        }
+        if !observed.seconds {
+            self.seconds.clear();
+        }
+        if !observed.progress {
+            self.progress.clear();
+        }
+    }
+
+    /// Sort the updates for each timer by its entity, keeping the order of each timer's own updates.
+    ///
+    /// Intervals then end up sorted by entity and count, and seconds by entity and descending remaining seconds.
+    fn sort_by_entity(&mut self) {
+        self.restarted.sort_by_key(|(_, entity)| *entity);
+        self.adjusted.sort_by_key(|(_, entity)| *entity);
+        self.intervals.sort_by_key(|(_, entity)| *entity);
+        self.seconds.sort_by_key(|(_, entity)| *entity);
+        self.progress.sort_by_key(|(_, entity)| *entity);
+        self.finished.sort_by_key(|(_, entity)| *entity);
+        self.behaviors.sort_by_key(|(_, entity)| *entity);
+        self.unqueued.sort_unstable();
+        self.paused.sort_unstable();
+        self.unpaused.sort_unstable();
+    }
+
+    /// Trigger every recorded event through `sink`, returning the finish behaviors to apply once they have been
+    /// triggered.
+    ///
+    /// Each kind of event is triggered in turn (restarts, adjustments, intervals, then seconds, progress, and finally
+    /// finished), so the events for each individual timer keep their usual order. Events with identical contents are
+    /// triggered once for all of their targets. Events that nothing observes aren't triggered at all, except for
+    /// [`TimerFinished`], which is always observed by the plugin itself.
+    ///
+    /// With `by_entity` (see [`TimerEventOrder::ByEntity`]), every kind of event is sorted by entity alone, and only
+    /// consecutive identical events are triggered together. Otherwise each kind is sorted by its contents, so that all
+    /// identical events are grouped. Either way each buffer is sorted once.
+    ///
+    /// With a [`TimerEventBudget`] that this would exceed, the events are queued in the budget instead.
+    fn apply(
+        mut self,
+        observed: ObservedEvents,
+        by_entity: bool,
+        budget: Option<&mut TimerEventBudget>,
+        sink: &mut impl TimerEventSink,
+    ) -> Vec<(TimerFinishBehavior, Entity)> {
+        for entity in std::mem::take(&mut self.unqueued) {
+            sink.remove::<QueuedTimer>(entity);
+        }
+        for entity in std::mem::take(&mut self.paused) {
+            sink.insert(entity, TimerIsPaused);
+        }
+        for entity in std::mem::take(&mut self.unpaused) {
+            sink.remove::<TimerIsPaused>(entity);
+        }
+
+        self.drop_unobserved(observed);
+        if by_entity {
+            self.sort_by_entity();
+        }
+
+        if let Some(budget) = budget {
+            if budget.exceeded_by(self.event_count()) {
+                return self.apply_budgeted(budget, sink);
+            }
+        }
+
+        if !by_entity && !self.restarted.is_empty() {
+            self.restarted.sort_unstable();
+        }
+        for (tag, targets) in group_targets(self.restarted) {
+            sink.trigger_targets(TimerRestarted { tag }, targets);
+        }
+        for (adjusted, entity) in self.adjusted {
+            sink.trigger_targets(adjusted, entity);
+        }
+
+        // Interval counts ascend and remaining seconds descend for each timer, so sorting keeps their order intact
+        if !by_entity && !self.intervals.is_empty() {
+            self.intervals.sort_unstable();
+        }
+        for ((count, tag), targets) in group_targets(self.intervals) {
+            sink.trigger_targets(TimerInterval { count, tag }, targets);
+        }
+
+        if !by_entity && !self.seconds.is_empty() {
+            self.seconds
+                .sort_unstable_by(|((a, a_tag), a_entity), ((b, b_tag), b_entity)| {
+                    b.cmp(a).then(a_tag.cmp(b_tag)).then(a_entity.cmp(b_entity))
+                });
+        }
+        for ((remaining_secs, tag), targets) in group_targets(self.seconds) {
+            let elapsed = TimerSecondElapsed {
+                remaining_secs,
+                tag,
+            };
+            sink.trigger_targets(elapsed, targets);
+        }
+
+        for (progress, entity) in self.progress {
+            sink.trigger_targets(progress, entity);
+        }
+
+        if !by_entity && !self.finished.is_empty() {
+            self.finished.sort_unstable();
+        }
+        for ((overshoot, interval_duration, direction, tag), targets) in
+            group_targets(self.finished)
+        {
+            let finished = TimerFinished {
+                reason: StopReason::Finished,
+                condition_met: false,
+                overshoot,
+                interval_duration,
+                direction,
+                tag,
+            };
+            sink.trigger_finished(finished, targets);
+        }
+
+        self.behaviors
+    }
+
+    /// The number of events that [`Self::apply()`] would trigger for individual timers.
+    fn event_count(&self) -> usize {
+        self.restarted.len()
+            + self.adjusted.len()
+            + self.intervals.len()
+            + self.seconds.len()
+            + self.progress.len()
+            + self.finished.len()
+    }
+
+    /// Queue every recorded event in `budget`, in the same order as [`Self::apply()`], then trigger as many queued
+    /// events as the budget allows, returning the finish behaviors of the timers whose events were triggered.
+    fn apply_budgeted(
+        self,
+        budget: &mut TimerEventBudget,
+        sink: &mut impl TimerEventSink,
+    ) -> Vec<(TimerFinishBehavior, Entity)> {
+        budget.queue(
+            self.restarted
+                .into_iter()
+                .map(|(tag, entity)| (BudgetedEvent::Restarted(TimerRestarted { tag }), entity)),
+        );
+        budget.queue(
+            self.adjusted
+                .into_iter()
+                .map(|(adjusted, entity)| (BudgetedEvent::Adjusted(adjusted), entity)),
+        );
+        budget.queue(self.intervals.into_iter().map(|((count, tag), entity)| {
+            (
+                BudgetedEvent::Interval(TimerInterval { count, tag }),
+                entity,
+            )
+        }));
+        budget.queue(
+            self.seconds
+                .into_iter()
+                .map(|((remaining_secs, tag), entity)| {
+                    let elapsed = TimerSecondElapsed {
+                        remaining_secs,
+                        tag,
+                    };
+                    (BudgetedEvent::Second(elapsed), entity)
+                }),
+        );
+        budget.queue(
+            self.progress
+                .into_iter()
+                .map(|(progress, entity)| (BudgetedEvent::Progress(progress), entity)),
+        );
+
+        // Finish behaviors are recorded in the same order as the finished events they follow
+        let mut behaviors = self.behaviors.into_iter().peekable();
+        budget.queue(self.finished.into_iter().map(
+            |((overshoot, interval_duration, direction, tag), entity)| {
+                let finished = TimerFinished {
+                    reason: StopReason::Finished,
+                    condition_met: false,
+                    overshoot,
+                    interval_duration,
+                    direction,
+                    tag,
+                };
+                let behavior = behaviors
+                    .next_if(|(_, target)| *target == entity)
+                    .map_or(TimerFinishBehavior::None, |(behavior, _)| behavior);
+                (BudgetedEvent::Finished(finished, behavior), entity)
+            },
+        ));
+
+        // Events for entities that have been despawned while queued are dropped
+        let mut behaviors = Vec::new();
+        for (event, entity) in budget.take() {
+            if !sink.contains(entity) {
+                continue;
+            }
+            match event {
+                BudgetedEvent::Restarted(event) => sink.trigger_targets(event, entity),
+                BudgetedEvent::Adjusted(event) => sink.trigger_targets(event, entity),
+                BudgetedEvent::Interval(event) => sink.trigger_targets(event, entity),
+                BudgetedEvent::Second(event) => sink.trigger_targets(event, entity),
+                BudgetedEvent::Progress(event) => sink.trigger_targets(event, entity),
+                BudgetedEvent::Finished(event, behavior) => {
+                    sink.trigger_finished(event, vec![entity]);
+                    behaviors.push((behavior, entity));
+                }
+            }
+        }
+        behaviors
+    }
+}
+
+/// Where [`TimerUpdates::apply()`] triggers events and updates timer markers: queued as [`Commands`] for
+/// [`TimerTriggerMode::Deferred`], or directly on the [`World`] for [`TimerTriggerMode::Immediate`].
+///
+/// Like applying the queued commands, the world flushes the commands of observers after each change, so observers see
+/// the effects of earlier events in both modes.
+trait TimerEventSink {
+    fn trigger_targets<E: Event>(&mut self, event: E, targets: impl TriggerTargets + 'static);
+
+    /// Triggers a [`TimerFinished`] event for timers that have finished.
+    fn trigger_finished(&mut self, finished: TimerFinished, targets: Vec<Entity>);
+
+    fn insert<C: Component>(&mut self, entity: Entity, component: C);
+
+    fn remove<C: Component>(&mut self, entity: Entity);
+
+    fn contains(&mut self, entity: Entity) -> bool;
+}
+
+impl TimerEventSink for Commands<'_, '_> {
+    fn trigger_targets<E: Event>(&mut self, event: E, targets: impl TriggerTargets + 'static) {
+        Commands::trigger_targets(self, event, targets);
+    }
+
+    fn trigger_finished(&mut self, finished: TimerFinished, targets: Vec<Entity>) {
+        #[cfg(feature = "rollback")]
+        self.add(move |world: &mut World| {
+            crate::rollback::trigger_finished(world, finished, targets);
+        });
+        #[cfg(not(feature = "rollback"))]
+        Commands::trigger_targets(self, finished, targets);
+    }
+
+    fn insert<C: Component>(&mut self, entity: Entity, component: C) {
+        self.entity(entity).insert(component);
+    }
+
+    fn remove<C: Component>(&mut self, entity: Entity) {
+        self.entity(entity).remove::<C>();
+    }
+
+    fn contains(&mut self, entity: Entity) -> bool {
+        self.get_entity(entity).is_some()
+    }
+}
+
+impl TimerEventSink for World {
+    fn trigger_targets<E: Event>(&mut self, event: E, targets: impl TriggerTargets + 'static) {
+        World::trigger_targets(self, event, targets);
+        self.flush();
+    }
+
+    fn trigger_finished(&mut self, finished: TimerFinished, targets: Vec<Entity>) {
+        #[cfg(feature = "rollback")]
+        crate::rollback::trigger_finished(self, finished, targets);
+        #[cfg(not(feature = "rollback"))]
+        World::trigger_targets(self, finished, targets);
+        self.flush();
+    }
+
+    fn insert<C: Component>(&mut self, entity: Entity, component: C) {
+        if let Some(mut entity) = self.get_entity_mut(entity) {
+            entity.insert(component);
+        }
+        self.flush();
+    }
+
+    fn remove<C: Component>(&mut self, entity: Entity) {
+        if let Some(mut entity) = self.get_entity_mut(entity) {
+            entity.remove::<C>();
+        }
+        self.flush();
+    }
+
+    fn contains(&mut self, entity: Entity) -> bool {
+        self.get_entity(entity).is_some()
+    }
+}
+
+/// Queue commands that apply the finish behaviors of timers that have finished.
+fn apply_finish_behaviors(behaviors: Vec<(TimerFinishBehavior, Entity)>, commands: &mut Commands) {
+    let mut despawns = Vec::new();
+    for (behavior, entity) in behaviors {
+        match behavior {
+            TimerFinishBehavior::None => {}
+            TimerFinishBehavior::RemoveComponent => {
+                commands.entity(entity).remove::<ObservableTimer>();
+            }
+            TimerFinishBehavior::DespawnEntity => despawns.push((entity, false)),
+            TimerFinishBehavior::DespawnRecursive => despawns.push((entity, true)),
+            TimerFinishBehavior::ReplaceWith(timer) => {
+                commands.entity(entity).insert(*timer);
+            }
+        }
+    }
+    if !despawns.is_empty() {
+        commands.add(move |world: &mut World| despawn_finished(world, despawns));
+    }
+}
+
+/// Groups runs of equal keys in `sorted` together with all of their targets.
+fn group_targets<K: PartialEq>(sorted: Vec<(K, Entity)>) -> Vec<(K, Vec<Entity>)> {
+    let mut groups: Vec<(K, Vec<Entity>)> = Vec::new();
+    for (key, entity) in sorted {
+        match groups.last_mut() {
+            Some((last_key, targets)) if *last_key == key => targets.push(entity),
+            _ => groups.push((key, vec![entity])),
+        }
+    }
+    groups
+}
+
+pub(crate) fn update_timer_fractions(mut timers: Query<(&ObservableTimer, &mut TimerFraction)>) {
+    for (timer, mut fraction) in timers.iter_mut() {
+        fraction.set_if_neq(TimerFraction(timer.fraction()));
+    }
+}
+
+/// The whole, non-zero second values `s` such that `after <= s < before`.
+fn whole_seconds_between(after: Duration, before: Duration) -> Range<u32> {
+    fn ceil_secs(duration: Duration) -> u32 {
+        (duration.as_secs() + u64::from(duration.subsec_nanos() > 0)) as u32
+    }
+    ceil_secs(after).max(1)..ceil_secs(before)
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::TimerTag;
+
+/// A timer component that must be [fed](Self::feed()) regularly, for detecting things that have stalled.
+///
+/// If the watchdog isn't fed within its [timeout](Self::timeout), a [`WatchdogExpired`] event is triggered on its
+/// entity. Feeding it resets the countdown without triggering anything. Once expired, it stays expired and triggers
+/// nothing further until it is fed again. This suits detecting stalled AI behaviors, missed network heartbeats and
+/// softlocks.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_mod_observable_timer::*;
+/// let mut world = World::new();
+/// let connection = world.spawn(Watchdog::from_seconds(5.0)).id();
+///
+/// world.tick_observable_timers(Duration::from_secs(4));
+/// world.get_mut::<Watchdog>(connection).unwrap().feed();
+/// world.tick_observable_timers(Duration::from_secs(4));
+/// assert!(!world.get::<Watchdog>(connection).unwrap().is_expired());
+///
+/// world.tick_observable_timers(Duration::from_secs(1));
+/// assert!(world.get::<Watchdog>(connection).unwrap().is_expired());
+/// ```
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct Watchdog {
+    /// How long the watchdog may go without being fed before it expires.
+    pub timeout: Duration,
+    /// The watchdog's [tag](TimerTag), which is included in its events. Defaults to `None`.
+    pub tag: Option<TimerTag>,
+    since_fed: Duration,
+    expired: bool,
+}
+
+impl Watchdog {
+    /// Creates a watchdog that expires if it isn't fed within `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            tag: None,
+            since_fed: Duration::ZERO,
+            expired: false,
+        }
+    }
+
+    /// Creates a watchdog that expires if it isn't fed within the given number of seconds.
+    pub fn from_seconds(timeout_seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(timeout_seconds))
+    }
+
+    /// Sets the watchdog's [tag](TimerTag), which is included in its events.
+    pub fn with_tag(mut self, tag: TimerTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Resets the countdown, without triggering any events. This also revives an expired watchdog.
+    pub fn feed(&mut self) {
+        self.since_fed = Duration::ZERO;
+        self.expired = false;
+    }
+
+    /// The time since the watchdog was last fed.
+    pub fn since_fed(&self) -> Duration {
+        self.since_fed
+    }
+
+    /// The time remaining until the watchdog expires.
+    pub fn remaining(&self) -> Duration {
+        self.timeout.saturating_sub(self.since_fed)
+    }
+
+    /// Returns `true` once the watchdog has expired, until it is fed again.
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+}
+
+/// A timer [`Event`] that is triggered on a [`Watchdog`]'s entity when it isn't fed within its timeout.
+#[derive(Event, Reflect, Debug, Clone)]
+#[reflect(Debug)]
+pub struct WatchdogExpired {
+    tag: Option<TimerTag>,
+}
+
+impl WatchdogExpired {
+    /// The [tag](TimerTag) of the watchdog that triggered this event.
+    pub fn tag(&self) -> Option<TimerTag> {
+        self.tag
+    }
+}
+
+pub(crate) fn update_watchdogs(
+    In(delta): In<Duration>,
+    mut watchdogs: Query<(Entity, &mut Watchdog)>,
+    mut commands: Commands,
+) {
+    for (entity, mut watchdog) in watchdogs.iter_mut() {
+        if watchdog.expired {
+            continue;
+        }
+
+        watchdog.since_fed += delta;
+        if watchdog.since_fed >= watchdog.timeout {
+            watchdog.expired = true;
+            commands.trigger_targets(WatchdogExpired { tag: watchdog.tag }, entity);
+        }
+    }
+}
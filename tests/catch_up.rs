@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Events {
+    intervals: u32,
+    finished: u32,
+}
+
+fn app(plugin: ObservableTimerPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins(plugin.with_tick_mode(TimerTickMode::Manual))
+        .init_resource::<Events>()
+        .observe(|_: Trigger<TimerInterval>, mut events: ResMut<Events>| events.intervals += 1)
+        .observe(|_: Trigger<TimerFinished>, mut events: ResMut<Events>| events.finished += 1);
+    app
+}
+
+fn events(app: &App) -> (u32, u32) {
+    let events = app.world().resource::<Events>();
+    (events.intervals, events.finished)
+}
+
+#[test]
+fn fire_all_triggers_every_completed_interval() {
+    let mut app = app(ObservableTimerPlugin::default());
+    app.world_mut()
+        .spawn(ObservableTimer::indefinite(Duration::from_millis(100)));
+
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(events(&app), (10, 0));
+}
+
+#[test]
+fn drop_extra_skips_events_but_counts_intervals() {
+    let mut app = app(ObservableTimerPlugin::default());
+    let timer = app
+        .world_mut()
+        .spawn(
+            ObservableTimer::indefinite(Duration::from_millis(100))
+                .with_catch_up(TimerCatchUp::DropExtra(3)),
+        )
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(events(&app), (3, 0));
+    let timer = app.world().get::<ObservableTimer>(timer).unwrap();
+    assert_eq!(timer.elapsed_intervals(), 10);
+    assert_eq!(timer.queued_intervals(), 0);
+}
+
+#[test]
+fn queue_for_next_frame_delays_finishing_until_drained() {
+    let mut app = app(ObservableTimerPlugin::default());
+    let timer = app
+        .world_mut()
+        .spawn(
+            ObservableTimer::from_seconds(10, 0.25)
+                .with_catch_up(TimerCatchUp::QueueForNextFrame(4)),
+        )
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(3));
+    assert_eq!(events(&app), (4, 0));
+    assert_eq!(
+        app.world()
+            .get::<ObservableTimer>(timer)
+            .unwrap()
+            .queued_intervals(),
+        6
+    );
+
+    app.tick_observable_timers(Duration::ZERO);
+    assert_eq!(events(&app), (8, 0));
+    assert!(app.world().get_entity(timer).is_some());
+
+    app.tick_observable_timers(Duration::ZERO);
+    assert_eq!(events(&app), (10, 1));
+    assert!(app.world().get_entity(timer).is_none());
+}
+
+#[test]
+fn timers_use_the_plugin_policy_by_default() {
+    let mut app = app(ObservableTimerPlugin::default().with_catch_up(TimerCatchUp::DropExtra(2)));
+    app.world_mut()
+        .spawn(ObservableTimer::indefinite(Duration::from_millis(100)));
+    app.world_mut().spawn(
+        ObservableTimer::indefinite(Duration::from_millis(100))
+            .with_catch_up(TimerCatchUp::FireAll),
+    );
+
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(events(&app), (12, 0));
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Resource, Default)]
+struct Intervals(u32);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        CompactTimerPlugin,
+    ))
+    .init_resource::<Intervals>()
+    .observe(|_: Trigger<TimerInterval>, mut intervals: ResMut<Intervals>| intervals.0 += 1);
+    app
+}
+
+#[test]
+fn long_intervals_are_clamped_below_half_a_clock_cycle() {
+    let timer = CompactTimer::once(DAY * 40);
+    assert!(timer.interval() < DAY * 25);
+
+    let mut app = app();
+    let world = app.world_mut();
+    let entity = world.spawn(timer).id();
+    world.tick_observable_timers(Duration::from_millis(1));
+    assert!(world.get::<CompactTimer>(entity).is_some());
+
+    world.tick_observable_timers(timer.interval());
+    assert!(world.get::<CompactTimer>(entity).is_none());
+}
+
+#[test]
+fn repeating_timers_keep_time_across_clock_wraparound() {
+    let mut app = app();
+    let world = app.world_mut();
+    let entity = world.spawn(CompactTimer::repeating(DAY * 10)).id();
+
+    // The millisecond clock wraps around after about 49.7 days
+    for _ in 0..6 {
+        world.tick_observable_timers(DAY * 10);
+    }
+    assert_eq!(world.resource::<Intervals>().0, 6);
+    assert_eq!(
+        world
+            .get::<CompactTimer>(entity)
+            .unwrap()
+            .elapsed_intervals(),
+        6
+    );
+
+    world.tick_observable_timers(DAY * 10 - Duration::from_millis(1));
+    assert_eq!(world.resource::<Intervals>().0, 6);
+    world.tick_observable_timers(Duration::from_millis(1));
+    assert_eq!(world.resource::<Intervals>().0, 7);
+}
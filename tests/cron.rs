@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+const MINUTE: Duration = Duration::from_secs(60);
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Resource, Default)]
+struct Intervals(Vec<u32>);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        CronTimerPlugin,
+    ))
+    .init_resource::<Time<Virtual>>()
+    .init_resource::<Intervals>()
+    .observe(
+        |trigger: Trigger<TimerInterval>, mut intervals: ResMut<Intervals>| {
+            intervals.0.push(trigger.event().count());
+        },
+    );
+    app
+}
+
+/// Advances the virtual clock to `elapsed` and runs a timer update.
+fn update_at(world: &mut World, elapsed: Duration) {
+    let mut time = world.resource_mut::<Time<Virtual>>();
+    let delta = elapsed - time.elapsed();
+    time.advance_by(delta);
+    world.tick_observable_timers(delta);
+}
+
+/// The first time `expression` triggers after the clock reads `now`.
+fn next_after(expression: &str, now: Duration) -> Option<Duration> {
+    let mut app = app();
+    let world = app.world_mut();
+    let entity = world.spawn(CronTimer::new(expression).unwrap()).id();
+    update_at(world, now);
+    world.get::<CronTimer>(entity).unwrap().next()
+}
+
+#[test]
+fn every_triggers_on_multiples_of_the_period() {
+    assert_eq!(next_after("every 5 minutes", MINUTE * 7), Some(MINUTE * 10));
+    assert_eq!(
+        next_after("every 30 seconds", MINUTE),
+        Some(MINUTE + Duration::from_secs(30))
+    );
+    assert_eq!(next_after("every 2 days", DAY), Some(DAY * 2));
+    assert_eq!(
+        next_after("every 10 secs", Duration::from_secs(5)),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(next_after("every 15 mins", MINUTE * 15), Some(MINUTE * 30));
+}
+
+#[test]
+fn every_without_a_count_uses_a_single_unit() {
+    assert_eq!(next_after("every hour", MINUTE), Some(HOUR));
+    assert_eq!(
+        next_after("every minute", Duration::from_secs(1)),
+        Some(MINUTE)
+    );
+    assert_eq!(next_after("every day", HOUR), Some(DAY));
+    assert_eq!(
+        next_after("every second", Duration::ZERO),
+        Some(Duration::from_secs(1))
+    );
+}
+
+#[test]
+fn at_minutes_triggers_every_hour() {
+    assert_eq!(next_after("at :00 and :30", MINUTE * 10), Some(MINUTE * 30));
+    assert_eq!(next_after("at :00 and :30", MINUTE * 40), Some(HOUR));
+    assert_eq!(
+        next_after("at :15, :45", HOUR + MINUTE * 50),
+        Some(HOUR * 2 + MINUTE * 15)
+    );
+}
+
+#[test]
+fn at_times_trigger_every_day() {
+    assert_eq!(next_after("at 08:00", HOUR), Some(HOUR * 8));
+    assert_eq!(next_after("at 08:00", HOUR * 9), Some(DAY + HOUR * 8));
+    assert_eq!(
+        next_after("at 06:00 and 18:30", HOUR * 7),
+        Some(HOUR * 18 + MINUTE * 30)
+    );
+    assert_eq!(
+        next_after("at 06:00 and 18:30", HOUR * 19),
+        Some(DAY + HOUR * 6)
+    );
+}
+
+#[test]
+fn expressions_ignore_case_and_surrounding_whitespace() {
+    assert_eq!(next_after("  Every 5 Minutes ", MINUTE), Some(MINUTE * 5));
+    assert_eq!(next_after("AT :30", Duration::ZERO), Some(MINUTE * 30));
+}
+
+#[test]
+fn invalid_expressions_are_rejected() {
+    for expression in [
+        "",
+        "whenever",
+        "every",
+        "every 0 minutes",
+        "every five minutes",
+        "every -5 minutes",
+        "every 5 fortnights",
+        "every 5 minutes please",
+        "at",
+        "at 8",
+        "at noon",
+        "at :60",
+        "at 24:00",
+        "at 08:xx",
+        "at :00 and 08:00",
+        "every 999999999999999999 days",
+    ] {
+        let error = CronTimer::new(expression).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("invalid cron timer schedule `{expression}`")
+        );
+    }
+}
+
+#[test]
+fn schedules_past_the_end_of_time_never_trigger() {
+    let mut app = app();
+    let world = app.world_mut();
+    let entity = world.spawn(CronTimer::new("every 2 days").unwrap()).id();
+    update_at(world, Duration::MAX);
+    update_at(world, Duration::MAX);
+    assert_eq!(world.get::<CronTimer>(entity).unwrap().next(), None);
+    assert!(world.resource::<Intervals>().0.is_empty());
+}
+
+#[test]
+fn passing_several_occurrences_triggers_once() {
+    let mut app = app();
+    let world = app.world_mut();
+    let entity = world.spawn(CronTimer::new("every minute").unwrap()).id();
+    update_at(world, Duration::ZERO);
+    update_at(world, MINUTE * 5 + Duration::from_secs(1));
+    update_at(world, MINUTE * 6);
+    assert_eq!(world.resource::<Intervals>().0, [1, 2]);
+    assert_eq!(world.get::<CronTimer>(entity).unwrap().count(), 2);
+}
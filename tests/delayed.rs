@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Event)]
+struct Fire(usize);
+
+#[derive(Resource, Default)]
+struct Fired(Vec<usize>);
+
+#[derive(Resource)]
+struct Fuses([Entity; 2]);
+
+#[test]
+fn despawning_a_due_fuse_cancels_it() {
+    let mut world = World::new();
+    world.init_resource::<Fired>();
+    let delay = Duration::from_secs(1);
+    let fuses = [
+        world.spawn(TriggerAfter::new(delay, Fire(0))).id(),
+        world.spawn(TriggerAfter::new(delay, Fire(1))).id(),
+    ];
+    world.insert_resource(Fuses(fuses));
+
+    // Whichever fuse goes off first defuses the other
+    world.observe(
+        |trigger: Trigger<Fire>,
+         mut fired: ResMut<Fired>,
+         fuses: Res<Fuses>,
+         mut commands: Commands| {
+            let index = trigger.event().0;
+            fired.0.push(index);
+            commands.entity(fuses.0[1 - index]).despawn();
+        },
+    );
+
+    world.tick_observable_timers(delay);
+    assert_eq!(world.resource::<Fired>().0.len(), 1);
+}
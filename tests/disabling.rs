@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Component)]
+struct Disabled;
+
+#[derive(Component)]
+struct Hidden;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        ObservableTimerPlugin::default()
+            .with_disabling_component::<Disabled>()
+            .with_disabling_component::<Hidden>(),
+    ));
+    app
+}
+
+fn paused(app: &App, entity: Entity) -> bool {
+    app.world().get::<ObservableTimer>(entity).unwrap().paused()
+}
+
+#[test]
+fn counts_disabling_components_inserted_together() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(ObservableTimer::indefinite_from_seconds(1.0))
+        .id();
+    app.world_mut()
+        .entity_mut(entity)
+        .insert((Disabled, Hidden));
+    app.update();
+    assert!(paused(&app, entity));
+
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    app.update();
+    assert!(paused(&app, entity));
+    assert!(app.world().get::<TimerDisabled>(entity).is_some());
+
+    app.world_mut().entity_mut(entity).remove::<Hidden>();
+    app.update();
+    assert!(!paused(&app, entity));
+    assert!(app.world().get::<TimerDisabled>(entity).is_none());
+}
+
+#[test]
+fn pauses_timers_inserted_on_disabled_entities() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(Disabled).id();
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(ObservableTimer::indefinite_from_seconds(1.0));
+    app.update();
+    assert!(paused(&app, entity));
+
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    app.update();
+    assert!(!paused(&app, entity));
+}
+
+#[test]
+fn keeps_timers_paused_before_disabling_paused() {
+    let mut app = app();
+    let mut timer = ObservableTimer::indefinite_from_seconds(1.0);
+    timer.pause();
+    let entity = app.world_mut().spawn(timer).id();
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    app.update();
+    assert!(paused(&app, entity));
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Intervals(Vec<Entity>);
+
+fn app(per_update: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::default()
+            .with_tick_mode(TimerTickMode::Manual)
+            .with_event_budget(per_update),
+    )
+    .init_resource::<Intervals>()
+    .observe(
+        |trigger: Trigger<TimerInterval>, mut intervals: ResMut<Intervals>| {
+            intervals.0.push(trigger.entity());
+        },
+    );
+    app
+}
+
+fn intervals_of(app: &App, timer: Entity) -> usize {
+    let intervals = &app.world().resource::<Intervals>().0;
+    intervals.iter().filter(|&&entity| entity == timer).count()
+}
+
+#[test]
+fn events_over_budget_are_triggered_on_following_updates_in_order() {
+    let mut app = app(2);
+    let first = app
+        .world_mut()
+        .spawn(ObservableTimer::indefinite_from_seconds(1.0))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn(ObservableTimer::indefinite_from_seconds(1.0))
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(app.world().resource::<Intervals>().0.len(), 2);
+    assert_eq!(app.world().resource::<TimerEventBudget>().pending(), 2);
+
+    app.tick_observable_timers(Duration::ZERO);
+    let intervals = &app.world().resource::<Intervals>().0;
+    assert_eq!(intervals.len(), 4);
+    for timer in [first, second] {
+        assert_eq!(
+            intervals.iter().filter(|&&entity| entity == timer).count(),
+            2
+        );
+    }
+    assert_eq!(app.world().resource::<TimerEventBudget>().pending(), 0);
+}
+
+#[test]
+fn finish_behaviors_wait_for_queued_finished_events() {
+    let mut app = app(1);
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::from_seconds(2, 1.0))
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(2));
+    assert!(app.world().get_entity(timer).is_some());
+
+    app.tick_observable_timers(Duration::ZERO);
+    assert!(app.world().get_entity(timer).is_some());
+
+    app.tick_observable_timers(Duration::ZERO);
+    assert!(app.world().get_entity(timer).is_none());
+}
+
+#[test]
+fn queued_events_of_despawned_entities_are_dropped() {
+    let mut app = app(1);
+    app.world_mut()
+        .spawn(ObservableTimer::indefinite_from_seconds(1.0));
+    let despawned = app
+        .world_mut()
+        .spawn(ObservableTimer::indefinite_from_seconds(1.0))
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(3));
+    let triggered = intervals_of(&app, despawned);
+    app.world_mut().despawn(despawned);
+    for _ in 0..6 {
+        app.tick_observable_timers(Duration::ZERO);
+    }
+    assert_eq!(app.world().resource::<TimerEventBudget>().pending(), 0);
+    assert_eq!(intervals_of(&app, despawned), triggered);
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Intervals(Vec<(Entity, u32)>);
+
+#[test]
+fn by_entity_triggers_each_timers_intervals_together() {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::default()
+            .with_tick_mode(TimerTickMode::Manual)
+            .with_event_order(TimerEventOrder::ByEntity),
+    )
+    .init_resource::<Intervals>()
+    .observe(
+        |trigger: Trigger<TimerInterval>, mut intervals: ResMut<Intervals>| {
+            intervals
+                .0
+                .push((trigger.entity(), trigger.event().count()));
+        },
+    );
+    let world = app.world_mut();
+    let slow = world.spawn(ObservableTimer::from_seconds(4, 2.0)).id();
+    let fast = world.spawn(ObservableTimer::from_seconds(4, 1.0)).id();
+    let other = world.spawn(ObservableTimer::from_seconds(4, 2.0)).id();
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(
+        world.resource::<Intervals>().0,
+        [(slow, 1), (fast, 1), (fast, 2), (other, 1)]
+    );
+}
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+/// A chain of timers lasting 1, 2 and 3 seconds, the last of which removes itself.
+fn chain() -> ObservableTimer {
+    let last = ObservableTimer::once_from_seconds(3.0)
+        .with_finish_behavior(TimerFinishBehavior::RemoveComponent);
+    let second = ObservableTimer::once_from_seconds(2.0)
+        .with_finish_behavior(TimerFinishBehavior::replace_with(last));
+    ObservableTimer::once_from_seconds(1.0)
+        .with_finish_behavior(TimerFinishBehavior::replace_with(second))
+}
+
+#[test]
+fn replaces_timers_in_order() {
+    let mut world = World::new();
+    let entity = world.spawn(chain()).id();
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    let timer = world.get::<ObservableTimer>(entity).unwrap();
+    assert_eq!(timer.duration(), Some(Duration::from_secs(2)));
+    assert_eq!(timer.elapsed(), Duration::ZERO);
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    let timer = world.get::<ObservableTimer>(entity).unwrap();
+    assert_eq!(timer.duration(), Some(Duration::from_secs(3)));
+
+    world.tick_observable_timers(Duration::from_secs(3));
+    assert!(world.get::<ObservableTimer>(entity).is_none());
+    assert!(world.get_entity(entity).is_some());
+}
+
+#[test]
+fn replaced_timers_trigger_replaced_and_started() {
+    #[derive(Resource, Default)]
+    struct Events(Vec<&'static str>);
+
+    let mut world = World::new();
+    world.init_resource::<Events>();
+    world.observe(|_: Trigger<TimerReplaced>, mut events: ResMut<Events>| {
+        events.0.push("Replaced");
+    });
+    world.observe(|_: Trigger<TimerStarted>, mut events: ResMut<Events>| {
+        events.0.push("Started");
+    });
+    world.observe(|_: Trigger<TimerFinished>, mut events: ResMut<Events>| {
+        events.0.push("Finished");
+    });
+    world.spawn(chain());
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    world.tick_observable_timers(Duration::ZERO);
+    assert_eq!(
+        world.resource::<Events>().0,
+        ["Started", "Finished", "Replaced", "Started"]
+    );
+}
+
+#[test]
+fn replaces_registered_timers_under_the_same_handle() {
+    let mut world = World::new();
+    world.init_resource::<TimerRegistry>();
+    let handle = world.resource_mut::<TimerRegistry>().start(chain());
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    let registry = world.resource::<TimerRegistry>();
+    assert_eq!(
+        registry.get(handle).unwrap().duration(),
+        Some(Duration::from_secs(2))
+    );
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    world.tick_observable_timers(Duration::from_secs(3));
+    assert!(!world.resource::<TimerRegistry>().contains(handle));
+}
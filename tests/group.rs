@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+fn world() -> World {
+    let mut world = World::new();
+    world.init_resource::<TimerGroups>();
+    world
+}
+
+fn elapsed(world: &World, entity: Entity) -> Duration {
+    world.get::<ObservableTimer>(entity).unwrap().elapsed()
+}
+
+#[test]
+fn group_scales_apply_to_their_timers_only() {
+    let mut world = world();
+    world.resource_mut::<TimerGroups>().set_scale("ui", 0.5);
+    let ui = world
+        .spawn((
+            TimerGroup::new("ui"),
+            ObservableTimer::once_from_seconds(10.0),
+        ))
+        .id();
+    let other = world.spawn(ObservableTimer::once_from_seconds(10.0)).id();
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(elapsed(&world, ui), Duration::from_secs(1));
+    assert_eq!(elapsed(&world, other), Duration::from_secs(2));
+}
+
+#[test]
+fn group_scales_combine_with_dilation() {
+    let mut world = world();
+    world.resource_mut::<TimerGroups>().set_scale("ui", 0.5);
+    let timer = world
+        .spawn((
+            TimerGroup::new("ui"),
+            TimeDilation(0.5),
+            ObservableTimer::once_from_seconds(10.0),
+        ))
+        .id();
+
+    world.tick_observable_timers(Duration::from_secs(4));
+    assert_eq!(elapsed(&world, timer), Duration::from_secs(1));
+}
+
+#[test]
+fn resuming_a_group_keeps_individually_paused_timers_paused() {
+    let mut world = world();
+    let group = TimerGroup::new("enemy_ai");
+    let running = world
+        .spawn((group.clone(), ObservableTimer::once_from_seconds(10.0)))
+        .id();
+    let mut paused = ObservableTimer::once_from_seconds(10.0);
+    paused.pause();
+    let paused = world.spawn((group, paused)).id();
+
+    world.resource_mut::<TimerGroups>().pause("enemy_ai");
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(elapsed(&world, running), Duration::ZERO);
+
+    world.resource_mut::<TimerGroups>().resume("enemy_ai");
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(elapsed(&world, running), Duration::from_secs(1));
+    assert_eq!(elapsed(&world, paused), Duration::ZERO);
+}
+
+#[test]
+fn cancelling_a_group_removes_its_timers() {
+    let mut world = world();
+    let grouped = world
+        .spawn((
+            TimerGroup::new("wave"),
+            ObservableTimer::once_from_seconds(10.0),
+        ))
+        .id();
+    let other = world.spawn(ObservableTimer::once_from_seconds(10.0)).id();
+
+    world.resource_mut::<TimerGroups>().cancel("wave");
+    world.tick_observable_timers(Duration::ZERO);
+    assert!(world.get::<ObservableTimer>(grouped).is_none());
+    assert!(world.get::<ObservableTimer>(other).is_some());
+}
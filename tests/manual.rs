@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[test]
+fn manual_updates_check_deadlines() {
+    let mut app = App::new();
+    app.add_plugins((
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        DeadlinePlugin,
+    ))
+    .init_resource::<Time>();
+    let world = app.world_mut();
+    let deadline = world.spawn(Deadline::new(Duration::from_secs(1))).id();
+
+    world.tick_observable_timers(Duration::ZERO);
+    assert!(world.get_entity(deadline).is_some());
+
+    world
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    world.tick_observable_timers(Duration::ZERO);
+    assert!(world.get_entity(deadline).is_none());
+}
+
+#[test]
+fn manual_tick_mode_stops_every_timer() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        DeadlinePlugin,
+    ));
+    let deadline = app.world_mut().spawn(Deadline::new(Duration::ZERO)).id();
+
+    app.update();
+    app.update();
+    assert!(app.world().get_entity(deadline).is_some());
+
+    app.tick_observable_timers(Duration::ZERO);
+    assert!(app.world().get_entity(deadline).is_none());
+}
+
+#[test]
+fn manual_updates_run_systems_added_to_the_timer_schedule() {
+    #[derive(Resource, Default)]
+    struct Updates(u32);
+
+    let mut app = App::new();
+    app.add_plugins(ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual))
+        .init_resource::<Updates>()
+        .add_systems(
+            ObservableTimerUpdate,
+            (|mut updates: ResMut<Updates>| updates.0 += 1).after(ObservableTimerSystems::Tick),
+        );
+
+    app.tick_observable_timers(Duration::from_secs(1));
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(app.world().resource::<Updates>().0, 2);
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::default()
+            .with_tick_mode(TimerTickMode::Manual)
+            .with_scheduling(TimerScheduling::DueQueue),
+    );
+    app
+}
+
+fn elapsed(app: &App, entity: Entity) -> Duration {
+    app.world()
+        .get::<ObservableTimer>(entity)
+        .unwrap()
+        .elapsed()
+}
+
+#[test]
+fn queued_timers_are_only_brought_up_to_date_when_due() {
+    let mut app = app();
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::from_seconds(2, 4.0))
+        .id();
+
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(elapsed(&app, timer), Duration::ZERO);
+
+    app.tick_observable_timers(Duration::from_secs(3));
+    assert_eq!(elapsed(&app, timer), Duration::from_secs(4));
+}
+
+#[test]
+fn timers_that_gain_a_dilation_stop_being_queued() {
+    let mut app = app();
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::once_from_seconds(10.0))
+        .id();
+    app.tick_observable_timers(Duration::from_secs(1));
+
+    app.world_mut().entity_mut(timer).insert(TimeDilation(0.5));
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(elapsed(&app, timer), Duration::from_secs(2));
+
+    app.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(elapsed(&app, timer), Duration::from_secs(3));
+}
+
+#[test]
+fn timers_that_gain_a_fraction_stop_being_queued() {
+    let mut app = app();
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::once_from_seconds(10.0))
+        .id();
+    app.tick_observable_timers(Duration::from_secs(1));
+
+    app.world_mut()
+        .entity_mut(timer)
+        .insert(TimerFraction::default());
+    for _ in 0..4 {
+        app.tick_observable_timers(Duration::from_secs(1));
+    }
+    assert_eq!(elapsed(&app, timer), Duration::from_secs(5));
+    assert_eq!(app.world().get::<TimerFraction>(timer).unwrap().0, 0.5);
+}
+
+#[test]
+fn timers_that_gain_a_parent_stop_being_queued() {
+    let mut app = app();
+    let bubble = app.world_mut().spawn(TimeDilation(0.5)).id();
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::once_from_seconds(10.0))
+        .id();
+    app.tick_observable_timers(Duration::from_secs(1));
+
+    app.world_mut().entity_mut(timer).set_parent(bubble);
+    app.tick_observable_timers(Duration::from_secs(1));
+    app.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(elapsed(&app, timer), Duration::from_secs(3));
+}
+
+#[test]
+fn timers_that_enable_second_events_stop_being_queued() {
+    #[derive(Resource, Default)]
+    struct Seconds(u32);
+
+    let mut app = app();
+    app.init_resource::<Seconds>()
+        .observe(|_: Trigger<TimerSecondElapsed>, mut seconds: ResMut<Seconds>| seconds.0 += 1);
+    let timer = app
+        .world_mut()
+        .spawn(ObservableTimer::once_from_seconds(10.0))
+        .id();
+    app.tick_observable_timers(Duration::from_secs(1));
+
+    app.world_mut()
+        .get_mut::<ObservableTimer>(timer)
+        .unwrap()
+        .second_events = true;
+    for _ in 0..3 {
+        app.tick_observable_timers(Duration::from_secs(1));
+    }
+    assert_eq!(app.world().resource::<Seconds>().0, 3);
+}
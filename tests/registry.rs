@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Events(Vec<(TimerHandle, String)>);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual))
+        .init_resource::<Events>()
+        .observe(
+            |trigger: Trigger<RegisteredTimerEvent<TimerStarted>>, mut events: ResMut<Events>| {
+                events.0.push((trigger.event().handle(), "started".into()));
+            },
+        )
+        .observe(
+            |trigger: Trigger<RegisteredTimerEvent<TimerInterval>>, mut events: ResMut<Events>| {
+                let count = trigger.event().event().count();
+                events
+                    .0
+                    .push((trigger.event().handle(), format!("interval {count}")));
+            },
+        )
+        .observe(
+            |trigger: Trigger<RegisteredTimerEvent<TimerFinished>>, mut events: ResMut<Events>| {
+                let reason = trigger.event().event().reason();
+                events
+                    .0
+                    .push((trigger.event().handle(), format!("{reason:?}")));
+            },
+        );
+    app
+}
+
+fn start(world: &mut World, timer: ObservableTimer) -> TimerHandle {
+    world.resource_mut::<TimerRegistry>().start(timer)
+}
+
+fn events(world: &World) -> Vec<(TimerHandle, &str)> {
+    let events = &world.resource::<Events>().0;
+    events
+        .iter()
+        .map(|(handle, event)| (*handle, event.as_str()))
+        .collect()
+}
+
+#[test]
+fn registered_timers_trigger_events_with_their_handles() {
+    let mut app = app();
+    let world = app.world_mut();
+    let first = start(world, ObservableTimer::from_seconds(2, 1.0));
+    let second = start(world, ObservableTimer::once_from_seconds(3.0));
+    assert_ne!(first, second);
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(
+        events(world),
+        [
+            (first, "started"),
+            (second, "started"),
+            (first, "interval 1"),
+            (first, "interval 2"),
+            (first, "Finished"),
+        ]
+    );
+
+    // Finished timers are removed, and their handles are inert
+    let registry = world.resource::<TimerRegistry>();
+    assert!(!registry.contains(first));
+    assert!(registry.contains(second));
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.remaining(second), Some(Duration::from_secs(1)));
+    let mut registry = world.resource_mut::<TimerRegistry>();
+    assert!(!registry.pause(first));
+    assert!(!registry.restart(first));
+    assert!(registry.cancel(first).is_none());
+}
+
+#[test]
+fn timers_that_finish_without_a_behavior_stay_registered() {
+    let mut app = app();
+    let world = app.world_mut();
+    let handle = start(
+        world,
+        ObservableTimer::once_from_seconds(1.0).with_finish_behavior(TimerFinishBehavior::None),
+    );
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    let registry = world.resource::<TimerRegistry>();
+    assert!(registry.get(handle).unwrap().is_done());
+
+    world.resource_mut::<TimerRegistry>().restart(handle);
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(
+        events(world),
+        [
+            (handle, "started"),
+            (handle, "interval 1"),
+            (handle, "Finished"),
+            (handle, "interval 1"),
+            (handle, "Finished"),
+        ]
+    );
+}
+
+#[test]
+fn cancelling_a_running_timer_triggers_a_cancelled_event() {
+    let mut app = app();
+    let world = app.world_mut();
+    let handle = start(world, ObservableTimer::once_from_seconds(2.0));
+    world.tick_observable_timers(Duration::from_secs(1));
+
+    let timer = world
+        .resource_mut::<TimerRegistry>()
+        .cancel(handle)
+        .unwrap();
+    assert_eq!(timer.elapsed(), Duration::from_secs(1));
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(events(world), [(handle, "started"), (handle, "Cancelled")]);
+    assert!(world.resource::<TimerRegistry>().is_empty());
+}
+
+#[test]
+fn paused_timers_keep_their_time() {
+    let mut app = app();
+    let world = app.world_mut();
+    let handle = start(world, ObservableTimer::once_from_seconds(2.0));
+    world.tick_observable_timers(Duration::from_secs(1));
+
+    let mut registry = world.resource_mut::<TimerRegistry>();
+    assert!(registry.pause(handle));
+    assert!(!registry.pause(handle));
+    assert_eq!(registry.is_paused(handle), Some(true));
+    world.tick_observable_timers(Duration::from_secs(5));
+    assert_eq!(
+        world.resource::<TimerRegistry>().remaining(handle),
+        Some(Duration::from_secs(1))
+    );
+
+    let mut registry = world.resource_mut::<TimerRegistry>();
+    assert!(registry.resume(handle));
+    assert!(!registry.resume(handle));
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(
+        events(world),
+        [
+            (handle, "started"),
+            (handle, "interval 1"),
+            (handle, "Finished")
+        ]
+    );
+}
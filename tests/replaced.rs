@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Stopped(Vec<(StopReason, Duration, TimerDirection)>);
+
+#[test]
+fn replaced_timers_report_their_last_interval_duration_and_direction() {
+    let mut world = World::new();
+    world.init_resource::<Stopped>();
+    world.observe(
+        |trigger: Trigger<TimerFinished>, mut stopped: ResMut<Stopped>| {
+            let finished = trigger.event();
+            stopped.0.push((
+                finished.reason(),
+                finished.interval_duration(),
+                finished.direction(),
+            ));
+        },
+    );
+    let entity = world
+        .spawn(ObservableTimer::from_seconds(4, 2.0).with_ping_pong(true))
+        .id();
+    world.tick_observable_timers(Duration::from_secs(3));
+
+    world
+        .entity_mut(entity)
+        .insert(ObservableTimer::once_from_seconds(1.0));
+    world.flush();
+    assert_eq!(
+        world.resource::<Stopped>().0,
+        [(
+            StopReason::Replaced,
+            Duration::from_secs(2),
+            TimerDirection::Backward
+        )]
+    );
+}
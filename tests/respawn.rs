@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Resource)]
+struct Respawns([Entity; 2]);
+
+#[test]
+fn despawning_a_due_respawn_cancels_it() {
+    let mut world = World::new();
+    let delay = Duration::from_secs(5);
+    let respawns = [
+        world.spawn(RespawnAfter::from_bundle(delay, Enemy)).id(),
+        world.spawn(RespawnAfter::from_bundle(delay, Enemy)).id(),
+    ];
+    world.insert_resource(Respawns(respawns));
+
+    // Whichever enemy respawns first cancels the other respawn
+    world.observe(
+        |trigger: Trigger<Respawned>, respawns: Res<Respawns>, mut commands: Commands| {
+            for respawn in respawns.0 {
+                if respawn != trigger.entity() {
+                    commands.entity(respawn).despawn();
+                }
+            }
+        },
+    );
+
+    world.tick_observable_timers(delay);
+    assert_eq!(world.query::<&Enemy>().iter(&world).count(), 1);
+    assert_eq!(world.query::<&RespawnAfter>().iter(&world).count(), 0);
+}
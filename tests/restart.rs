@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[test]
+fn restarted_timers_run_again_after_finishing() {
+    let mut world = World::new();
+    let entity = world
+        .spawn(
+            ObservableTimer::once_from_seconds(1.0).with_finish_behavior(TimerFinishBehavior::None),
+        )
+        .id();
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert!(world.get::<ObservableTimer>(entity).unwrap().is_done());
+
+    let mut timer = world.get_mut::<ObservableTimer>(entity).unwrap();
+    timer.restart();
+    assert!(!timer.is_done());
+    world.tick_observable_timers(Duration::from_millis(500));
+    let timer = world.get::<ObservableTimer>(entity).unwrap();
+    assert_eq!(timer.elapsed(), Duration::from_millis(500));
+
+    world.tick_observable_timers(Duration::from_millis(500));
+    let timer = world.get::<ObservableTimer>(entity).unwrap();
+    assert!(timer.is_done());
+    assert_eq!(timer.elapsed_intervals(), 1);
+}
@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Finished(u32);
+
+fn world(finished_events: RollbackFinishedEvents) -> World {
+    let mut world = World::new();
+    world.insert_resource(TimerRollback::new(8).with_finished_events(finished_events));
+    world.init_resource::<Finished>();
+    world.observe(
+        |_: Trigger<TimerFinished>, mut finished: ResMut<Finished>| {
+            finished.0 += 1;
+        },
+    );
+    world
+}
+
+fn timer() -> ObservableTimer {
+    ObservableTimer::once_from_seconds(1.0).with_finish_behavior(TimerFinishBehavior::None)
+}
+
+fn simulate(world: &mut World, tick: u32) {
+    world.resource_mut::<TimerRollback>().set_tick(tick);
+    world.tick_observable_timers(Duration::from_millis(500));
+}
+
+/// Simulates ticks 1 to 4, during which a timer finishes on tick 2, then rolls back to tick 1 and resimulates them.
+/// The resimulation inserts a new timer on the same entity after tick 2, which finishes on tick 4.
+fn resimulate_with_new_timer(world: &mut World) {
+    let entity = world.spawn(timer()).id();
+    let confirmed = world.get::<ObservableTimer>(entity).unwrap().clone();
+    for tick in 1..=4 {
+        simulate(world, tick);
+    }
+    assert_eq!(world.resource::<Finished>().0, 1);
+
+    world.entity_mut(entity).restore_timer(confirmed);
+    simulate(world, 1);
+    assert!(world.resource::<TimerRollback>().is_resimulating());
+    simulate(world, 2);
+    world.entity_mut(entity).insert(timer());
+    simulate(world, 3);
+    simulate(world, 4);
+    assert!(world.resource::<TimerRollback>().is_resimulating());
+}
+
+#[test]
+fn resimulated_ticks_trigger_finished_once() {
+    let mut world = world(RollbackFinishedEvents::Deduplicate);
+    let entity = world.spawn(timer()).id();
+    let confirmed = world.get::<ObservableTimer>(entity).unwrap().clone();
+    simulate(&mut world, 1);
+    simulate(&mut world, 2);
+
+    // Roll back to tick 1 and resimulate it twice
+    for _ in 0..2 {
+        world.entity_mut(entity).restore_timer(confirmed.clone());
+        simulate(&mut world, 1);
+        simulate(&mut world, 2);
+    }
+    assert_eq!(world.resource::<Finished>().0, 1);
+}
+
+#[test]
+fn deduplicate_keeps_events_of_new_timers() {
+    let mut world = world(RollbackFinishedEvents::Deduplicate);
+    resimulate_with_new_timer(&mut world);
+    assert_eq!(world.resource::<Finished>().0, 2);
+}
+
+#[test]
+fn suppress_keeps_events_of_new_timers() {
+    let mut world = world(RollbackFinishedEvents::Suppress);
+    resimulate_with_new_timer(&mut world);
+    assert_eq!(world.resource::<Finished>().0, 2);
+}
+
+#[test]
+fn suppress_drops_events_of_timers_running_before_the_rollback() {
+    let mut world = world(RollbackFinishedEvents::Suppress);
+    let entity = world
+        .spawn(
+            ObservableTimer::once_from_seconds(1.5).with_finish_behavior(TimerFinishBehavior::None),
+        )
+        .id();
+    simulate(&mut world, 1);
+    let confirmed = world.get::<ObservableTimer>(entity).unwrap().clone();
+    simulate(&mut world, 2);
+    assert_eq!(world.resource::<Finished>().0, 0);
+
+    // The corrected tick 2 takes longer, so the timer now finishes while resimulating
+    world.entity_mut(entity).restore_timer(confirmed);
+    world.resource_mut::<TimerRollback>().set_tick(2);
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert!(world.get::<ObservableTimer>(entity).unwrap().is_done());
+    assert_eq!(world.resource::<Finished>().0, 0);
+}
+
+#[test]
+fn restoring_a_timer_triggers_no_lifecycle_events() {
+    #[derive(Resource, Default)]
+    struct Events(Vec<&'static str>);
+
+    let mut world = world(RollbackFinishedEvents::Replay);
+    world.init_resource::<Events>();
+    world.observe(|_: Trigger<TimerStarted>, mut events: ResMut<Events>| {
+        events.0.push("Started");
+    });
+    world.observe(|_: Trigger<TimerReplaced>, mut events: ResMut<Events>| {
+        events.0.push("Replaced");
+    });
+    world.observe(
+        |trigger: Trigger<TimerFinished>, mut events: ResMut<Events>| {
+            events.0.push(match trigger.event().reason() {
+                StopReason::Finished => "Finished",
+                _ => "Stopped",
+            });
+        },
+    );
+
+    let running = world.spawn(timer()).id();
+    let removed = world
+        .spawn(
+            ObservableTimer::once_from_seconds(1.0)
+                .with_finish_behavior(TimerFinishBehavior::RemoveComponent),
+        )
+        .id();
+    simulate(&mut world, 1);
+    let confirmed_running = world.get::<ObservableTimer>(running).unwrap().clone();
+    let confirmed_removed = world.get::<ObservableTimer>(removed).unwrap().clone();
+    simulate(&mut world, 2);
+    assert!(world.get::<ObservableTimer>(removed).is_none());
+    world.resource_mut::<Events>().0.clear();
+
+    // Restoring both overwrites a running timer and reinserts a removed one, then tick 2 is resimulated
+    world.entity_mut(running).restore_timer(confirmed_running);
+    world.entity_mut(removed).restore_timer(confirmed_removed);
+    world.flush();
+    assert!(!world.get::<ObservableTimer>(running).unwrap().is_done());
+    assert!(!world.get::<ObservableTimer>(removed).unwrap().is_done());
+    simulate(&mut world, 2);
+    assert_eq!(world.resource::<Events>().0, ["Finished", "Finished"]);
+    assert!(world.get::<ObservableTimer>(removed).is_none());
+}
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct GgrsSchedule;
+
+#[derive(Resource, Default)]
+struct RollbackFrameCount(i32);
+
+fn set_timer_tick(frame: Res<RollbackFrameCount>, mut rollback: ResMut<TimerRollback>) {
+    rollback.set_tick(frame.0 as u32);
+}
+
+/// Advances the world by one frame, as GGRS does when simulating or resimulating it.
+fn advance_frame(world: &mut World) {
+    world.resource_mut::<RollbackFrameCount>().0 += 1;
+    world.run_schedule(GgrsSchedule);
+}
+
+#[test]
+fn timers_roll_back_in_a_ggrs_schedule() {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::in_schedule(GgrsSchedule)
+            .with_tick_mode(TimerTickMode::FixedStep(Duration::from_millis(500)))
+            .with_rollback(8),
+    )
+    .init_resource::<RollbackFrameCount>()
+    .init_resource::<Finished>()
+    .add_systems(GgrsSchedule, set_timer_tick.before(ObservableTimerSet))
+    .observe(|_: Trigger<TimerFinished>, mut finished: ResMut<Finished>| finished.0 += 1);
+    let world = app.world_mut();
+    let entity = world.spawn(timer()).id();
+
+    advance_frame(world);
+    let snapshot = TimerSnapshot::capture(world);
+    advance_frame(world);
+    assert_eq!(world.resource::<Finished>().0, 1);
+
+    // Loading the snapshot of frame 1 overwrites the timer in place, like `rollback_component_with_clone`
+    snapshot.apply(world);
+    world.resource_mut::<RollbackFrameCount>().0 = 1;
+    assert!(!world.get::<ObservableTimer>(entity).unwrap().is_done());
+    advance_frame(world);
+    advance_frame(world);
+    assert!(world.get::<ObservableTimer>(entity).unwrap().is_done());
+    assert_eq!(world.resource::<Finished>().0, 1);
+}
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+fn elapsed(world: &World, entity: Entity) -> Duration {
+    world.get::<ObservableTimer>(entity).unwrap().elapsed()
+}
+
+#[test]
+fn ignores_invalid_dilations() {
+    let mut world = World::new();
+    let timers = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -1.0].map(|dilation| {
+        world
+            .spawn((
+                TimeDilation(dilation),
+                ObservableTimer::once_from_seconds(2.0),
+            ))
+            .id()
+    });
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    for timer in timers {
+        assert_eq!(elapsed(&world, timer), Duration::ZERO);
+    }
+}
+
+#[test]
+fn saturates_overflowing_dilations() {
+    let mut world = World::new();
+    let timer = world
+        .spawn((
+            TimeDilation(f32::MAX),
+            ObservableTimer::once_from_seconds(2.0),
+        ))
+        .id();
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert!(world.get_entity(timer).is_none());
+}
+
+#[test]
+fn ignores_invalid_group_scales() {
+    let mut world = World::new();
+    world.init_resource::<TimerGroups>();
+    world
+        .resource_mut::<TimerGroups>()
+        .set_scale("ui", f32::INFINITY);
+    let timer = world
+        .spawn((
+            TimerGroup::new("ui"),
+            ObservableTimer::once_from_seconds(2.0),
+        ))
+        .id();
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(world.resource::<TimerGroups>().scale("ui"), 0.0);
+    assert_eq!(elapsed(&world, timer), Duration::ZERO);
+}
+
+#[test]
+fn ignores_invalid_timeline_speeds() {
+    let mut app = App::new();
+    app.add_plugins((
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        TimelinePlugin,
+    ));
+    let world = app.world_mut();
+    let timeline = world
+        .spawn(
+            Timeline::new()
+                .with_duration(Duration::from_secs(2))
+                .with_speed(f32::NAN),
+        )
+        .id();
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    let timeline = world.get::<Timeline>(timeline).unwrap();
+    assert_eq!(timeline.elapsed(), Duration::ZERO);
+}
+
+#[test]
+fn ignores_invalid_game_clock_scales() {
+    let mut world = World::new();
+    world.insert_resource(GameClock::new(f32::INFINITY));
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(world.resource::<GameClock>().elapsed(), Duration::ZERO);
+}
+
+#[test]
+fn never_completes_intervals_of_zero_hz() {
+    let timer = ObservableTimer::from_hz(0, 0.0);
+    assert_eq!(timer.interval_duration(), Duration::MAX);
+}
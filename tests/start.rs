@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Started(u32);
+
+fn app(start_mode: TimerStartMode) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        ObservableTimerPlugin::default().with_start_mode(start_mode),
+    ))
+    .init_resource::<Started>()
+    .observe(|_: Trigger<TimerStarted>, mut started: ResMut<Started>| started.0 += 1);
+    app
+}
+
+#[test]
+fn deferred_start_waits_for_timer_update() {
+    let mut app = app(TimerStartMode::Deferred);
+    app.world_mut()
+        .spawn(ObservableTimer::once_from_seconds(1.0));
+    app.world_mut().flush();
+    assert_eq!(app.world().resource::<Started>().0, 0);
+
+    app.update();
+    assert_eq!(app.world().resource::<Started>().0, 1);
+}
+
+#[test]
+fn immediate_start_triggers_on_insert() {
+    let mut app = app(TimerStartMode::Immediate);
+    app.world_mut()
+        .spawn(ObservableTimer::once_from_seconds(1.0));
+    app.world_mut().flush();
+    assert_eq!(app.world().resource::<Started>().0, 1);
+
+    // Nothing is left to trigger on the next update
+    app.update();
+    assert_eq!(app.world().resource::<Started>().0, 1);
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::{test_utils::*, *};
+
+#[test]
+fn advancing_timers_advances_the_clocks() {
+    let mut app = timer_test_app();
+    app.advance_timers(Duration::from_secs(3));
+    let world = app.world();
+    assert_eq!(world.resource::<Time>().elapsed(), Duration::from_secs(3));
+    assert_eq!(
+        world.resource::<Time<Real>>().elapsed(),
+        Duration::from_secs(3)
+    );
+    assert_eq!(
+        world.resource::<Time<Virtual>>().elapsed(),
+        Duration::from_secs(3)
+    );
+}
+
+#[test]
+fn clock_driven_timers_fire() {
+    let mut app = timer_test_app();
+    app.add_plugins((CronTimerPlugin, DeadlinePlugin))
+        .record_timer_events();
+    let cron = app
+        .world_mut()
+        .spawn(CronTimer::new("every 2 seconds").unwrap())
+        .id();
+    let deadline = app
+        .world_mut()
+        .spawn(Deadline::new(Duration::from_secs(3)))
+        .id();
+
+    app.advance_timers_in_steps(Duration::from_secs(5), Duration::from_millis(500));
+    let log = app.world().resource::<TimerEventLog>();
+    assert_eq!(
+        log.events_for(cron),
+        [
+            RecordedTimerEvent::Started,
+            RecordedTimerEvent::Interval(1),
+            RecordedTimerEvent::Interval(2),
+        ]
+    );
+    assert_eq!(
+        log.events_for(deadline),
+        [
+            RecordedTimerEvent::Started,
+            RecordedTimerEvent::Finished { cancelled: false },
+        ]
+    );
+}
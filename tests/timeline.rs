@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Event, Clone)]
+struct Keyframe(u32);
+
+#[derive(Resource, Default)]
+struct Triggered(Vec<u32>);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual),
+        TimelinePlugin,
+    ))
+    .init_resource::<Triggered>()
+    .observe(
+        |trigger: Trigger<Keyframe>, mut triggered: ResMut<Triggered>| {
+            triggered.0.push(trigger.event().0);
+        },
+    );
+    app
+}
+
+fn timeline() -> Timeline {
+    Timeline::new()
+        .at_seconds(1.0, Keyframe(1))
+        .at_seconds(2.0, Keyframe(2))
+        .at_seconds(3.0, Keyframe(3))
+        .with_finish_behavior(TimerFinishBehavior::None)
+}
+
+fn triggered(app: &App) -> &[u32] {
+    &app.world().resource::<Triggered>().0
+}
+
+#[test]
+fn keyframes_are_triggered_in_order() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(timeline()).id();
+
+    app.tick_observable_timers(Duration::from_millis(1500));
+    assert_eq!(triggered(&app), [1]);
+
+    app.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(triggered(&app), [1, 2, 3]);
+    assert!(app.world().get::<Timeline>(entity).unwrap().is_finished());
+}
+
+#[test]
+fn rewinding_triggers_keyframes_again() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(timeline()).id();
+    app.tick_observable_timers(Duration::from_millis(2500));
+
+    app.world_mut().get_mut::<Timeline>(entity).unwrap().speed = -1.0;
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(triggered(&app), [1, 2, 2]);
+
+    // Rewinding stops at the start
+    app.tick_observable_timers(Duration::from_secs(5));
+    assert_eq!(triggered(&app), [1, 2, 2, 1]);
+    assert_eq!(
+        app.world().get::<Timeline>(entity).unwrap().elapsed(),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn rewinding_without_reverse_events_is_silent() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(timeline().with_reverse_events(false))
+        .id();
+    app.tick_observable_timers(Duration::from_millis(2500));
+
+    app.world_mut().get_mut::<Timeline>(entity).unwrap().speed = -1.0;
+    app.tick_observable_timers(Duration::from_secs(5));
+    assert_eq!(triggered(&app), [1, 2]);
+}
+
+#[test]
+fn seeking_skips_keyframes_in_between() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(timeline()).id();
+
+    app.world_mut()
+        .get_mut::<Timeline>(entity)
+        .unwrap()
+        .seek(Duration::from_secs(2));
+    app.tick_observable_timers(Duration::from_millis(500));
+    assert_eq!(triggered(&app), [2]);
+
+    app.world_mut()
+        .get_mut::<Timeline>(entity)
+        .unwrap()
+        .seek(Duration::ZERO);
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(triggered(&app), [2, 1]);
+}
+
+#[test]
+fn seeking_a_finished_timeline_runs_it_again() {
+    #[derive(Resource, Default)]
+    struct Finished(u32);
+
+    let mut app = app();
+    app.init_resource::<Finished>()
+        .observe(|_: Trigger<TimelineFinished>, mut finished: ResMut<Finished>| finished.0 += 1);
+    let entity = app.world_mut().spawn(timeline()).id();
+    app.tick_observable_timers(Duration::from_secs(4));
+
+    app.world_mut()
+        .get_mut::<Timeline>(entity)
+        .unwrap()
+        .seek(Duration::from_millis(2500));
+    assert!(!app.world().get::<Timeline>(entity).unwrap().is_finished());
+    app.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(triggered(&app), [1, 2, 3, 3]);
+    assert_eq!(app.world().resource::<Finished>().0, 2);
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Log(Vec<String>);
+
+#[derive(Component)]
+struct LastInterval(u32);
+
+fn app(trigger_mode: TimerTriggerMode) -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::default()
+            .with_tick_mode(TimerTickMode::Manual)
+            .with_trigger_mode(trigger_mode),
+    )
+    .init_resource::<Log>()
+    .observe(
+        |trigger: Trigger<TimerInterval>, mut log: ResMut<Log>, mut commands: Commands| {
+            let count = trigger.event().count();
+            log.0
+                .push(format!("{:?} interval {count}", trigger.entity()));
+            commands
+                .entity(trigger.entity())
+                .insert(LastInterval(count));
+        },
+    )
+    .observe(
+        |trigger: Trigger<TimerSecondElapsed>, mut log: ResMut<Log>| {
+            let remaining = trigger.event().remaining_secs();
+            log.0
+                .push(format!("{:?} second {remaining}", trigger.entity()));
+        },
+    )
+    .observe(
+        |trigger: Trigger<TimerFinished>, mut log: ResMut<Log>, intervals: Query<&LastInterval>| {
+            let last = intervals.get(trigger.entity()).map(|last| last.0);
+            log.0
+                .push(format!("{:?} finished after {last:?}", trigger.entity()));
+        },
+    );
+    app
+}
+
+fn run(trigger_mode: TimerTriggerMode) -> Vec<String> {
+    let mut app = app(trigger_mode);
+    let world = app.world_mut();
+    world.spawn(ObservableTimer::from_seconds(3, 1.0).with_second_events(true));
+    world.spawn(ObservableTimer::from_seconds(2, 1.5));
+    for _ in 0..4 {
+        world.tick_observable_timers(Duration::from_millis(1500));
+    }
+    std::mem::take(&mut world.resource_mut::<Log>().0)
+}
+
+#[test]
+fn immediate_mode_triggers_the_same_events_as_deferred_mode() {
+    let immediate = run(TimerTriggerMode::Immediate);
+    assert_eq!(immediate, run(TimerTriggerMode::Deferred));
+    assert_eq!(immediate.len(), 9);
+}
+
+#[test]
+fn observers_see_the_effects_of_earlier_events() {
+    let mut app = app(TimerTriggerMode::Immediate);
+    let world = app.world_mut();
+    let entity = world.spawn(ObservableTimer::from_seconds(2, 1.0)).id();
+    world.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(
+        world.resource::<Log>().0.last().unwrap(),
+        &format!("{entity:?} finished after Ok(2)")
+    );
+}
+
+#[test]
+fn immediate_mode_respects_the_event_budget() {
+    let mut app = App::new();
+    app.add_plugins(
+        ObservableTimerPlugin::default()
+            .with_tick_mode(TimerTickMode::Manual)
+            .with_trigger_mode(TimerTriggerMode::Immediate)
+            .with_event_budget(1),
+    )
+    .init_resource::<Log>()
+    .observe(|trigger: Trigger<TimerFinished>, mut log: ResMut<Log>| {
+        log.0.push(format!("{:?}", trigger.entity()));
+    });
+    let world = app.world_mut();
+    for _ in 0..3 {
+        world.spawn(ObservableTimer::once_from_seconds(1.0));
+    }
+
+    // The interval events of all three timers are triggered first, then their finished events, one per update
+    let mut finished = Vec::new();
+    world.tick_observable_timers(Duration::from_secs(1));
+    finished.push(world.resource::<Log>().0.len());
+    for _ in 0..5 {
+        world.tick_observable_timers(Duration::ZERO);
+        finished.push(world.resource::<Log>().0.len());
+    }
+    assert_eq!(finished, [0, 0, 0, 1, 2, 3]);
+    assert_eq!(world.resource::<TimerEventBudget>().pending(), 0);
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_mod_observable_timer::*;
+
+#[derive(Resource, Default)]
+struct Expired(Vec<(&'static str, u32)>);
+
+fn world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Expired>();
+    world.observe(
+        |trigger: Trigger<EntryExpired<&'static str, u32>>, mut expired: ResMut<Expired>| {
+            let event = trigger.event();
+            expired.0.push((*event.key(), *event.value()));
+        },
+    );
+    world
+}
+
+fn expired(world: &World) -> &[(&'static str, u32)] {
+    &world.resource::<Expired>().0
+}
+
+#[test]
+fn entries_expire_in_order_of_expiry() {
+    let mut world = world();
+    let mut map = TtlMap::<&str, u32>::new();
+    map.insert("c", 3, Duration::from_secs(3));
+    map.insert("a", 1, Duration::from_secs(1));
+    map.insert("d", 4, Duration::from_secs(10));
+    map.insert("b", 2, Duration::from_secs(2));
+    let entity = world.spawn(map).id();
+
+    world.tick_observable_timers(Duration::from_secs(5));
+    assert_eq!(expired(&world), [("a", 1), ("b", 2), ("c", 3)]);
+    let map = world.get::<TtlMap<&str, u32>>(entity).unwrap();
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.ttl(&"d"), Some(Duration::from_secs(5)));
+}
+
+#[test]
+fn refreshing_an_entry_before_it_expires_extends_it() {
+    let mut world = world();
+    let mut map = TtlMap::<&str, u32>::new();
+    map.insert("a", 1, Duration::from_secs(2));
+    let entity = world.spawn(map).id();
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    let mut map = world.get_mut::<TtlMap<&str, u32>>(entity).unwrap();
+    assert!(map.refresh(&"a", Duration::from_secs(2)));
+    assert!(!map.refresh(&"b", Duration::from_secs(2)));
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert!(expired(&world).is_empty());
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(expired(&world), [("a", 1)]);
+}
+
+#[test]
+fn removed_entries_never_expire() {
+    let mut world = world();
+    let mut map = TtlMap::<&str, u32>::new();
+    map.insert("a", 1, Duration::from_secs(1));
+    map.insert("b", 2, Duration::from_secs(1));
+    map.insert("c", 3, Duration::from_secs(1));
+    let entity = world.spawn(map).id();
+
+    let mut map = world.get_mut::<TtlMap<&str, u32>>(entity).unwrap();
+    assert_eq!(map.remove(&"a"), Some(1));
+    assert_eq!(map.remove(&"a"), None);
+    // Re-inserting a key replaces its entry without expiring the old one
+    assert_eq!(map.insert("b", 20, Duration::from_secs(2)), Some(2));
+
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(expired(&world), [("c", 3)]);
+    world.tick_observable_timers(Duration::from_secs(1));
+    assert_eq!(expired(&world), [("c", 3), ("b", 20)]);
+    assert!(world.get::<TtlMap<&str, u32>>(entity).unwrap().is_empty());
+}
+
+#[test]
+fn resource_entries_expire_globally() {
+    let mut app = App::new();
+    app.add_plugins(ObservableTimerPlugin::default().with_tick_mode(TimerTickMode::Manual))
+        .init_ttl_map::<&'static str, u32>()
+        .init_resource::<Expired>()
+        .observe(
+            |trigger: Trigger<EntryExpired<&'static str, u32>>, mut expired: ResMut<Expired>| {
+                assert_eq!(trigger.entity(), Entity::PLACEHOLDER);
+                let event = trigger.event();
+                expired.0.push((*event.key(), *event.value()));
+            },
+        );
+    let world = app.world_mut();
+    let mut map = world.resource_mut::<TtlMap<&str, u32>>();
+    map.insert("a", 1, Duration::from_secs(2));
+    map.insert("b", 2, Duration::from_secs(1));
+
+    world.tick_observable_timers(Duration::from_secs(2));
+    assert_eq!(expired(world), [("b", 2), ("a", 1)]);
+    assert!(world.resource::<TtlMap<&str, u32>>().is_empty());
+}